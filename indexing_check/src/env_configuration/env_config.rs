@@ -1,33 +1,90 @@
 use crate::common::*;
 
-#[doc = "환경변수를 읽고, 없을 경우 error 로그 후 panic"]
-fn get_env_or_panic(key: &str) -> String {
-    match env::var(key) {
-        Ok(val) => val,
-        Err(_) => {
-            let msg = format!("[ENV file read Error] '{}' must be set", key);
-            error!("{}", msg);
-            panic!("{}", msg);
-        }
-    }
-}
-
-#[doc = "Function to globally initialize the 'INDEX_LIST_PATH' variable"]
-pub static INDEX_LIST_PATH: once_lazy<String> =
-    once_lazy::new(|| get_env_or_panic("INDEX_LIST_PATH"));
-
-#[doc = "Function to globally initialize the 'EMAIL_RECEIVER_PATH' variable"]
-pub static EMAIL_RECEIVER_PATH: once_lazy<String> =
-    once_lazy::new(|| get_env_or_panic("EMAIL_RECEIVER_PATH"));
-
-#[doc = "Function to globally initialize the 'SYSTEM_CONFIG_PATH' variable"]
-pub static SYSTEM_CONFIG_PATH: once_lazy<String> =
-    once_lazy::new(|| get_env_or_panic("SYSTEM_CONFIG_PATH"));
-
-#[doc = "Function to globally initialize the 'HTML_TEMPLATE_PATH' variable"]
-pub static HTML_TEMPLATE_PATH: once_lazy<String> =
-    once_lazy::new(|| get_env_or_panic("HTML_TEMPLATE_PATH"));
-
-#[doc = "Function to globally initialize the 'SQL_SERVER_INFO_PATH' variable"]
-pub static SQL_SERVER_INFO_PATH: once_lazy<String> =
-    once_lazy::new(|| get_env_or_panic("SQL_SERVER_INFO_PATH"));
+use crate::errors::app_error::*;
+
+#[doc = "명령어 인자(--system-config 등)로 지정한 설정 파일 경로 - key 는 환경변수 이름"]
+/// 시작할 때 한번만 지정하고, 지정하지 않은 경로는 환경변수(.env)에서 읽는다.
+static CONFIG_PATHS: OnceCell<HashMap<&'static str, String>> = OnceCell::new();
+
+#[doc = "명령어 인자로 받은 설정 파일 경로들을 전역으로 지정해주는 함수"]
+/// # Arguments
+/// * `config_paths` - 환경변수 이름 -> 경로
+///
+/// # Returns
+/// * Result<(), AppError> - 이미 지정되어 있으면 에러
+pub fn initialize_config_paths(
+    config_paths: HashMap<&'static str, String>,
+) -> Result<(), AppError> {
+    CONFIG_PATHS
+        .set(config_paths)
+        .map_err(|_| AppError::Config(String::from("Config paths are already initialized")))
+}
+
+#[doc = "설정 파일 경로를 읽어주는 함수 - 명령어 인자 > 환경변수 순서, 비어있으면 None"]
+/// # Arguments
+/// * `key` - 환경변수 이름 (예: SYSTEM_CONFIG_PATH)
+///
+/// # Returns
+/// * Option<String>
+pub fn get_config_path(key: &str) -> Option<String> {
+    CONFIG_PATHS
+        .get()
+        .and_then(|config_paths| config_paths.get(key).cloned())
+        .or_else(|| env::var(key).ok())
+        .filter(|path| !path.trim().is_empty())
+}
+
+#[doc = "필수 설정 파일 경로를 읽어주는 함수 - 없으면 AppError::Config"]
+fn get_required_config_path(key: &str) -> Result<String, AppError> {
+    get_config_path(key).ok_or_else(|| AppError::Config(format!("'{}' must be set", key)))
+}
+
+#[doc = "Function to read the 'INDEX_LIST_PATH' variable"]
+pub fn get_index_list_path() -> Result<String, AppError> {
+    get_required_config_path("INDEX_LIST_PATH")
+}
+
+#[doc = "Function to read the 'EMAIL_RECEIVER_PATH' variable"]
+pub fn get_email_receiver_path() -> Result<String, AppError> {
+    get_required_config_path("EMAIL_RECEIVER_PATH")
+}
+
+#[doc = "Function to read the 'SYSTEM_CONFIG_PATH' variable"]
+pub fn get_system_config_path() -> Result<String, AppError> {
+    get_required_config_path("SYSTEM_CONFIG_PATH")
+}
+
+#[doc = "Function to read the 'HTML_TEMPLATE_PATH' variable"]
+pub fn get_html_template_path() -> Result<String, AppError> {
+    get_required_config_path("HTML_TEMPLATE_PATH")
+}
+
+#[doc = "Function to read the 'SQL_SERVER_INFO_PATH' variable"]
+pub fn get_sql_server_info_path() -> Result<String, AppError> {
+    get_required_config_path("SQL_SERVER_INFO_PATH")
+}
+
+#[doc = "Function to read the 'TEXT_TEMPLATE_PATH' variable (optional)"]
+pub fn get_text_template_path() -> Option<String> {
+    get_config_path("TEXT_TEMPLATE_PATH")
+}
+
+#[doc = "Function to read the 'DIGEST_HTML_TEMPLATE_PATH' variable (optional)"]
+pub fn get_digest_html_template_path() -> Option<String> {
+    get_config_path("DIGEST_HTML_TEMPLATE_PATH")
+}
+
+#[doc = "Function to read the 'DIGEST_TEXT_TEMPLATE_PATH' variable (optional)"]
+pub fn get_digest_text_template_path() -> Option<String> {
+    get_config_path("DIGEST_TEXT_TEMPLATE_PATH")
+}
+
+#[doc = "Function to read the 'REPORT_HTML_TEMPLATE_PATH' variable (optional)"]
+pub fn get_report_html_template_path() -> Option<String> {
+    get_config_path("REPORT_HTML_TEMPLATE_PATH")
+}
+
+#[doc = "Function to read the 'REPORT_TEXT_TEMPLATE_PATH' variable (optional)"]
+pub fn get_report_text_template_path() -> Option<String> {
+    get_config_path("REPORT_TEXT_TEMPLATE_PATH")
+}
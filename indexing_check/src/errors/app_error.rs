@@ -0,0 +1,62 @@
+use crate::common::*;
+
+#[doc = "프로그램 전체에서 구분해서 처리해야 하는 에러 종류"]
+/// 함수들은 그대로 anyhow::Error 를 반환하고, 에러가 처음 발생한 곳에서 AppError 로 감싸서 올려보낸다.
+/// 호출하는 쪽은 `AppError::find()` 로 종류를 확인해서 종료 코드나 알람 여부를 결정한다.
+#[derive(Debug, Clone, ThisError)]
+pub enum AppError {
+    /* 환경변수 / 설정 파일 / 템플릿 */
+    #[error("[Config Error] {0}")]
+    Config(String),
+    /* Elasticsearch 연결 실패, 실패 응답 */
+    #[error("[Elasticsearch Error] {0}")]
+    Elasticsearch(String),
+    /* SQL Server 커넥션 풀 / 쿼리 실패 */
+    #[error("[SqlServer Error] {0}")]
+    SqlServer(String),
+    /* 이메일 / Telegram 발송 실패 */
+    #[error("[Notification Error] {0}")]
+    Notification(String),
+    /* 색인 로그 등 데이터 형식 오류 */
+    #[error("[Parse Error] {0}")]
+    Parse(String),
+}
+
+impl AppError {
+    #[doc = "에러 종류별 프로그램 종료 코드 (분류되지 않은 에러는 1)"]
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Config(_) => 2,
+            Self::Elasticsearch(_) => 3,
+            Self::SqlServer(_) => 4,
+            Self::Notification(_) => 5,
+            Self::Parse(_) => 6,
+        }
+    }
+
+    #[doc = "모니터링 프로그램 / 주변 시스템의 문제인지 여부 - 색인 작업 자체의 문제(Parse)와 구분"]
+    pub fn is_infrastructure(&self) -> bool {
+        !matches!(self, Self::Parse(_))
+    }
+
+    #[doc = "anyhow 에러 체인에서 AppError 를 찾아주는 함수"]
+    /// # Arguments
+    /// * `err` - 확인할 에러 (context 로 감싸져 있어도 된다)
+    ///
+    /// # Returns
+    /// * Option<&AppError>
+    pub fn find(err: &anyhow::Error) -> Option<&AppError> {
+        err.chain()
+            .find_map(|cause| cause.downcast_ref::<AppError>())
+    }
+
+    #[doc = "anyhow 에러에 맞는 프로그램 종료 코드"]
+    pub fn exit_code_of(err: &anyhow::Error) -> i32 {
+        Self::find(err).map_or(1, AppError::exit_code)
+    }
+
+    #[doc = "anyhow 에러가 인프라(설정, Elasticsearch, SQL Server, 알림) 문제인지 여부"]
+    pub fn is_infrastructure_error(err: &anyhow::Error) -> bool {
+        Self::find(err).is_some_and(AppError::is_infrastructure)
+    }
+}
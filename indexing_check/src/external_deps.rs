@@ -1,20 +1,42 @@
-pub use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+pub use base64::{prelude::BASE64_STANDARD, Engine};
+pub use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 pub use chrono_tz::Asia::Seoul;
+pub use clap::{Args, Parser, Subcommand, ValueEnum};
 pub use cron::Schedule;
 pub use deadpool_tiberius::{Manager, Pool};
 pub use elasticsearch::{
+    auth::ClientCertificate,
+    cert::{Certificate, CertificateValidation},
+    http::headers::{HeaderMap, HeaderValue, AUTHORIZATION},
+    http::request::JsonBody,
     http::response::Response,
     http::transport::{ConnectionPool, Transport as EsTransport},
     http::transport::{SingleNodeConnectionPool, TransportBuilder},
+    http::Method,
     http::Url,
-    DeleteParts, Elasticsearch, IndexParts, SearchParts,
+    ilm::IlmPutLifecycleParts,
+    indices::{
+        IndicesCreateDataStreamParts, IndicesExistsParts, IndicesGetDataStreamParts,
+        IndicesGetMappingParts, IndicesPutIndexTemplateParts, IndicesPutMappingParts,
+    },
+    nodes::NodesInfoParts,
+    params::{Conflicts, OpType},
+    BulkParts, CountParts, DeleteByQueryParts, Elasticsearch, GetParts, IndexParts,
+    OpenPointInTimeParts, SearchParts, UpdateByQueryParts, UpdateParts,
 };
 pub use flexi_logger::{Age, Cleanup, Criterion, FileSpec, Logger, Naming, Record};
 pub use futures::{stream::TryStreamExt, Future};
-pub use lettre::{AsyncTransport, Transport};
+pub use lettre::{
+    message::{Mailbox, MultiPart},
+    transport::smtp::{authentication::Credentials as SmtpCredentials, AsyncSmtpTransportBuilder},
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+pub use native_tls::TlsConnector;
 pub use num_format::{Locale, ToFormattedString};
-pub use once_cell::sync::Lazy as once_lazy;
-pub use rand::{prelude::SliceRandom, rngs::StdRng, SeedableRng};
+pub use once_cell::sync::{Lazy as once_lazy, OnceCell};
+pub use rand::{prelude::SliceRandom, rngs::StdRng, Rng, SeedableRng};
 pub use regex::Regex;
 pub use reqwest::Client;
-pub use urlencoding::encode;
+pub use sha2::{Digest, Sha256};
+pub use tera::{Context, Tera};
+pub use thiserror::Error as ThisError;
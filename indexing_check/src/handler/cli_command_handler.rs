@@ -0,0 +1,203 @@
+use crate::common::*;
+
+use crate::errors::app_error::*;
+
+use crate::handler::{main_handler::*, telegram_command_handler::*};
+
+use crate::model::{
+    cli_args::*, error_alarm_info::*, error_alarm_info_format::*, index_check_status::*,
+    index_schedules_config::*, notification_outbox::*,
+};
+
+use crate::traits::service_traits::{
+    notification_service_trait::*, query_service_trait::*, status_service_trait::*,
+};
+
+use crate::utils_modules::time_utils::*;
+
+pub struct CliCommandHandler<N: NotificationService, Q: QueryService, S: StatusService> {
+    main_handler: Arc<MainHandler<N, Q, S>>,
+    index_schedules: Vec<IndexSchedules>,
+}
+
+impl<N: NotificationService, Q: QueryService, S: StatusService> CliCommandHandler<N, Q, S> {
+    pub fn new(
+        main_handler: Arc<MainHandler<N, Q, S>>,
+        index_schedules: Vec<IndexSchedules>,
+    ) -> Self {
+        Self {
+            main_handler,
+            index_schedules,
+        }
+    }
+
+    #[doc = "하위 명령어 한 건을 실행하고 결과를 표준출력으로 보여주는 함수"]
+    /// # Arguments
+    /// * `command` - 실행할 하위 명령어
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    pub async fn run(&self, command: &CliCommand) -> Result<(), anyhow::Error> {
+        match command {
+            CliCommand::Check(check_args) => self.check_index(check_args.index()).await,
+            CliCommand::List(list_args) => self.list_schedules(*list_args.count()),
+            CliCommand::Alerts(alerts_args) => {
+                self.print_open_alerts(alerts_args.index().as_deref()).await
+            }
+            CliCommand::TestNotify => self.test_notify().await,
+            CliCommand::Report(report_args) => {
+                self.main_handler
+                    .export_sla_report(&self.index_schedules, report_args)
+                    .await
+            }
+            CliCommand::Replay(replay_args) => {
+                self.main_handler
+                    .export_replay_report(&self.index_schedules, replay_args)
+                    .await
+            }
+            /* 모니터링 서버 실행 / 설정 검증은 main 에서 처리 */
+            CliCommand::Run | CliCommand::Validate => Ok(()),
+        }
+    }
+
+    #[doc = "설정 파일에 등록된 인덱스 스케쥴을 찾아주는 함수"]
+    fn find_schedule(&self, index_name: &str) -> Result<&IndexSchedules, anyhow::Error> {
+        self.index_schedules
+            .iter()
+            .find(|schedule| schedule.index_name() == index_name)
+            .ok_or_else(|| {
+                anyhow!(AppError::Config(format!(
+                    "Unknown index '{}' - not in index_list",
+                    index_name
+                )))
+            })
+    }
+
+    #[doc = "check <index> - 색인 체크를 한번 실행하고 결과를 출력"]
+    async fn check_index(&self, index_name: &str) -> Result<(), anyhow::Error> {
+        let index_schedule: &IndexSchedules = self.find_schedule(index_name)?;
+
+        self.main_handler.main_task(index_schedule, None).await?;
+
+        /* 프로그램이 바로 종료되므로 체크 결과 이력을 지금 저장 */
+        self.main_handler.flush_check_history().await;
+
+        let check_status: IndexCheckStatus = self
+            .main_handler
+            .status_service()
+            .get_check_status(index_name)
+            .ok_or_else(|| anyhow!("[CliCommandHandler->check_index] No check result recorded"))?;
+
+        println!("[Check Result]\n{}", format_check_status(&check_status));
+
+        Ok(())
+    }
+
+    #[doc = "list - 인덱스 스케쥴과 다음 체크 시각 (한국시간)"]
+    fn list_schedules(&self, count: usize) -> Result<(), anyhow::Error> {
+        let kst_offset: FixedOffset = get_kst_offset()?;
+
+        println!(
+            "{:<32} {:<14} {:<20} {:>10} {:>12}  NEXT CHECKS (KST)",
+            "INDEX", "TYPE", "CRON", "DURATION", "SIZE"
+        );
+
+        for index_schedule in &self.index_schedules {
+            let next_checks: String = match Schedule::from_str(index_schedule.time()) {
+                Ok(schedule) => schedule
+                    .upcoming(kst_offset)
+                    .take(count.max(1))
+                    .map(|next| next.format("%Y-%m-%d %H:%M:%S").to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                Err(e) => format!("invalid cron: {}", e),
+            };
+
+            println!(
+                "{:<32} {:<14} {:<20} {:>9}s {:>12}  {}",
+                index_schedule.index_name(),
+                index_schedule.indexing_type(),
+                index_schedule.time(),
+                index_schedule.duration(),
+                index_schedule.size(),
+                next_checks
+            );
+        }
+
+        Ok(())
+    }
+
+    #[doc = "alerts - 열려있는 알람 목록"]
+    async fn print_open_alerts(&self, index_name: Option<&str>) -> Result<(), anyhow::Error> {
+        let open_alarms: Vec<ErrorAlarmInfoFormat> = self
+            .main_handler
+            .get_open_alarms()
+            .await?
+            .into_iter()
+            .filter(|alarm| {
+                index_name
+                    .is_none_or(|index_name| alarm.error_alarm_info().index_name() == index_name)
+            })
+            .collect();
+
+        println!("[Open Alerts] {}", open_alarms.len());
+
+        for alarm in &open_alarms {
+            let info: &ErrorAlarmInfo = alarm.error_alarm_info();
+
+            println!(
+                "- [{}] {} ({}) {} / first seen {} / last seen {} / {} time(s)",
+                info.cluster_name(),
+                info.index_name(),
+                info.index_type(),
+                info.error_type(),
+                info.first_seen(),
+                info.last_seen(),
+                info.occurrence_count()
+            );
+
+            if !info.reason().is_empty() {
+                println!("   - reason: {}", info.reason());
+            }
+        }
+
+        Ok(())
+    }
+
+    #[doc = "test-notify - 모든 채널 / 수신자로 테스트 알림 발송"]
+    async fn test_notify(&self) -> Result<(), anyhow::Error> {
+        let results: Vec<(NotificationOutbox, Result<(), anyhow::Error>)> =
+            self.main_handler.send_test_notifications().await?;
+
+        let mut failed_cnt: usize = 0;
+
+        for (notification, result) in &results {
+            match result {
+                Ok(_) => println!(
+                    "[OK] {} -> {}",
+                    notification.channel(),
+                    notification.recipient()
+                ),
+                Err(e) => {
+                    failed_cnt += 1;
+                    println!(
+                        "[FAILED] {} -> {}: {}",
+                        notification.channel(),
+                        notification.recipient(),
+                        e
+                    );
+                }
+            }
+        }
+
+        if failed_cnt > 0 {
+            return Err(anyhow!(AppError::Notification(format!(
+                "{} of {} test notification(s) failed",
+                failed_cnt,
+                results.len()
+            ))));
+        }
+
+        Ok(())
+    }
+}
@@ -1,37 +1,87 @@
 use crate::common::*;
 
+use crate::errors::app_error::*;
+
 use crate::model::{
-    code_config::*, error_alarm_info::*, error_alarm_info_format::*, index_schedules_config::*,
-    system_config::*, total_config::*, vector_index_log_format::*,
+    alarm_snooze::*, bulk_operation::*, check_history::*, check_record::*, cli_args::*,
+    code_config::*, consistency_check_config::*, consistency_check_result::*, digest_config::*,
+    digest_template_context::*, elastic_server_config::*, error_alarm_info::*,
+    error_alarm_info_format::*, es_node_status::*, history_config::*, index_check_status::*,
+    index_management_config::*, index_schedules_config::*, index_template::*,
+    leader_election_config::*, leader_lease::*, notification_outbox::*, outbox_config::*,
+    replay_report::*, sla_report::*, system_config::*, total_config::*, vector_index_log_format::*,
 };
 
-use crate::traits::service_traits::{notification_service_trait::*, query_service_trait::*};
+use crate::traits::service_traits::{
+    notification_service_trait::*, query_service_trait::*, status_service_trait::*,
+};
 
-use crate::utils_modules::time_utils::*;
+use crate::utils_modules::{io_utils::*, time_utils::*};
 
-static IDX_CNT_RE: once_lazy<Regex> = once_lazy::new(|| {
-    Regex::new(r"(?i)worked\s*\((?P<num>[\d,]+)\)").unwrap_or_else(|e| {
-        error!(
-            "[IDX_CNT_RE] Failed to initialize the `IDX_CNT_RE` regular expression.: {:?}",
-            e
-        );
-        panic!("[IDX_CNT_RE] Failed to initialize the `IDX_CNT_RE` regular expression.")
-    })
-});
+/* 패턴이 고정값이라 실패하지 않지만, 실패하더라도 panic 대신 체크 에러로 처리 */
+static IDX_CNT_RE: once_lazy<Result<Regex, regex::Error>> =
+    once_lazy::new(|| Regex::new(r"(?i)worked\s*\((?P<num>[\d,]+)\)"));
 
-pub struct MainHandler<N: NotificationService, Q: QueryService> {
+pub struct MainHandler<N: NotificationService, Q: QueryService, S: StatusService> {
     notification_service: N,
     query_service: Q,
+    status_service: S,
+    /* bulk 로 저장하기 전까지 쌓아두는 체크 결과 이력 */
+    history_buffer: Mutex<Vec<CheckHistory>>,
+    /* true 이면 조회만 하고, 쓰기 / 삭제 / 알림 발송 대신 그 내용을 출력 */
+    dry_run: bool,
 }
 
-impl<N: NotificationService, Q: QueryService> MainHandler<N, Q> {
-    pub fn new(notification_service: N, query_service: Q) -> Self {
+impl<N: NotificationService, Q: QueryService, S: StatusService> MainHandler<N, Q, S> {
+    pub fn new(
+        notification_service: N,
+        query_service: Q,
+        status_service: S,
+        dry_run: bool,
+    ) -> Self {
         Self {
             notification_service,
             query_service,
+            status_service,
+            history_buffer: Mutex::new(Vec::new()),
+            dry_run,
+        }
+    }
+
+    #[doc = "dry-run 모드에서 실제로 하지 않은 작업을 로그 / 표준출력으로 남겨주는 함수"]
+    /// # Arguments
+    /// * `action` - 건너뛴 작업 (예: "upsert incident")
+    /// * `detail` - 쓰려던 내용
+    fn print_dry_run(&self, action: &str, detail: &str) {
+        info!("[DRY-RUN] would {}: {}", action, detail);
+        println!("[DRY-RUN] would {}\n{}\n", action, detail);
+    }
+
+    #[doc = "dry-run 모드에서 발송하지 않은 알림들을 채널별 본문(Telegram 은 텍스트, 이메일 / 프로시저는 HTML)과 함께 출력"]
+    fn print_dry_run_notifications(&self, notifications: &[NotificationOutbox]) {
+        for notification in notifications {
+            let body: &str = if notification.channel() == OUTBOX_CHANNEL_TELEGRAM {
+                notification.text_form()
+            } else {
+                notification.html_form()
+            };
+
+            self.print_dry_run(
+                &format!(
+                    "send [{}] notification to {}",
+                    notification.channel(),
+                    notification.recipient()
+                ),
+                &format!("subject: {}\n{}", notification.subject(), body),
+            );
         }
     }
 
+    #[doc = "색인 체크 결과 / 알람 일시중지 상태를 관리하는 서비스를 반환"]
+    pub fn status_service(&self) -> &S {
+        &self.status_service
+    }
+
     #[doc = "메인 스케쥴러 함수"]
     /// # Arguments
     /// * `index_schedule` - 인덱스 스케쥴 객체
@@ -39,17 +89,22 @@ impl<N: NotificationService, Q: QueryService> MainHandler<N, Q> {
     /// # Returns
     /// * Result<(), anyhow::Error>
     pub async fn main_schedule_task(
-        &self,
+        self: Arc<Self>,
         index_schedule: IndexSchedules,
-    ) -> Result<(), anyhow::Error> {
-        let schedule: Schedule = Schedule::from_str(&index_schedule.time).unwrap_or_else(|e| {
-            let err_msg: String = format!(
-                "[main_schedule_task] Failed to parse CRON expression: {:?}",
+    ) -> Result<(), anyhow::Error>
+    where
+        N: Send + Sync + 'static,
+        Q: Send + Sync + 'static,
+        S: Send + Sync + 'static,
+    {
+        let schedule: Schedule = Schedule::from_str(&index_schedule.time).map_err(|e| {
+            anyhow!(
+                "[main_schedule_task] Failed to parse CRON expression '{}' of '{}': {:?}",
+                index_schedule.time,
+                index_schedule.index_name,
                 e
-            );
-            error!("{}", err_msg);
-            panic!("{}", err_msg)
-        });
+            )
+        })?;
 
         let schedule_term: Arc<SystemConfig> = get_system_config_info();
         let mut interval: Interval = tokio::time::interval(tokio::time::Duration::from_millis(
@@ -57,13 +112,7 @@ impl<N: NotificationService, Q: QueryService> MainHandler<N, Q> {
         ));
 
         /* 한국 표준시 GMT + 9 */
-        let kst_offset: FixedOffset = match FixedOffset::east_opt(9 * 3600) {
-            Some(kst_offset) => kst_offset,
-            None => {
-                error!("[main_schedule_task()] There was a problem initializing 'kst_offset'.");
-                panic!("[main_schedule_task()] There was a problem initializing 'kst_offset'.");
-            }
-        };
+        let kst_offset: FixedOffset = get_kst_offset()?;
 
         loop {
             /* 설정한 시간대로 tick check -> schedule_term */
@@ -78,9 +127,34 @@ impl<N: NotificationService, Q: QueryService> MainHandler<N, Q> {
             if let Some(next) = schedule.upcoming(kst_offset).take(1).next() {
                 /*  현재 시각(kst_now)과 **다음 실행 예정 시각(next)**의 차이를 초 단위로 계산. 그 차이가 1초 미만이면 “지금 실행할 시각에 도달했다”고 판단. */
                 if (next - kst_now).num_seconds() < 1 {
-                    self.main_task(&index_schedule).await.unwrap_or_else(|e| {
-                        error!("[main_schedule_task() -> main_task()] {:?}", e);
-                    })
+                    /* 대기(standby) 인스턴스는 체크하지 않는다. */
+                    if !self.is_leader() {
+                        continue;
+                    }
+
+                    self.main_task(&index_schedule, Some(next.naive_utc()))
+                        .await
+                        .unwrap_or_else(|e| {
+                            error!("[main_schedule_task() -> main_task()] {:?}", e);
+                        });
+
+                    /*
+                        DR 클러스터와 문서 수 비교 (설정한 경우만)
+                        delay_sec 동안 기다리므로 따로 실행해서, 다음 CRON 실행 시각을 놓치지 않도록 한다.
+                    */
+                    if let Some(consistency_config) = index_schedule.consistency().clone() {
+                        let handler: Arc<Self> = Arc::clone(&self);
+                        let index_schedule: IndexSchedules = index_schedule.clone();
+
+                        tokio::spawn(async move {
+                            handler
+                                .consistency_task(&index_schedule, &consistency_config)
+                                .await
+                                .unwrap_or_else(|e| {
+                                    error!("[main_schedule_task() -> consistency_task()] {:?}", e);
+                                });
+                        });
+                    }
                 }
             }
         }
@@ -89,259 +163,2233 @@ impl<N: NotificationService, Q: QueryService> MainHandler<N, Q> {
     #[doc = "인덱스 색인 작업 확인 함수"]
     /// # Arguments
     /// * `index_schedule` - 인덱스 스케쥴 객체
+    /// * `scheduled_at` - CRON 실행 예정 시각 (UTC), 수동 체크인 경우 None
     ///
     /// # Returns
     /// * Result<(), anyhow::Error>
-    pub async fn main_task(&self, index_schedule: &IndexSchedules) -> Result<(), anyhow::Error> {
+    pub async fn main_task(
+        &self,
+        index_schedule: &IndexSchedules,
+        scheduled_at: Option<NaiveDateTime>,
+    ) -> Result<(), anyhow::Error> {
         info!("main task start: {}", index_schedule.index_name());
 
+        let check_started_at: Instant = Instant::now();
+
         /* 탐색할 인덱스 이름을 가져온다. */
-        let search_index_name: String = self.build_search_index_name()?;
+        let search_index_name: String = self.build_search_index_name(get_current_utc_naivedate())?;
 
         /* 현재시간, 색인 동작시간 */
         let (curr_time_utc, time_minutes_ago) = calc_time_window(index_schedule.duration);
 
         let system_config: Arc<SystemConfig> = get_system_config_info();
+        let elastic_config: Arc<ElasticServerConfig> = get_elasticsearch_config_info();
 
-        /* 색인 로그 확인 -> ES 쿼리 */
+        /* 색인 로그 확인 -> ES 쿼리 (로그가 있는 클러스터) */
         let vector_index_logs: Option<VectorIndexLogFormat> = self
-            .query_service
-            .get_indexing_movement_log(
+            .find_indexing_log(
+                index_schedule,
                 &search_index_name,
-                index_schedule.index_name(),
-                index_schedule.indexing_type(),
                 time_minutes_ago,
                 curr_time_utc,
             )
-            .await
-            .ok();
+            .await?;
+
+        let log_doc_id: Option<String> = vector_index_logs
+            .as_ref()
+            .map(|log| log.doc_id().to_string());
 
-        if let Some(log) = vector_index_logs {
+        let check_status: IndexCheckStatus = if let Some(log) = vector_index_logs {
             /* 색인자체는 성공한 경우 */
             self.handle_indexing_success(&log, &system_config, index_schedule)
-                .await?;
+                .await?
         } else {
             /* 색인 자체가 실패가 난 경우. */
             self.handle_indexing_failure(&system_config, index_schedule)
-                .await?;
+                .await?
+        };
+
+        /* 성공한 체크도 포함해서 모든 체크 결과를 이력 인덱스에 남긴다. */
+        let mut check_history: CheckHistory = CheckHistory::new(
+            index_schedule.target_cluster_or(elastic_config.elastic_cluster_name()),
+            index_schedule,
+            &check_status,
+            get_currnet_utc_naivedatetime(),
+        );
+        check_history
+            .set_check_latency_ms(check_started_at.elapsed().as_millis() as u64)
+            .set_log_index(search_index_name)
+            .set_log_doc_id(log_doc_id);
+
+        if let Some(scheduled_at) = scheduled_at {
+            check_history.set_scheduled(scheduled_at);
+        }
+
+        self.record_check_history(check_history).await;
+
+        /* 봇 명령어(/status) 조회를 위해 마지막 체크 결과를 기록 */
+        self.status_service.record_check_status(check_status);
+
+        Ok(())
+    }
+
+    #[doc = "기간 안에 완료된 색인 로그를 찾아주는 함수 - 로그가 없거나 형식이 잘못되었으면 None (색인 실패)"]
+    /// # Arguments
+    /// * `index_schedule` - 인덱스 스케쥴 객체
+    /// * `search_index_name` - 색인 로그 인덱스 이름
+    /// * `start_dt` - 탐색 시작 시각 (UTC)
+    /// * `end_dt` - 탐색 종료 시각 (UTC)
+    ///
+    /// # Returns
+    /// * Result<Option<VectorIndexLogFormat>, anyhow::Error> - Elasticsearch 장애 등 인프라 문제면 에러
+    async fn find_indexing_log(
+        &self,
+        index_schedule: &IndexSchedules,
+        search_index_name: &str,
+        start_dt: NaiveDateTime,
+        end_dt: NaiveDateTime,
+    ) -> Result<Option<VectorIndexLogFormat>, anyhow::Error> {
+        let elastic_config: Arc<ElasticServerConfig> = get_elasticsearch_config_info();
+
+        match self
+            .query_service
+            .get_indexing_movement_log(
+                index_schedule.log_cluster_or(elastic_config.elastic_cluster_name()),
+                search_index_name,
+                index_schedule.index_name(),
+                index_schedule.indexing_type(),
+                start_dt,
+                end_dt,
+            )
+            .await
+        {
+            Ok(log) => Ok(Some(log)),
+            /*
+                Elasticsearch 장애 등으로 로그를 조회하지 못한 것은 색인 실패가 아니다.
+                색인 실패 알람을 보내지 않고 체크를 건너뛴다.
+            */
+            Err(e) if AppError::is_infrastructure_error(&e) => Err(e.context(format!(
+                "[find_indexing_log] Skipped the check of '{}' - failed to read indexing logs",
+                index_schedule.index_name()
+            ))),
+            /* 로그가 없거나 형식이 잘못된 경우 -> 색인 실패 */
+            Err(e) => {
+                info!(
+                    "[find_indexing_log] No valid indexing log for '{}': {:?}",
+                    index_schedule.index_name(),
+                    e
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    #[doc = "같은 인덱스의 주(primary) / DR 클러스터 문서 수를 비교해서, 허용 범위를 넘으면 장애로 기록하는 함수"]
+    /// # Arguments
+    /// * `index_schedule` - 인덱스 스케쥴 객체
+    /// * `consistency_config` - 비교할 클러스터 / 허용 범위 설정
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    pub async fn consistency_task(
+        &self,
+        index_schedule: &IndexSchedules,
+        consistency_config: &ConsistencyCheckConfig,
+    ) -> Result<(), anyhow::Error> {
+        /* DR 클러스터에 반영될 때까지 대기 */
+        if *consistency_config.delay_sec() > 0 {
+            sleep(Duration::from_secs(*consistency_config.delay_sec())).await;
+        }
+
+        let elastic_config: Arc<ElasticServerConfig> = get_elasticsearch_config_info();
+        let system_config: Arc<SystemConfig> = get_system_config_info();
+
+        let index_name: &str = index_schedule.index_name();
+        let primary_cluster: &str = consistency_config
+            .primary_cluster()
+            .as_deref()
+            .unwrap_or_else(|| {
+                index_schedule.target_cluster_or(elastic_config.elastic_cluster_name())
+            });
+        let replica_cluster: &str = consistency_config.replica_cluster();
+
+        let (primary_cnt, replica_cnt) = tokio::try_join!(
+            self.query_service
+                .get_doc_count(primary_cluster, index_name),
+            self.query_service
+                .get_doc_count(replica_cluster, index_name)
+        )?;
+
+        let mut check_result: ConsistencyCheckResult = ConsistencyCheckResult::new(
+            primary_cluster.to_string(),
+            replica_cluster.to_string(),
+            primary_cnt,
+            replica_cnt,
+            consistency_config.tolerance(primary_cnt),
+        );
+
+        if let Some(fingerprint_field) = consistency_config.fingerprint_field() {
+            let fingerprint_size: usize = *consistency_config.fingerprint_size();
+
+            let (primary_terms, replica_terms) = tokio::try_join!(
+                self.query_service.get_term_counts(
+                    primary_cluster,
+                    index_name,
+                    fingerprint_field,
+                    fingerprint_size
+                ),
+                self.query_service.get_term_counts(
+                    replica_cluster,
+                    index_name,
+                    fingerprint_field,
+                    fingerprint_size
+                )
+            )?;
+
+            check_result.compare_terms(&primary_terms, &replica_terms, |primary_term_cnt| {
+                consistency_config.tolerance(primary_term_cnt)
+            });
+        }
+
+        /* 장애는 DR 클러스터 기준 - 같은 인덱스의 색인 실패 장애와 구분된다. */
+        if check_result.is_consistent() {
+            info!(
+                "[consistency_task] `{}` is consistent: {}={} / {}={}",
+                index_name, primary_cluster, primary_cnt, replica_cluster, replica_cnt
+            );
+
+            return self
+                .resolve_incidents(
+                    system_config.err_monitor_index(),
+                    replica_cluster,
+                    index_name,
+                    &["Consistency Error"],
+                )
+                .await;
+        }
+
+        warn!(
+            "[consistency_task] `{}` diverged: {}",
+            index_name,
+            check_result.reason()
+        );
+
+        let mut error_alarm_info: ErrorAlarmInfo = ErrorAlarmInfo::new(
+            get_current_kor_naive_datetime_str()?,
+            String::from("Consistency Error"),
+            index_name.to_string(),
+            index_schedule.indexing_type().to_string(),
+            replica_cnt as usize,
+            primary_cnt as usize,
+        );
+        error_alarm_info.set_reason(check_result.reason());
+
+        self.record_incident(
+            system_config.err_monitor_index(),
+            replica_cluster,
+            error_alarm_info,
+        )
+        .await
+    }
+
+    #[doc = "모니터링 프로그램 자체 인덱스의 index template / ILM policy 를 설치해주는 함수 - 여러 번 실행해도 같은 결과"]
+    /// # Returns
+    /// * Result<(), anyhow::Error> - 실패한 단계가 있으면 모아서 반환
+    pub async fn bootstrap_indices(&self) -> Result<(), anyhow::Error> {
+        let system_config: Arc<SystemConfig> = get_system_config_info();
+        let outbox_config: Arc<OutboxConfig> = get_outbox_config_info();
+        let history_config: Arc<HistoryConfig> = get_history_config_info();
+        let index_management_config: Arc<IndexManagementConfig> =
+            get_index_management_config_info();
+
+        if self.dry_run {
+            self.print_dry_run(
+                "install index templates / lifecycle policies",
+                &format!(
+                    "{}, {}, {}, {}",
+                    system_config.err_monitor_index(),
+                    outbox_config.outbox_index(),
+                    get_leader_election_config_info().lease_index(),
+                    history_config.history_index()
+                ),
+            );
+            return Ok(());
+        }
+
+        let mut failures: Vec<String> = Vec::new();
+
+        /* 1. 알람 / 발송 대기열 / 리더 임대 인덱스 - 문서 id 로 수정/삭제하므로 rollover 없이 일반 인덱스로 유지 */
+        let leader_election_config: Arc<LeaderElectionConfig> = get_leader_election_config_info();
+
+        let mut regular_indices: Vec<(&str, Value)> = vec![
+            (
+                system_config.err_monitor_index(),
+                error_alarm_info_mapping(),
+            ),
+            (outbox_config.outbox_index(), notification_outbox_mapping()),
+        ];
+
+        /* 알람 일시중지(snooze) 문서도 임대 인덱스에 저장하므로 리더 선출을 쓰지 않아도 만든다. */
+        regular_indices.push((leader_election_config.lease_index(), leader_lease_mapping()));
+
+        for (index_name, mapping) in regular_indices {
+            let template: Value = build_index_template(index_name, mapping.clone(), None, false);
+
+            if let Err(e) = self
+                .query_service
+                .install_index_template(&format!("{}-template", index_name), &template)
+                .await
+            {
+                failures.push(format!("template of '{}': {}", index_name, e));
+                continue;
+            }
+
+            match self
+                .query_service
+                .update_index_mapping(index_name, &mapping)
+                .await
+            {
+                Ok(true) => info!("[bootstrap_indices] '{}' mapping updated", index_name),
+                Ok(false) => (),
+                Err(e) => failures.push(format!("mapping of '{}': {}", index_name, e)),
+            }
+        }
+
+        /* 2. 체크 이력 - rollover / 보관 기간이 적용되는 data stream */
+        if *history_config.enabled() {
+            let history_index: &str = history_config.history_index();
+            let policy_name: String = format!("{}-policy", history_index);
+
+            /* 모니터링 인덱스는 기본 클러스터에 있다. */
+            let engine: SearchEngine = *get_elasticsearch_config_info().elastic_engine();
+
+            let lifecycle_policy: Option<&str> = if *index_management_config.ilm_enabled() {
+                match self
+                    .query_service
+                    .install_lifecycle_policy(
+                        &policy_name,
+                        &index_management_config.history_lifecycle_policy(engine, history_index),
+                    )
+                    .await
+                {
+                    /* OpenSearch(ISM) 는 policy 의 ism_template 으로 연결되므로 template 에는 지정하지 않는다. */
+                    Ok(()) if engine == SearchEngine::Opensearch => None,
+                    Ok(()) => Some(&policy_name),
+                    Err(e) => {
+                        failures.push(format!("lifecycle policy '{}': {}", policy_name, e));
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+
+            let template: Value = build_index_template(
+                history_index,
+                check_history_mapping(),
+                lifecycle_policy,
+                true,
+            );
+
+            let history_result: Result<(), anyhow::Error> = match self
+                .query_service
+                .install_index_template(&format!("{}-template", history_index), &template)
+                .await
+            {
+                Ok(()) => {
+                    self.query_service
+                        .ensure_data_stream(history_index, &check_history_mapping())
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+
+            if let Err(e) = history_result {
+                failures.push(format!("data stream '{}': {}", history_index, e));
+            }
+        }
+
+        if !failures.is_empty() {
+            return Err(anyhow!(AppError::Elasticsearch(format!(
+                "[MainHandler->bootstrap_indices] {}",
+                failures.join(" / ")
+            ))));
         }
 
+        info!("[bootstrap_indices] Index templates and lifecycle policies are installed");
         Ok(())
     }
 
-    #[doc = "알람관련 로직을 실행하는 함수 -> Telegram 메시지 발송 및 이메일 발송"]
+    #[doc = "체크 결과 이력을 버퍼에 쌓아두는 함수 - 저장은 flush 주기마다 bulk 로 한다. (버퍼가 차면 바로 저장)"]
+    async fn record_check_history(&self, check_history: CheckHistory) {
+        let history_config: Arc<HistoryConfig> = get_history_config_info();
+
+        if !*history_config.enabled() {
+            return;
+        }
+
+        if self.dry_run {
+            self.print_dry_run(
+                &format!(
+                    "write check history to '{}'",
+                    history_config.history_index()
+                ),
+                &serde_json::to_string(&check_history).unwrap_or_default(),
+            );
+            return;
+        }
+
+        let buffered_cnt: usize = {
+            let mut buffer: MutexGuard<'_, Vec<CheckHistory>> = self.lock_history_buffer();
+            buffer.push(check_history);
+            buffer.len()
+        };
+
+        if buffered_cnt >= *history_config.flush_batch_size() {
+            self.flush_check_history().await;
+        }
+    }
+
+    fn lock_history_buffer(&self) -> MutexGuard<'_, Vec<CheckHistory>> {
+        self.history_buffer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    #[doc = "쌓여있는 체크 결과 이력을 bulk 로 저장해주는 함수 - 실패해도 체크 자체는 계속 진행하도록 로깅만 한다."]
+    pub async fn flush_check_history(&self) {
+        let history_config: Arc<HistoryConfig> = get_history_config_info();
+        let pending: Vec<CheckHistory> = std::mem::take(&mut *self.lock_history_buffer());
+
+        if pending.is_empty() {
+            return;
+        }
+
+        /* 다시 시도할 이력 (요청 자체가 실패했거나 429 로 거절된 항목) */
+        let mut requeue: Vec<CheckHistory> = Vec::new();
+
+        for chunk in pending.chunks((*history_config.flush_batch_size()).max(1)) {
+            match self
+                .query_service
+                .post_check_histories(history_config.history_index(), chunk)
+                .await
+            {
+                Ok(bulk_result) => {
+                    for failure in bulk_result.failures() {
+                        error!(
+                            "[MainHandler->flush_check_history] index={} status={} err={:?}",
+                            chunk[*failure.position()].index_name(),
+                            failure.status(),
+                            failure.error()
+                        );
+
+                        if *failure.status() == 429 {
+                            requeue.push(chunk[*failure.position()].clone());
+                        }
+                    }
+                }
+                Err(e) => {
+                    error!("[MainHandler->flush_check_history] {:?}", e);
+                    requeue.extend_from_slice(chunk);
+                }
+            }
+        }
+
+        if requeue.is_empty() {
+            return;
+        }
+
+        /* 다시 시도할 이력이 더 오래되었으므로 앞에 넣고, 최대 개수를 넘으면 오래된 것부터 버린다. */
+        let mut buffer: MutexGuard<'_, Vec<CheckHistory>> = self.lock_history_buffer();
+        requeue.append(&mut buffer);
+
+        let max_buffered: usize = *history_config.max_buffered();
+
+        if requeue.len() > max_buffered {
+            let dropped_cnt: usize = requeue.len() - max_buffered;
+            requeue.drain(..dropped_cnt);
+            error!(
+                "[MainHandler->flush_check_history] History buffer is full, dropped {} record(s)",
+                dropped_cnt
+            );
+        }
+
+        *buffer = requeue;
+    }
+
+    #[doc = "알람관련 로직을 실행하는 함수 -> Telegram / 이메일 알림을 발송 대기열(outbox)에 저장"]
     pub async fn alarm_task(&self) -> Result<(), anyhow::Error> {
         info!("alarm task start");
 
         let system_config: Arc<SystemConfig> = get_system_config_info();
+        let outbox_config: Arc<OutboxConfig> = get_outbox_config_info();
         let err_monitor_index: String = system_config.err_monitor_index().to_string();
 
-        /* Error 관련 인덱스를 조회한다. */
+        /* Error 관련 인덱스에서 열려있는 알람을 조회한다. (ack / 발송된 증분색인 알람 제외) */
         let error_alarm_infos: Vec<ErrorAlarmInfoFormat> = self
             .query_service
             .get_error_alarm_infos(&err_monitor_index)
             .await?;
-        
+
+        /* 이전에 만든 알림이 아직 발송(재시도) 중인 알람은 다시 넣지 않는다. */
+        let pending_alarm_ids: HashSet<String> = self
+            .query_service
+            .get_pending_outbox_alarm_ids(outbox_config.outbox_index())
+            .await?;
+
+        /* 다른 인스턴스(이전 리더)나 재시작 전에 저장된 일시중지도 반영 - 조회에 실패하면 메모리의 값으로 계속 */
+        if let Err(e) = self.load_alarm_snoozes().await {
+            error!("[alarm_task() -> load_alarm_snoozes()] {:?}", e);
+        }
+
+        /* 일시중지(snooze)된 인덱스의 알람은 제외 */
+        let cur_time_kor: NaiveDateTime = get_current_kor_naive_datetime();
+        let error_alarm_infos: Vec<ErrorAlarmInfoFormat> = error_alarm_infos
+            .into_iter()
+            .filter(|alarm| {
+                let info: &ErrorAlarmInfo = alarm.error_alarm_info();
+                !pending_alarm_ids.contains(alarm.doc_id())
+                    && self
+                        .status_service
+                        .get_snoozed_until(info.index_name(), cur_time_kor)
+                        .is_none()
+            })
+            .collect();
+
         if error_alarm_infos.is_empty() {
             info!("No indexing failures");
-        } else {
+            return Ok(());
+        }
+
+        /* 채널/수신자별 알림을 만들어서 발송 대기열에 저장 */
+        let notifications: Vec<NotificationOutbox> = self
+            .notification_service
+            .build_notifications(&error_alarm_infos, get_currnet_utc_naivedatetime())?;
+
+        if self.dry_run {
+            self.print_dry_run_notifications(&notifications);
+            return Ok(());
+        }
 
-            let send_fut = self.send_error_notifications(&error_alarm_infos);
-            let cleanup_fut = self.cleanup_dynamic_index_docs(&err_monitor_index, &error_alarm_infos);
-
-            tokio::try_join!(send_fut, cleanup_fut)?;
-        }
-        //if !error_alaram_infos.is_empty() {
-            /* 알람 내역이 있을 경우 -> 알람 보내주기 */
-            
-
-            /* 동적색인인 경우에는 기록 지워주기 */
-
-            // self.notification_service
-            //     .send_message_to_receivers(&error_alaram_infos)
-            //     .await
-            //     .unwrap_or_else(|e| error!("[MainHandler->alarm_task] {:?}", e));
-            
-            // self.notification_service -> 여기서 실행이 문제가 생겨서 그런거 같은데...? 병렬 처리 해야 될거같은데..?
-            
-            // for alarm in error_alaram_infos {
-            //     let error_alarm_info: &ErrorAlarmInfo = alarm.error_alarm_info();
-            //     let index_type: &str = error_alarm_info.index_type();
-
-            //     /* 증분색인인 경우에는 한번 알람 주고 제거 */
-            //     if index_type == "dynamic index" {
-            //         match self.query_service
-            //             .delete_index_by_doc(&err_monitor_index, alarm.doc_id())
-            //             .await {
-            //                 Ok(_) => {
-            //                     info!("The document with ID {} has been successfully deleted", alarm.doc_id());
-            //                 },
-            //                 Err(e) => {
-            //                     error!("[MainHandler->alarm_task] {:?}", e);
-            //                 }
-            //             }
-            //     }
-            // }
-        //}
+        /*
+            증분색인 알람은 한번만 보내지만, dispatched 상태로 바꾸는 것은 outbox_task 에서
+            알림이 하나라도 발송된 뒤에 한다. (모든 채널의 발송이 만료되면 open 으로 남아 다시 알람)
+        */
+        self.query_service
+            .post_notification_outboxes(outbox_config.outbox_index(), &notifications)
+            .await?
+            .ensure_success()?;
 
         Ok(())
     }
 
-    #[doc = "prod / test 여부에 따라 검색 인덱스명 구성"]
-    fn build_search_index_name(&self) -> Result<String, anyhow::Error> {
-        /* 현재 프로그램실행 type -> prod type 인지 아닌지 체크 */
-        let code_config: Arc<CodeConfig> = get_code_config_info();
-        
-        if code_config.code_type().as_str() == "prod" {
-            let curr_date_utc: String =
-                get_current_utc_naivedate_str("%Y-%m-%d").unwrap_or_else(|e| {
-                    error!(
-                        "[MainHandler->build_search_index_name] curr_date_utc error: {:?}",
-                        e
-                    );
-                    panic!(
-                        "[MainHandler->build_search_index_name] curr_date_utc error: {:?}",
-                        e
-                    );
-                });
-            let search_index: Arc<SystemConfig> = get_system_config_info();
-            Ok(format!(
-                "{}-{}",
-                search_index.log_index_name(),
-                curr_date_utc
-            ))
-        } else {
-            Ok("vector-indexing-logs-2025-01-08".to_string())
-        }
-    }
+    #[doc = "발송 대기열(outbox)의 알림을 발송하고, 실패한 알림은 backoff 후 재시도하도록 갱신하는 함수"]
+    pub async fn outbox_task(&self) -> Result<(), anyhow::Error> {
+        let outbox_config: Arc<OutboxConfig> = get_outbox_config_info();
+        let outbox_index: &str = outbox_config.outbox_index();
 
-    #[doc = "색인 로그가 존재하는 경우 처리 (Partial Error or Success)"]
-    async fn handle_indexing_success(
-        &self,
-        log: &VectorIndexLogFormat,
-        system_config: &SystemConfig,
-        index_schedule: &IndexSchedules,
-    ) -> Result<(), anyhow::Error> {
-        let log_detail: &str = log.vector_index_log.message().as_str();
-        let expected_size: usize = index_schedule.size;
+        let due_entries: Vec<NotificationOutboxFormat> = self
+            .query_service
+            .get_due_outbox_entries(
+                outbox_index,
+                get_currnet_utc_naivedatetime(),
+                *outbox_config.dispatch_batch_size(),
+            )
+            .await?;
 
-        if let Some(caps) = IDX_CNT_RE.captures(log_detail) {
-            if let Some(num) = caps.name("num") {
-                let n: usize = num.as_str().replace(',', "").parse::<usize>()?; /* 실제 색인된 문서의 개수 */
-
-                /* 실제 색인된 문서의 개수가 설정한 문서의 개수보다 작은 경우 */
-                if n < expected_size {
-                    let err_monitor_index: String = system_config.err_monitor_index().to_string();
-                    let cur_time_kor_str: String = get_current_kor_naive_datetime_str()?; /* 현재 시각을 문자열로 표시함 */
-
-                    /* 색인은 성공했지만, 색인 개수가 올바르지 않은 경우. */
-                    let error_alarm_info: ErrorAlarmInfo = ErrorAlarmInfo::new(
-                        cur_time_kor_str,
-                        String::from("Partial Error"),
-                        index_schedule.index_name().to_string(),
-                        index_schedule.indexing_type().to_string(),
-                        n,
-                        *index_schedule.size(),
-                    );
+        if due_entries.is_empty() {
+            return Ok(());
+        }
 
-                    self.query_service
-                        .post_indexing_error_info(&err_monitor_index, error_alarm_info)
-                        .await?;
-                } else {
-                    /* 색인이 문제없이 잘 된 경우 */
-                    info!(
-                        "Indexing of `{}({})` completed successfully.",
-                        log.vector_index_log.index_name(),
-                        log.vector_index_log.state()
-                    );
-                }
-            }
+        info!("outbox task start: {} notification(s)", due_entries.len());
+
+        if self.dry_run {
+            let notifications: Vec<NotificationOutbox> = due_entries
+                .iter()
+                .map(|entry| entry.notification_outbox().clone())
+                .collect();
+            self.print_dry_run_notifications(&notifications);
+            return Ok(());
         }
 
-        Ok(())
-    }
+        let concurrency: usize = 8;
 
-    #[doc = "색인 로그가 없는 경우 처리(Full Error)"]
-    async fn handle_indexing_failure(
-        &self,
-        system_config: &SystemConfig,
-        index_schedule: &IndexSchedules,
-    ) -> Result<(), anyhow::Error> {
-        /* 색인 자체가 실패가 난 경우. */
-        let err_monitor_index: String = system_config.err_monitor_index().to_string();
-        let cur_time_kor_str: String = get_current_kor_naive_datetime_str()?; /* 현재 시각을 문자열로 표시함 */
+        /* 발송된 알림이 다루는 알람 문서를 찾기 위해 outbox 문서 id -> 알람 문서 id 목록 */
+        let alarm_doc_ids_by_entry: HashMap<String, Vec<String>> = due_entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.doc_id().to_string(),
+                    entry.notification_outbox().alarm_doc_ids().clone(),
+                )
+            })
+            .collect();
 
-        let error_alarm_info: ErrorAlarmInfo = ErrorAlarmInfo::new(
-            cur_time_kor_str,
-            String::from("Full Error"),
-            index_schedule.index_name().to_string(),
-            index_schedule.indexing_type().to_string(),
-            0,
-            *index_schedule.size(),
-        );
+        /* 발송은 병렬로 하고, 결과는 모아서 bulk 로 한번에 반영 */
+        let settlements: Vec<(String, OutboxSettlement)> = stream::iter(due_entries)
+            .map(|entry| {
+                let outbox_config: &OutboxConfig = &outbox_config;
+                async move { self.dispatch_outbox_entry(outbox_config, entry).await }
+            })
+            .buffer_unordered(concurrency)
+            .filter_map(|result| async move {
+                result
+                    .map_err(|e| error!("[MainHandler->outbox_task] {:?}", e))
+                    .ok()
+            })
+            .collect()
+            .await;
 
-        /* Elasticsearch 로그 인덱스로 실패건 전송 */
-        self.query_service
-            .post_indexing_error_info(&err_monitor_index, error_alarm_info)
-            .await?;
+        /*
+            증분색인 알람은 알림이 하나라도 발송되면 dispatched 로 바꾼다.
+            outbox 문서를 지우기 전에 바꿔야 alarm_task 가 같은 알람을 다시 넣지 않는다.
+        */
+        let delivered_alarm_doc_ids: Vec<String> = settlements
+            .iter()
+            .filter(|(_, settlement)| matches!(settlement, OutboxSettlement::Delivered))
+            .filter_map(|(doc_id, _)| alarm_doc_ids_by_entry.get(doc_id))
+            .flatten()
+            .cloned()
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .collect();
 
-        Ok(())
-    }   
+        self.mark_dynamic_alarms_dispatched(&delivered_alarm_doc_ids)
+            .await;
 
-    #[doc = "알람 내역이 있을 경우 -> 알림을 발송 (실패해도 전체가 멈추지 않게 내부에서 로깅)"]
-    async fn send_error_notifications(&self, error_alaram_infos: &[ErrorAlarmInfoFormat]) -> Result<(), anyhow::Error> {
+        let bulk_result: BulkResult = self
+            .query_service
+            .settle_outbox_entries(outbox_index, &settlements)
+            .await?;
 
-        self.notification_service
-                .send_message_to_receivers(error_alaram_infos)
-                .await
-                .unwrap_or_else(|e| error!("[MainHandler->alarm_task] {:?}", e));
+        /* 반영에 실패한 알림은 다음 주기에 다시 발송될 수 있다. */
+        for failure in bulk_result.failures() {
+            error!(
+                "[MainHandler->outbox_task] doc_id={} status={} err={:?}",
+                settlements[*failure.position()].0,
+                failure.status(),
+                failure.error()
+            );
+        }
+
+        info!(
+            "outbox task end: {} of {} notification(s) settled",
+            bulk_result.success_cnt(),
+            settlements.len()
+        );
 
         Ok(())
-    } 
+    }
 
-    #[doc = "증분색인(dynamic index) 문서 정리: 병렬 삭제(동시 N개), 실패한 건만 로깅"]
-    async fn cleanup_dynamic_index_docs(
+    #[doc = "발송 대기 알림 한 건을 보내고 outbox 에 반영할 결과를 만들어주는 함수"]
+    async fn dispatch_outbox_entry(
         &self,
-        err_monitor_index: &str,
-        infos: &[ErrorAlarmInfoFormat],
-    ) -> Result<(), anyhow::Error> {
+        outbox_config: &OutboxConfig,
+        entry: NotificationOutboxFormat,
+    ) -> Result<(String, OutboxSettlement), anyhow::Error> {
+        let doc_id: String = entry.doc_id().to_string();
+        let mut notification: NotificationOutbox = entry.notification_outbox().clone();
 
-        let concurrency: usize = 8;
+        let err: anyhow::Error = match self
+            .notification_service
+            .deliver_notification(&notification)
+            .await
+        {
+            Ok(_) => {
+                /* 발송 완료된 알림은 대기열에서 제거 */
+                return Ok((doc_id, OutboxSettlement::Delivered));
+            }
+            Err(e) => e,
+        };
 
-         stream::iter(infos.iter().filter(|a| {
-            a.error_alarm_info().index_type() == "dynamic index"
-        }))
-        .for_each_concurrent(concurrency, |alarm| async move {
+        let now_utc: NaiveDateTime = get_currnet_utc_naivedatetime();
+        let attempts: u32 = notification.attempts() + 1;
 
-            match self.query_service
-                .delete_index_by_doc(err_monitor_index, alarm.doc_id())
-                .await
-            {
-                Ok(_) => info!("Deleted doc_id={}", alarm.doc_id()),
-                Err(e) => error!("[cleanup_dynamic_index_docs] doc_id={} err={:?}", alarm.doc_id(), e),
-            }
+        notification
+            .set_attempts(attempts)
+            .set_last_error(format!("{:?}", err));
 
-        }).await;
-
-        // for alarm in infos {
-        //         let error_alarm_info: &ErrorAlarmInfo = alarm.error_alarm_info();
-        //         let index_type: &str = error_alarm_info.index_type();
-
-        //         /* 증분색인인 경우에는 한번 알람 주고 제거 */
-        //         if index_type == "dynamic index" {
-        //             match self.query_service
-        //                 .delete_index_by_doc(&err_monitor_index, alarm.doc_id())
-        //                 .await {
-        //                     Ok(_) => {
-        //                         info!("The document with ID {} has been successfully deleted", alarm.doc_id());
-        //                     },
-        //                     Err(e) => {
-        //                         error!("[MainHandler->alarm_task] {:?}", e);
-        //                     }
-        //                 }
-        //         }
-        //     }
+        if attempts >= *outbox_config.max_attempts() || notification.is_expired(now_utc) {
+            error!(
+                "[MainHandler->dispatch_outbox_entry] Gave up sending [{}] notification to {} after {} attempt(s): {:?}",
+                notification.channel(),
+                notification.recipient(),
+                attempts,
+                err
+            );
+            notification.set_status(OUTBOX_STATUS_EXPIRED.to_string());
+        } else {
+            let next_attempt_at: NaiveDateTime =
+                now_utc + chrono::Duration::seconds(outbox_config.backoff_sec(attempts));
+
+            error!(
+                "[MainHandler->dispatch_outbox_entry] Failed sending [{}] notification to {} (attempt {}), retry at {}: {:?}",
+                notification.channel(),
+                notification.recipient(),
+                attempts,
+                next_attempt_at,
+                err
+            );
+            let next_attempt_at_str: String =
+                get_str_from_naive_datetime(next_attempt_at, OUTBOX_TIME_FORMAT)?;
+            notification.set_next_attempt_at(next_attempt_at_str);
+        }
+
+        Ok((doc_id, OutboxSettlement::Failed(Box::new(notification))))
+    }
+
+    #[doc = "보관 기간이 지난 알람 / 발송 대기열 문서 정리 스케쥴러 함수"]
+    /// # Arguments
+    /// * `cron` - 정리 시각 CRON 표현식 (한국시간)
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    pub async fn cleanup_schedule_task(&self, cron: String) -> Result<(), anyhow::Error> {
+        let schedule: Schedule = Schedule::from_str(&cron).map_err(|e| {
+            anyhow!(
+                "[cleanup_schedule_task] Failed to parse CRON expression '{}': {:?}",
+                cron,
+                e
+            )
+        })?;
+
+        let schedule_term: Arc<SystemConfig> = get_system_config_info();
+        let mut interval: Interval = tokio::time::interval(tokio::time::Duration::from_millis(
+            schedule_term.schedule_term,
+        ));
+
+        let kst_offset: FixedOffset = get_kst_offset()?;
+
+        loop {
+            wait_for_cron(&schedule, &mut interval, kst_offset).await;
+
+            if !self.is_leader() {
+                continue;
+            }
+
+            self.cleanup_task().await.unwrap_or_else(|e| {
+                error!("[cleanup_schedule_task() -> cleanup_task()] {:?}", e);
+            })
+        }
+    }
+
+    #[doc = "확인/발송 완료된 오래된 알람과 발송을 포기한 오래된 알림을 삭제하는 함수 - 열린 알람은 지우지 않는다."]
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    pub async fn cleanup_task(&self) -> Result<(), anyhow::Error> {
+        let system_config: Arc<SystemConfig> = get_system_config_info();
+        let outbox_config: Arc<OutboxConfig> = get_outbox_config_info();
+        let index_management_config: Arc<IndexManagementConfig> =
+            get_index_management_config_info();
+
+        let alarm_before: NaiveDateTime = get_current_kor_naive_datetime()
+            - chrono::Duration::days(*index_management_config.alarm_retention_days());
+        let outbox_before: NaiveDateTime = Utc::now().naive_utc()
+            - chrono::Duration::days(*index_management_config.outbox_retention_days());
+
+        if self.dry_run {
+            self.print_dry_run(
+                "delete old documents",
+                &format!(
+                    "closed alarms in '{}' before {} (KST), expired notifications in '{}' before {} (UTC)",
+                    system_config.err_monitor_index(),
+                    alarm_before,
+                    outbox_config.outbox_index(),
+                    outbox_before
+                ),
+            );
+            return Ok(());
+        }
+
+        let alarm_cnt: u64 = self
+            .query_service
+            .delete_closed_alarms(system_config.err_monitor_index(), alarm_before)
+            .await?;
+
+        let outbox_cnt: u64 = self
+            .query_service
+            .delete_expired_outbox(outbox_config.outbox_index(), outbox_before)
+            .await?;
+
+        info!(
+            "[cleanup_task] Deleted {} closed alarm(s), {} expired notification(s)",
+            alarm_cnt, outbox_cnt
+        );
+
+        Ok(())
+    }
+
+    #[doc = "일일 상태 요약(digest) 스케쥴러 함수"]
+    /// # Arguments
+    /// * `index_schedules` - 모니터링 대상 인덱스 스케쥴 목록
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    pub async fn digest_schedule_task(
+        &self,
+        index_schedules: Vec<IndexSchedules>,
+    ) -> Result<(), anyhow::Error> {
+        let digest_config: Arc<DigestConfig> = get_digest_config_info();
+
+        let schedule: Schedule = Schedule::from_str(digest_config.cron()).map_err(|e| {
+            anyhow!(
+                "[digest_schedule_task] Failed to parse CRON expression '{}': {:?}",
+                digest_config.cron(),
+                e
+            )
+        })?;
+
+        let schedule_term: Arc<SystemConfig> = get_system_config_info();
+        let mut interval: Interval = tokio::time::interval(tokio::time::Duration::from_millis(
+            schedule_term.schedule_term,
+        ));
+
+        let kst_offset: FixedOffset = get_kst_offset()?;
+
+        loop {
+            wait_for_cron(&schedule, &mut interval, kst_offset).await;
+
+            if !self.is_leader() {
+                continue;
+            }
+
+            self.digest_task(&index_schedules)
+                .await
+                .unwrap_or_else(|e| {
+                    error!("[digest_schedule_task() -> digest_task()] {:?}", e);
+                })
+        }
+    }
+
+    #[doc = "모든 인덱스의 마지막 체크 결과 / 열린 알람 개수를 요약해서 발송 대기열(outbox)에 저장하는 함수"]
+    /// # Arguments
+    /// * `index_schedules` - 모니터링 대상 인덱스 스케쥴 목록
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    pub async fn digest_task(
+        &self,
+        index_schedules: &[IndexSchedules],
+    ) -> Result<(), anyhow::Error> {
+        info!("digest task start");
+
+        let system_config: Arc<SystemConfig> = get_system_config_info();
+        let outbox_config: Arc<OutboxConfig> = get_outbox_config_info();
+
+        /* 인덱스별 확인(ack) 되지 않은 알람 개수 */
+        let error_alarm_infos: Vec<ErrorAlarmInfoFormat> = self
+            .query_service
+            .get_error_alarm_infos(system_config.err_monitor_index())
+            .await?;
+
+        let mut open_alarm_cnt_map: HashMap<String, usize> = HashMap::new();
+
+        for alarm in &error_alarm_infos {
+            *open_alarm_cnt_map
+                .entry(alarm.error_alarm_info().index_name().to_string())
+                .or_default() += 1;
+        }
+
+        let cur_time_kor: NaiveDateTime = get_current_kor_naive_datetime();
+
+        let items: Vec<DigestTemplateItem> = index_schedules
+            .iter()
+            .map(|index_schedule| {
+                let index_name: &str = index_schedule.index_name();
+
+                DigestTemplateItem::new(
+                    index_schedule,
+                    self.status_service.get_check_status(index_name).as_ref(),
+                    open_alarm_cnt_map.get(index_name).copied().unwrap_or(0),
+                    self.status_service
+                        .get_snoozed_until(index_name, cur_time_kor),
+                )
+            })
+            .collect();
+
+        let notifications: Vec<NotificationOutbox> =
+            self.notification_service.build_digest_notifications(
+                items,
+                &cur_time_kor.format("%Y-%m-%d %H:%M:%S").to_string(),
+                get_currnet_utc_naivedatetime(),
+            )?;
+
+        if self.dry_run {
+            self.print_dry_run_notifications(&notifications);
+            return Ok(());
+        }
+
+        self.query_service
+            .post_notification_outboxes(outbox_config.outbox_index(), &notifications)
+            .await?
+            .ensure_success()?;
+
+        Ok(())
+    }
+
+    #[doc = "SLA 리포트 스케쥴러 함수"]
+    /// # Arguments
+    /// * `index_schedules` - 모니터링 대상 인덱스 스케쥴 목록
+    /// * `period` - 리포트 기간 종류 (weekly / monthly)
+    /// * `cron` - 발송 시각 CRON 표현식 (한국시간)
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    pub async fn report_schedule_task(
+        &self,
+        index_schedules: Vec<IndexSchedules>,
+        period: ReportPeriod,
+        cron: String,
+    ) -> Result<(), anyhow::Error> {
+        let schedule: Schedule = Schedule::from_str(&cron).map_err(|e| {
+            anyhow!(
+                "[report_schedule_task] Failed to parse CRON expression '{}': {:?}",
+                cron,
+                e
+            )
+        })?;
+
+        let schedule_term: Arc<SystemConfig> = get_system_config_info();
+        let mut interval: Interval = tokio::time::interval(tokio::time::Duration::from_millis(
+            schedule_term.schedule_term,
+        ));
+
+        let kst_offset: FixedOffset = get_kst_offset()?;
+
+        loop {
+            wait_for_cron(&schedule, &mut interval, kst_offset).await;
+
+            if !self.is_leader() {
+                continue;
+            }
+
+            self.report_task(&index_schedules, period)
+                .await
+                .unwrap_or_else(|e| {
+                    error!("[report_schedule_task() -> report_task()] {:?}", e);
+                })
+        }
+    }
+
+    #[doc = "직전 기간의 SLA 리포트를 만들어서 이메일 발송 대기열(outbox)에 저장하는 함수"]
+    /// # Arguments
+    /// * `index_schedules` - 모니터링 대상 인덱스 스케쥴 목록
+    /// * `period` - 리포트 기간 종류 (weekly / monthly)
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    pub async fn report_task(
+        &self,
+        index_schedules: &[IndexSchedules],
+        period: ReportPeriod,
+    ) -> Result<(), anyhow::Error> {
+        info!("report task start: {}", period.name());
+
+        let outbox_config: Arc<OutboxConfig> = get_outbox_config_info();
+        let (period_start, period_end) = period.range_before(get_current_kor_naive_datetime())?;
+
+        let sla_report: SlaReport = self
+            .build_sla_report(index_schedules, period.name(), period_start, period_end)
+            .await?;
+
+        let notifications: Vec<NotificationOutbox> = self
+            .notification_service
+            .build_report_notifications(&sla_report, get_currnet_utc_naivedatetime())?;
+
+        if self.dry_run {
+            self.print_dry_run_notifications(&notifications);
+            return Ok(());
+        }
+
+        self.query_service
+            .post_notification_outboxes(outbox_config.outbox_index(), &notifications)
+            .await?
+            .ensure_success()?;
+
+        Ok(())
+    }
+
+    #[doc = "기간 [period_start, period_end) 의 인덱스별 SLA 리포트를 만들어주는 함수"]
+    /// # Arguments
+    /// * `index_schedules` - 모니터링 대상 인덱스 스케쥴 목록
+    /// * `period_name` - 리포트 기간 이름 (weekly / monthly / custom)
+    /// * `period_start` - 집계 시작 시각 (한국시간, 포함)
+    /// * `period_end` - 집계 종료 시각 (한국시간, 미포함)
+    ///
+    /// # Returns
+    /// * Result<SlaReport, anyhow::Error>
+    pub async fn build_sla_report(
+        &self,
+        index_schedules: &[IndexSchedules],
+        period_name: &str,
+        period_start: NaiveDateTime,
+        period_end: NaiveDateTime,
+    ) -> Result<SlaReport, anyhow::Error> {
+        let system_config: Arc<SystemConfig> = get_system_config_info();
+        let elastic_config: Arc<ElasticServerConfig> = get_elasticsearch_config_info();
+
+        /* 아직 오지 않은 시각의 체크는 집계하지 않는다. */
+        let cur_time_kor: NaiveDateTime = get_current_kor_naive_datetime();
+        let check_end: NaiveDateTime = period_end.min(cur_time_kor);
+
+        let error_alarm_history: Vec<ErrorAlarmInfo> = self
+            .query_service
+            .get_error_alarm_history(system_config.err_monitor_index(), period_start, check_end)
+            .await?;
+
+        let kst_offset: FixedOffset = get_kst_offset()?;
+        let mut indexes: Vec<IndexSlaReport> = Vec::new();
+
+        for index_schedule in index_schedules {
+            /* 체크 이력이 있는 구간은 이력을 그대로 사용한다. */
+            let mut history_records: Vec<CheckRecord> = self
+                .get_check_history_records(index_schedule, period_start, check_end, kst_offset)
+                .await?;
+            history_records.sort_by_key(|record| *record.checked_at());
+
+            /* 이력이 기록되기 전 구간은 CRON 실행 시각과 실패 기록으로 추정한다. */
+            let history_start: NaiveDateTime = history_records
+                .first()
+                .map(|record| *record.checked_at())
+                .unwrap_or(check_end);
+
+            let failures: Vec<&ErrorAlarmInfo> = error_alarm_history
+                .iter()
+                .filter(|alarm| alarm.index_name() == index_schedule.index_name())
+                /* DR 정합성 알람은 색인 체크 실패가 아니다. */
+                .filter(|alarm| alarm.error_type() != "Consistency Error")
+                .filter(|alarm| {
+                    NaiveDateTime::parse_from_str(
+                        alarm.first_seen_or_timestamp(),
+                        "%Y-%m-%dT%H:%M:%SZ",
+                    )
+                    .map_or(true, |first_seen| first_seen < history_start)
+                })
+                .collect();
+
+            let mut records: Vec<CheckRecord> =
+                build_check_records(index_schedule, &failures, period_start, history_start)?;
+            records.extend(history_records);
+
+            indexes.push(IndexSlaReport::from_records(index_schedule, &records));
+        }
+
+        Ok(SlaReport::new(
+            elastic_config.elastic_cluster_name(),
+            period_name,
+            period_start,
+            period_end,
+            cur_time_kor,
+            indexes,
+        ))
+    }
+
+    #[doc = "기간 [start, end) 의 체크 이력을 리포트 집계용 체크 결과로 가져오는 함수"]
+    /// # Arguments
+    /// * `index_schedule` - 인덱스 스케쥴 객체
+    /// * `start` - 집계 시작 시각 (한국시간, 포함)
+    /// * `end` - 집계 종료 시각 (한국시간, 미포함)
+    /// * `kst_offset` - 한국 표준시 offset
+    ///
+    /// # Returns
+    /// * Result<Vec<CheckRecord>, anyhow::Error>
+    async fn get_check_history_records(
+        &self,
+        index_schedule: &IndexSchedules,
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        kst_offset: FixedOffset,
+    ) -> Result<Vec<CheckRecord>, anyhow::Error> {
+        let history_config: Arc<HistoryConfig> = get_history_config_info();
+
+        if !*history_config.enabled() {
+            return Ok(Vec::new());
+        }
+
+        /* 체크 이력의 시각은 UTC 로 저장되어 있다. */
+        let utc_diff: chrono::Duration =
+            chrono::Duration::seconds(kst_offset.local_minus_utc() as i64);
+
+        self.query_service
+            .get_check_history(
+                history_config.history_index(),
+                index_schedule.index_name(),
+                start - utc_diff,
+                end - utc_diff,
+            )
+            .await?
+            .iter()
+            .map(|check_history| check_history.to_check_record(kst_offset))
+            .collect()
+    }
+
+    #[doc = "SLA 리포트를 CSV / JSON 으로 내보내는 함수 (report 명령어)"]
+    /// # Arguments
+    /// * `index_schedules` - 모니터링 대상 인덱스 스케쥴 목록
+    /// * `report_args` - report 명령어 인자
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    pub async fn export_sla_report(
+        &self,
+        index_schedules: &[IndexSchedules],
+        report_args: &ReportArgs,
+    ) -> Result<(), anyhow::Error> {
+        let (period_name, period_start, period_end) =
+            report_args.resolve_range(get_current_kor_naive_datetime())?;
+
+        let sla_report: SlaReport = self
+            .build_sla_report(index_schedules, &period_name, period_start, period_end)
+            .await?;
+
+        let exported: String = match report_args.format() {
+            ReportFormat::Csv => sla_report.to_csv(),
+            ReportFormat::Json => serde_json::to_string_pretty(&sla_report)?,
+        };
+
+        write_or_print(report_args.output().as_deref(), &exported)
+    }
+
+    #[doc = "과거 기간의 CRON 실행 시각들을 다시 평가해서 내보내는 함수 (replay 명령어) - 알람 / 이력은 남기지 않는다."]
+    /// # Arguments
+    /// * `index_schedules` - 모니터링 대상 인덱스 스케쥴 목록
+    /// * `replay_args` - replay 명령어 인자
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    pub async fn export_replay_report(
+        &self,
+        index_schedules: &[IndexSchedules],
+        replay_args: &ReplayArgs,
+    ) -> Result<(), anyhow::Error> {
+        let (period_start, period_end) = replay_args.resolve_range()?;
+
+        /* --index 를 지정하지 않으면 모든 인덱스 */
+        let target_schedules: Vec<&IndexSchedules> = if replay_args.indexes().is_empty() {
+            index_schedules.iter().collect()
+        } else {
+            replay_args
+                .indexes()
+                .iter()
+                .map(|index_name| {
+                    index_schedules
+                        .iter()
+                        .find(|schedule| schedule.index_name() == index_name)
+                        .ok_or_else(|| {
+                            anyhow!(AppError::Config(format!(
+                                "Unknown index '{}' - not in index_list",
+                                index_name
+                            )))
+                        })
+                })
+                .collect::<Result<Vec<&IndexSchedules>, anyhow::Error>>()?
+        };
+
+        let replay_report: ReplayReport = self
+            .build_replay_report(&target_schedules, period_start, period_end)
+            .await?;
+
+        let exported: String = match replay_args.format() {
+            ReplayFormat::Table => replay_report.to_table(),
+            ReplayFormat::Csv => replay_report.to_csv(),
+            ReplayFormat::Json => serde_json::to_string_pretty(&replay_report)?,
+        };
+
+        write_or_print(replay_args.output().as_deref(), &exported)
+    }
+
+    #[doc = "기간 안의 CRON 실행 시각마다 과거 색인 로그로 체크를 다시 평가해주는 함수"]
+    /// # Arguments
+    /// * `index_schedules` - 다시 평가할 인덱스 스케쥴 목록
+    /// * `period_start` - 시작 시각 (한국시간, 포함)
+    /// * `period_end` - 종료 시각 (한국시간, 미포함) - 현재 시각 이후의 실행 시각은 제외
+    ///
+    /// # Returns
+    /// * Result<ReplayReport, anyhow::Error>
+    pub async fn build_replay_report(
+        &self,
+        index_schedules: &[&IndexSchedules],
+        period_start: NaiveDateTime,
+        period_end: NaiveDateTime,
+    ) -> Result<ReplayReport, anyhow::Error> {
+        let max_slots: usize = 10000;
+        let concurrency: usize = 4;
+
+        let kst_offset: FixedOffset = get_kst_offset()?;
+        let start_kst: DateTime<FixedOffset> = period_start
+            .and_local_timezone(kst_offset)
+            .single()
+            .ok_or_else(|| {
+                anyhow!(
+                    "[MainHandler->build_replay_report] Invalid start time: {}",
+                    period_start
+                )
+            })?;
+        let end_kst: NaiveDateTime = period_end.min(get_current_kor_naive_datetime());
+
+        let mut replay_targets: Vec<(&IndexSchedules, DateTime<FixedOffset>)> = Vec::new();
+
+        for index_schedule in index_schedules {
+            let schedule: Schedule = Schedule::from_str(index_schedule.time()).map_err(|e| {
+                anyhow!(AppError::Config(format!(
+                    "Failed to parse CRON expression of {}: {:?}",
+                    index_schedule.index_name(),
+                    e
+                )))
+            })?;
+
+            replay_targets.extend(
+                schedule
+                    .after(&(start_kst - chrono::Duration::seconds(1)))
+                    .take_while(|slot| slot.naive_local() < end_kst)
+                    .take(max_slots + 1)
+                    .map(|slot| (*index_schedule, slot)),
+            );
+
+            if replay_targets.len() > max_slots {
+                return Err(anyhow!(
+                    "[MainHandler->build_replay_report] More than {} check slots in {} ~ {} - narrow the period or use --index",
+                    max_slots,
+                    period_start,
+                    period_end
+                ));
+            }
+        }
+
+        /* 조회만 하므로 병렬로 실행하고, 결과는 인덱스 / 실행 시각 순서를 유지한다. */
+        let slots: Vec<ReplaySlot> = stream::iter(replay_targets)
+            .map(|(index_schedule, scheduled_at)| self.replay_slot(index_schedule, scheduled_at))
+            .buffered(concurrency)
+            .collect()
+            .await;
+
+        Ok(ReplayReport::new(period_start, period_end, slots))
+    }
+
+    #[doc = "CRON 실행 시각 하나를 정해진 체크와 같은 기준으로 다시 평가해주는 함수"]
+    /// # Arguments
+    /// * `index_schedule` - 인덱스 스케쥴 객체
+    /// * `scheduled_at` - CRON 실행 시각 (한국시간)
+    ///
+    /// # Returns
+    /// * ReplaySlot - 로그를 조회하지 못했으면 outcome 이 Skipped
+    async fn replay_slot(
+        &self,
+        index_schedule: &IndexSchedules,
+        scheduled_at: DateTime<FixedOffset>,
+    ) -> ReplaySlot {
+        /* 정해진 체크와 같이 실행 시각 직전 duration 초 동안의 색인 로그를 찾는다. */
+        let slot_utc: NaiveDateTime = scheduled_at.naive_utc();
+        let window_start_utc: NaiveDateTime =
+            slot_utc - chrono::Duration::seconds(*index_schedule.duration());
+
+        let mut replay_slot: ReplaySlot = ReplaySlot::new(index_schedule, scheduled_at);
+
+        let log_index: String = match self.build_search_index_name(slot_utc.date()) {
+            Ok(log_index) => log_index,
+            Err(e) => {
+                replay_slot.detail = e.to_string();
+                return replay_slot;
+            }
+        };
+        replay_slot.log_index = log_index;
+
+        match self
+            .find_indexing_log(
+                index_schedule,
+                &replay_slot.log_index,
+                window_start_utc,
+                slot_utc,
+            )
+            .await
+        {
+            Ok(Some(log)) => {
+                replay_slot.log_timestamp = Some(log.vector_index_log().timestamp().to_string());
+
+                match evaluate_indexing_log(
+                    log.vector_index_log().message(),
+                    *index_schedule.size(),
+                ) {
+                    Ok(("Unknown", _)) => {
+                        replay_slot.outcome = String::from("Unknown");
+                        replay_slot.detail =
+                            String::from("Could not read the indexed count from the log message");
+                    }
+                    Ok((outcome, n)) => {
+                        replay_slot.outcome = outcome.to_string();
+                        replay_slot.indexing_cnt_num = Some(n);
+                    }
+                    Err(e) => replay_slot.detail = e.to_string(),
+                }
+            }
+            Ok(None) => {
+                replay_slot.outcome = String::from("Full Error");
+                replay_slot.detail = String::from("No indexing log in the check window");
+            }
+            Err(e) => replay_slot.detail = format!("{:#}", e),
+        }
+
+        replay_slot
+    }
+
+    #[doc = "특정 인덱스의 열린 알람을 확인(ack) 처리해주는 함수"]
+    /// # Arguments
+    /// * `index_name` - 알람을 확인 처리할 색인 대상 인덱스 이름
+    ///
+    /// # Returns
+    /// * Result<u64, anyhow::Error> - 확인 처리된 알람 개수
+    pub async fn acknowledge_index(&self, index_name: &str) -> Result<u64, anyhow::Error> {
+        let system_config: Arc<SystemConfig> = get_system_config_info();
+
+        if self.dry_run {
+            self.print_dry_run(
+                &format!(
+                    "acknowledge open alarms in '{}'",
+                    system_config.err_monitor_index()
+                ),
+                index_name,
+            );
+            return Ok(0);
+        }
+
+        self.query_service
+            .acknowledge_alarms_by_index(system_config.err_monitor_index(), index_name)
+            .await
+    }
+
+    #[doc = "특정 인덱스의 알람을 until 시각까지 일시중지하는 함수 - 임대 인덱스에 저장해서 재시작 / 리더 변경 후에도 유지된다."]
+    /// # Arguments
+    /// * `index_name` - 알람을 멈출 색인 대상 인덱스 이름
+    /// * `until` - 일시중지 만료 시각 (한국시간)
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    pub async fn snooze_index(
+        &self,
+        index_name: &str,
+        until: NaiveDateTime,
+    ) -> Result<(), anyhow::Error> {
+        let leader_election_config: Arc<LeaderElectionConfig> = get_leader_election_config_info();
+        let kst_offset: FixedOffset = get_kst_offset()?;
+
+        let until_utc: NaiveDateTime = until
+            .and_local_timezone(kst_offset)
+            .single()
+            .ok_or_else(|| anyhow!("[MainHandler->snooze_index] Invalid time: {}", until))?
+            .naive_utc();
+        let alarm_snooze: AlarmSnooze =
+            AlarmSnooze::new(index_name, get_currnet_utc_naivedatetime(), until_utc);
+
+        if self.dry_run {
+            self.print_dry_run(
+                &format!(
+                    "snooze alarms in '{}'",
+                    leader_election_config.lease_index()
+                ),
+                &serde_json::to_string_pretty(&alarm_snooze)?,
+            );
+        } else {
+            self.query_service
+                .put_alarm_snooze(leader_election_config.lease_index(), &alarm_snooze)
+                .await?;
+        }
+
+        self.status_service.snooze_index(index_name, until);
+
+        Ok(())
+    }
+
+    #[doc = "임대 인덱스에 저장된 만료되지 않은 알람 일시중지를 읽어서 메모리에 반영해주는 함수"]
+    async fn load_alarm_snoozes(&self) -> Result<(), anyhow::Error> {
+        let leader_election_config: Arc<LeaderElectionConfig> = get_leader_election_config_info();
+        let kst_offset: FixedOffset = get_kst_offset()?;
+
+        let alarm_snoozes: Vec<AlarmSnooze> = self
+            .query_service
+            .get_active_alarm_snoozes(
+                leader_election_config.lease_index(),
+                get_currnet_utc_naivedatetime(),
+            )
+            .await?;
+
+        for alarm_snooze in alarm_snoozes {
+            match alarm_snooze.snoozed_until_utc() {
+                Some(until_utc) => self.status_service.snooze_index(
+                    alarm_snooze.index_name(),
+                    until_utc.and_utc().with_timezone(&kst_offset).naive_local(),
+                ),
+                None => error!(
+                    "[MainHandler->load_alarm_snoozes] Invalid snoozed_until of {}: {}",
+                    alarm_snooze.index_name(),
+                    alarm_snooze.snoozed_until()
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[doc = "열려있는 알람(ack / 발송된 증분색인 알람 / 해결된 장애 제외) 목록을 반환해주는 함수"]
+    pub async fn get_open_alarms(&self) -> Result<Vec<ErrorAlarmInfoFormat>, anyhow::Error> {
+        let system_config: Arc<SystemConfig> = get_system_config_info();
+
+        self.query_service
+            .get_error_alarm_infos(system_config.err_monitor_index())
+            .await
+    }
+
+    #[doc = "모든 채널 / 수신자로 테스트 알림을 바로 발송해주는 함수 - 발송 대기열(outbox)을 거치지 않는다."]
+    /// # Returns
+    /// * Result<Vec<(NotificationOutbox, Result<(), anyhow::Error>)>, anyhow::Error> - 알림별 발송 결과
+    pub async fn send_test_notifications(
+        &self,
+    ) -> Result<Vec<(NotificationOutbox, Result<(), anyhow::Error>)>, anyhow::Error> {
+        let notifications: Vec<NotificationOutbox> = self
+            .notification_service
+            .build_test_notifications(get_currnet_utc_naivedatetime())?;
+
+        if self.dry_run {
+            self.print_dry_run_notifications(&notifications);
+            return Ok(Vec::new());
+        }
+
+        let mut results: Vec<(NotificationOutbox, Result<(), anyhow::Error>)> = Vec::new();
+
+        for notification in notifications {
+            let result: Result<(), anyhow::Error> = self
+                .notification_service
+                .deliver_notification(&notification)
+                .await;
+            results.push((notification, result));
+        }
+
+        Ok(results)
+    }
+
+    #[doc = "클러스터에서 Elasticsearch 노드 목록을 다시 가져오는 함수 (sniffing)"]
+    /// # Arguments
+    /// * `cluster_name` - 노드 목록을 갱신할 클러스터 이름
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    pub async fn refresh_es_nodes(&self, cluster_name: &str) -> Result<(), anyhow::Error> {
+        let node_cnt: usize = self.query_service.refresh_es_nodes(cluster_name).await?;
+        info!(
+            "Elasticsearch nodes of '{}' refreshed: {} node(s)",
+            cluster_name, node_cnt
+        );
+
+        Ok(())
+    }
+
+    #[doc = "Elasticsearch 노드별 연결 상태를 반환해주는 함수 (진단용)"]
+    pub fn es_node_states(&self) -> Vec<EsNodeStatus> {
+        self.query_service.get_es_node_states()
+    }
+
+    #[doc = "이 인스턴스가 체크/알림을 실행해야 하는지 여부 (리더 선출을 쓰지 않으면 항상 true)"]
+    pub fn is_leader(&self) -> bool {
+        self.status_service
+            .is_leader(get_currnet_utc_naivedatetime())
+    }
+
+    #[doc = "리더 임대(lease)를 주기적으로 얻거나 갱신하는 함수 - 임대가 만료되면 대기 인스턴스가 가져간다."]
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    pub async fn leader_election_task(&self) -> Result<(), anyhow::Error> {
+        let leader_election_config: Arc<LeaderElectionConfig> = get_leader_election_config_info();
+        let instance_id: String = leader_election_config.resolve_instance_id();
+
+        info!(
+            "[leader_election_task] instance={} lease={}/{}",
+            instance_id,
+            leader_election_config.lease_index(),
+            leader_election_config.lease_id()
+        );
+
+        let mut interval: Interval = tokio::time::interval(Duration::from_secs(
+            *leader_election_config.renew_interval_sec(),
+        ));
+
+        loop {
+            interval.tick().await;
+
+            /* 갱신에 실패해도 이미 얻은 임대가 만료될 때까지는 리더로 동작 */
+            if let Err(e) = self
+                .renew_leader_lease(&leader_election_config, &instance_id)
+                .await
+            {
+                error!("[leader_election_task() -> renew_leader_lease()] {:?}", e);
+            }
+        }
+    }
+
+    #[doc = "리더 임대를 한번 얻거나 갱신해보는 함수 - seq_no / primary_term 이 바뀌었으면 다른 인스턴스가 먼저 가져간 것"]
+    async fn renew_leader_lease(
+        &self,
+        leader_election_config: &LeaderElectionConfig,
+        instance_id: &str,
+    ) -> Result<(), anyhow::Error> {
+        let lease_index: &str = leader_election_config.lease_index();
+        let lease_id: &str = leader_election_config.lease_id();
+
+        let now: NaiveDateTime = get_currnet_utc_naivedatetime();
+        let expires_at: NaiveDateTime =
+            now + chrono::Duration::seconds(*leader_election_config.lease_duration_sec());
+        let was_leader: bool = self.is_leader();
+
+        let current: Option<LeaderLeaseFormat> = self
+            .query_service
+            .get_leader_lease(lease_index, lease_id)
+            .await?;
+
+        let acquired_at: NaiveDateTime = match &current {
+            None => now,
+            Some(lease) if lease.leader_lease().holder() == instance_id => {
+                lease.leader_lease().acquired_at_or(now)
+            }
+            Some(lease) if lease.leader_lease().is_expired(now) => now,
+            Some(lease) => {
+                /* 다른 인스턴스가 유효한 임대를 가지고 있음 */
+                if was_leader {
+                    info!(
+                        "[renew_leader_lease] Leadership taken over by {}",
+                        lease.leader_lease().holder()
+                    );
+                }
+                self.status_service.set_leader_until(None);
+                return Ok(());
+            }
+        };
+
+        let leader_lease: LeaderLease = LeaderLease::new(instance_id, acquired_at, now, expires_at);
+
+        if self
+            .query_service
+            .put_leader_lease(lease_index, lease_id, &leader_lease, current.as_ref())
+            .await?
+        {
+            if !was_leader {
+                info!("[renew_leader_lease] {} became the leader", instance_id);
+            }
+            self.status_service.set_leader_until(Some(expires_at));
+        } else {
+            if was_leader {
+                info!("[renew_leader_lease] {} lost the leadership", instance_id);
+            }
+            self.status_service.set_leader_until(None);
+        }
+
+        Ok(())
+    }
+
+    #[doc = "종료 시 리더 임대를 바로 만료시켜서 대기 인스턴스가 기다리지 않고 넘겨받도록 하는 함수"]
+    pub async fn release_leader_lease(&self) -> Result<(), anyhow::Error> {
+        let leader_election_config: Arc<LeaderElectionConfig> = get_leader_election_config_info();
+
+        if !*leader_election_config.enabled() || self.dry_run || !self.is_leader() {
+            return Ok(());
+        }
+
+        let instance_id: String = leader_election_config.resolve_instance_id();
+        let lease_index: &str = leader_election_config.lease_index();
+        let lease_id: &str = leader_election_config.lease_id();
+
+        self.status_service.set_leader_until(None);
+
+        let current: Option<LeaderLeaseFormat> = self
+            .query_service
+            .get_leader_lease(lease_index, lease_id)
+            .await?;
+
+        if let Some(lease) = current.filter(|lease| lease.leader_lease().holder() == &instance_id) {
+            let now: NaiveDateTime = get_currnet_utc_naivedatetime();
+            let released: LeaderLease = LeaderLease::new(
+                &instance_id,
+                lease.leader_lease().acquired_at_or(now),
+                now,
+                now,
+            );
+
+            self.query_service
+                .put_leader_lease(lease_index, lease_id, &released, Some(&lease))
+                .await?;
+
+            info!(
+                "[release_leader_lease] {} released the leadership",
+                instance_id
+            );
+        }
+
+        Ok(())
+    }
+
+    #[doc = "prod / test 여부에 따라 검색 인덱스명 구성"]
+    /// # Arguments
+    /// * `log_date_utc` - 색인 로그 인덱스의 날짜 (UTC)
+    ///
+    /// # Returns
+    /// * Result<String, anyhow::Error>
+    fn build_search_index_name(&self, log_date_utc: NaiveDate) -> Result<String, anyhow::Error> {
+        /* 현재 프로그램실행 type -> prod type 인지 아닌지 체크 */
+        let code_config: Arc<CodeConfig> = get_code_config_info();
+
+        if code_config.code_type().as_str() == "prod" {
+            let log_date_utc: String =
+                get_str_from_naivedate(log_date_utc, "%Y-%m-%d").map_err(|e| {
+                    AppError::Parse(format!(
+                        "[MainHandler->build_search_index_name] log_date_utc error: {:?}",
+                        e
+                    ))
+                })?;
+            let search_index: Arc<SystemConfig> = get_system_config_info();
+            Ok(format!(
+                "{}-{}",
+                search_index.log_index_name(),
+                log_date_utc
+            ))
+        } else {
+            Ok("vector-indexing-logs-2025-01-08".to_string())
+        }
+    }
+
+    #[doc = "색인 로그가 존재하는 경우 처리 (Partial Error or Success)"]
+    async fn handle_indexing_success(
+        &self,
+        log: &VectorIndexLogFormat,
+        system_config: &SystemConfig,
+        index_schedule: &IndexSchedules,
+    ) -> Result<IndexCheckStatus, anyhow::Error> {
+        let expected_size: usize = index_schedule.size;
+        let (outcome, n) = evaluate_indexing_log(log.vector_index_log.message(), expected_size)?;
+
+        match outcome {
+            /* 색인은 성공했지만, 색인 개수가 올바르지 않은 경우. */
+            "Partial Error" => {
+                let err_monitor_index: String = system_config.err_monitor_index().to_string();
+                let cur_time_kor_str: String = get_current_kor_naive_datetime_str()?; /* 현재 시각을 문자열로 표시함 */
+
+                let mut error_alarm_info: ErrorAlarmInfo = ErrorAlarmInfo::new(
+                    cur_time_kor_str,
+                    String::from("Partial Error"),
+                    index_schedule.index_name().to_string(),
+                    index_schedule.indexing_type().to_string(),
+                    n,
+                    *index_schedule.size(),
+                );
+                error_alarm_info
+                    .set_reason(format!(
+                        "Only {} documents were indexed, expected at least {}.",
+                        n.to_formatted_string(&Locale::en),
+                        expected_size.to_formatted_string(&Locale::en)
+                    ))
+                    .set_duration(*index_schedule.duration());
+
+                self.record_indexing_incident(&err_monitor_index, index_schedule, error_alarm_info)
+                    .await?;
+            }
+            /* 색인이 문제없이 잘 된 경우 */
+            "Success" => {
+                info!(
+                    "Indexing of `{}({})` completed successfully.",
+                    log.vector_index_log.index_name(),
+                    log.vector_index_log.state()
+                );
+
+                /* 진행중인 장애가 있었다면 해결 처리 - 실패해도 체크 결과에는 영향이 없다. */
+                let elastic_config: Arc<ElasticServerConfig> = get_elasticsearch_config_info();
+
+                if let Err(e) = self
+                    .resolve_incidents(
+                        system_config.err_monitor_index(),
+                        index_schedule.target_cluster_or(elastic_config.elastic_cluster_name()),
+                        index_schedule.index_name(),
+                        &["Full Error", "Partial Error"],
+                    )
+                    .await
+                {
+                    error!("[MainHandler->handle_indexing_success] {:?}", e);
+                }
+            }
+            /* 색인 로그는 있지만 색인 개수를 확인할 수 없는 경우 */
+            _ => (),
+        }
+
+        self.build_check_status(outcome, n, index_schedule)
+    }
+
+    #[doc = "색인 로그가 없는 경우 처리(Full Error)"]
+    async fn handle_indexing_failure(
+        &self,
+        system_config: &SystemConfig,
+        index_schedule: &IndexSchedules,
+    ) -> Result<IndexCheckStatus, anyhow::Error> {
+        /* 색인 자체가 실패가 난 경우. */
+        let err_monitor_index: String = system_config.err_monitor_index().to_string();
+        let cur_time_kor_str: String = get_current_kor_naive_datetime_str()?; /* 현재 시각을 문자열로 표시함 */
+
+        let mut error_alarm_info: ErrorAlarmInfo = ErrorAlarmInfo::new(
+            cur_time_kor_str,
+            String::from("Full Error"),
+            index_schedule.index_name().to_string(),
+            index_schedule.indexing_type().to_string(),
+            0,
+            *index_schedule.size(),
+        );
+        error_alarm_info
+            .set_reason(format!(
+                "No completed indexing log was found within {} seconds before the check.",
+                index_schedule.duration()
+            ))
+            .set_duration(*index_schedule.duration());
+
+        /* Elasticsearch 로그 인덱스로 실패건 전송 */
+        self.record_indexing_incident(&err_monitor_index, index_schedule, error_alarm_info)
+            .await?;
+
+        self.build_check_status("Full Error", 0, index_schedule)
+    }
+
+    #[doc = "색인 실패건을 색인 대상 인덱스가 있는 클러스터의 장애로 기록해주는 함수"]
+    async fn record_indexing_incident(
+        &self,
+        err_monitor_index: &str,
+        index_schedule: &IndexSchedules,
+        error_alarm_info: ErrorAlarmInfo,
+    ) -> Result<(), anyhow::Error> {
+        let elastic_config: Arc<ElasticServerConfig> = get_elasticsearch_config_info();
+
+        self.record_incident(
+            err_monitor_index,
+            index_schedule.target_cluster_or(elastic_config.elastic_cluster_name()),
+            error_alarm_info,
+        )
+        .await
+    }
+
+    #[doc = "실패건을 (클러스터, 인덱스, 에러 종류) 단위의 장애로 기록해주는 함수 - 같은 장애가 이어지면 발생 횟수만 올라간다."]
+    /// # Arguments
+    /// * `err_monitor_index` - 에러메시지 정보가 들어있는 인덱스 이름
+    /// * `cluster_name` - 장애가 발생한 클러스터 이름
+    /// * `error_alarm_info` - 이번 체크의 실패 정보
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    async fn record_incident(
+        &self,
+        err_monitor_index: &str,
+        cluster_name: &str,
+        mut error_alarm_info: ErrorAlarmInfo,
+    ) -> Result<(), anyhow::Error> {
+        error_alarm_info.set_incident(cluster_name);
+
+        if self.dry_run {
+            self.print_dry_run(
+                &format!("upsert incident into '{}'", err_monitor_index),
+                &serde_json::to_string_pretty(&error_alarm_info)?,
+            );
+
+            /* 알람 테스크가 이 장애로 만들게 될 알림 (같은 장애가 열려있으면 새로 만들지 않는다.) */
+            let doc_id: String = incident_doc_id(
+                error_alarm_info.fingerprint(),
+                error_alarm_info.first_seen(),
+            );
+            let notifications: Vec<NotificationOutbox> =
+                self.notification_service.build_notifications(
+                    &[ErrorAlarmInfoFormat::new(doc_id, error_alarm_info)],
+                    get_currnet_utc_naivedatetime(),
+                )?;
+            self.print_dry_run_notifications(&notifications);
+
+            return Ok(());
+        }
+
+        self.query_service
+            .upsert_incident(err_monitor_index, &error_alarm_info)
+            .await
+    }
+
+    #[doc = "체크가 성공한 인덱스의 진행중인 장애들을 해결 처리해주는 함수"]
+    /// # Arguments
+    /// * `err_monitor_index` - 에러메시지 정보가 들어있는 인덱스 이름
+    /// * `cluster_name` - 장애가 발생했던 클러스터 이름
+    /// * `index_name` - 체크가 성공한 인덱스 이름
+    /// * `error_types` - 해결 처리할 장애의 에러 종류
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    async fn resolve_incidents(
+        &self,
+        err_monitor_index: &str,
+        cluster_name: &str,
+        index_name: &str,
+        error_types: &[&str],
+    ) -> Result<(), anyhow::Error> {
+        let resolved_at: String = get_current_kor_naive_datetime_str()?;
+
+        if self.dry_run {
+            self.print_dry_run(
+                &format!("resolve open incidents in '{}'", err_monitor_index),
+                &format!(
+                    "{} ({}) {:?} at {}",
+                    index_name, cluster_name, error_types, resolved_at
+                ),
+            );
+            return Ok(());
+        }
+
+        let resolved_cnt: u64 = self
+            .query_service
+            .resolve_incidents(
+                err_monitor_index,
+                cluster_name,
+                index_name,
+                error_types,
+                &resolved_at,
+            )
+            .await?;
+
+        if resolved_cnt > 0 {
+            info!(
+                "[MainHandler->resolve_incidents] {} incident(s) of `{}` ({}) resolved",
+                resolved_cnt, index_name, cluster_name
+            );
+        }
 
         Ok(())
     }
+
+    #[doc = "색인 체크 결과 객체를 만들어주는 함수"]
+    fn build_check_status(
+        &self,
+        outcome: &str,
+        indexing_cnt_num: usize,
+        index_schedule: &IndexSchedules,
+    ) -> Result<IndexCheckStatus, anyhow::Error> {
+        Ok(IndexCheckStatus::new(
+            index_schedule.index_name().to_string(),
+            outcome.to_string(),
+            get_current_kor_naive_datetime_str()?,
+            indexing_cnt_num,
+            *index_schedule.size(),
+        ))
+    }
+
+    #[doc = "발송된 알림이 다루는 증분색인(dynamic index) 알람을 dispatched 상태로 변경: 실패하면 로깅만 한다."]
+    /// 실패하면 알람이 open 으로 남아서 다음 alarm_task 에서 한번 더 발송될 수 있다.
+    async fn mark_dynamic_alarms_dispatched(&self, alarm_doc_ids: &[String]) {
+        if alarm_doc_ids.is_empty() {
+            return;
+        }
+
+        let system_config: Arc<SystemConfig> = get_system_config_info();
+
+        match self
+            .query_service
+            .dispatch_dynamic_alarms(system_config.err_monitor_index(), alarm_doc_ids)
+            .await
+        {
+            Ok(dispatched_cnt) => {
+                if dispatched_cnt > 0 {
+                    info!("Dispatched {} dynamic index alarm(s)", dispatched_cnt);
+                }
+            }
+            Err(e) => error!("[mark_dynamic_alarms_dispatched] {:?}", e),
+        }
+    }
+}
+
+#[doc = "색인 로그 메시지의 색인 건수로 체크 결과를 판단해주는 함수 (정해진 체크 / replay 공통)"]
+/// # Arguments
+/// * `log_detail` - 색인 로그 메시지 (예: "... index worked (1,234)")
+/// * `expected_size` - 설정한 최소 색인 건수
+///
+/// # Returns
+/// * Result<(&'static str, usize), anyhow::Error> - (Success | Partial Error | Unknown, 색인 건수)
+pub fn evaluate_indexing_log(
+    log_detail: &str,
+    expected_size: usize,
+) -> Result<(&'static str, usize), anyhow::Error> {
+    let idx_cnt_re: &Regex = IDX_CNT_RE.as_ref().map_err(|e| {
+        AppError::Parse(format!(
+            "[evaluate_indexing_log] Failed to initialize the `IDX_CNT_RE` regular expression: {}",
+            e
+        ))
+    })?;
+
+    let Some(num) = idx_cnt_re
+        .captures(log_detail)
+        .and_then(|caps| caps.name("num"))
+    else {
+        return Ok(("Unknown", 0));
+    };
+
+    /* 실제 색인된 문서의 개수 */
+    let n: usize = num
+        .as_str()
+        .replace(',', "")
+        .parse::<usize>()
+        .map_err(|e| {
+            AppError::Parse(format!(
+                "[evaluate_indexing_log] Invalid indexed count '{}': {}",
+                num.as_str(),
+                e
+            ))
+        })?;
+
+    /* 실제 색인된 문서의 개수가 설정한 문서의 개수보다 작은 경우 */
+    if n < expected_size {
+        Ok(("Partial Error", n))
+    } else {
+        Ok(("Success", n))
+    }
+}
+
+#[doc = "schedule_term 간격으로 확인하다가 CRON 실행 시각이 되면 반환해주는 함수"]
+async fn wait_for_cron(schedule: &Schedule, interval: &mut Interval, kst_offset: FixedOffset) {
+    loop {
+        interval.tick().await;
+
+        let kst_now: DateTime<FixedOffset> = Utc::now().with_timezone(&kst_offset);
+
+        if let Some(next) = schedule.upcoming(kst_offset).take(1).next() {
+            if (next - kst_now).num_seconds() < 1 {
+                return;
+            }
+        }
+    }
+}
+
+#[doc = "CRON 실행 시각과 err_monitor_index 의 장애(incident) 기록으로 기간 내 체크 결과들을 구성해주는 함수"]
+/// 장애가 이어진 동안(first_seen ~ last_seen)의 실행 시각은 모두 실패로 보고,
+/// occurrence_count 가 그보다 많으면 나머지는 수동 실행(/check 등)으로 보고 last_seen 에 따로 기록한다.
+///
+/// # Arguments
+/// * `index_schedule` - 인덱스 스케쥴 객체
+/// * `failures` - 해당 인덱스의 장애 기록 (처음 발견된 순서)
+/// * `start` - 집계 시작 시각 (한국시간, 포함)
+/// * `end` - 집계 종료 시각 (한국시간, 미포함)
+///
+/// # Returns
+/// * Result<Vec<CheckRecord>, anyhow::Error> - 시간순 체크 결과
+fn build_check_records(
+    index_schedule: &IndexSchedules,
+    failures: &[&ErrorAlarmInfo],
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+) -> Result<Vec<CheckRecord>, anyhow::Error> {
+    let schedule: Schedule = Schedule::from_str(index_schedule.time()).map_err(|e| {
+        anyhow!(
+            "[build_check_records] Failed to parse CRON expression of {}: {:?}",
+            index_schedule.index_name(),
+            e
+        )
+    })?;
+
+    let kst_offset: FixedOffset = get_kst_offset()?;
+    let start_kst: DateTime<FixedOffset> = start
+        .and_local_timezone(kst_offset)
+        .single()
+        .ok_or_else(|| anyhow!("[build_check_records] Invalid start time: {}", start))?;
+
+    /* CRON 실행 시각마다 체크가 한번씩 일어났다고 보고, 실패 기록이 없으면 성공으로 본다. */
+    let slots: Vec<NaiveDateTime> = schedule
+        .after(&(start_kst - chrono::Duration::seconds(1)))
+        .map(|slot| slot.naive_local())
+        .take_while(|slot| *slot < end)
+        .collect();
+
+    let mut records: Vec<CheckRecord> = slots
+        .iter()
+        .map(|slot| CheckRecord::new(String::from("Success"), *slot, None))
+        .collect();
+    let mut slot_failed: Vec<bool> = vec![false; slots.len()];
+
+    for failure in failures {
+        /* 시각을 읽을 수 없는 문서 하나 때문에 리포트 전체가 실패하지 않도록 건너뛴다. */
+        let (first_seen, last_seen): (NaiveDateTime, NaiveDateTime) = match (
+            NaiveDateTime::parse_from_str(failure.first_seen_or_timestamp(), "%Y-%m-%dT%H:%M:%SZ"),
+            NaiveDateTime::parse_from_str(failure.last_seen_or_timestamp(), "%Y-%m-%dT%H:%M:%SZ"),
+        ) {
+            (Ok(first_seen), Ok(last_seen)) => (first_seen, last_seen.max(first_seen)),
+            _ => {
+                warn!(
+                    "[build_check_records] Skip alarm document of {} with unparseable time (first_seen: '{}', last_seen: '{}')",
+                    index_schedule.index_name(),
+                    failure.first_seen_or_timestamp(),
+                    failure.last_seen_or_timestamp()
+                );
+                continue;
+            }
+        };
+
+        let indexing_cnt_num: Option<usize> = if failure.error_type() == "Partial Error" {
+            Some(failure.indexing_cnt_num)
+        } else {
+            None
+        };
+
+        /* 발견 시각 직전의 실행 시각부터, 마지막 발견 시각 직전의 실행 시각까지 */
+        let first_pos: usize = slots
+            .partition_point(|slot| *slot <= first_seen)
+            .saturating_sub(1);
+        let last_pos: usize = slots.partition_point(|slot| *slot <= last_seen);
+        let failed_slot_cnt: u64 = last_pos.saturating_sub(first_pos) as u64;
+
+        for pos in first_pos..last_pos {
+            if !slot_failed[pos] {
+                slot_failed[pos] = true;
+                records[pos] = CheckRecord::new(
+                    failure.error_type().to_string(),
+                    slots[pos],
+                    indexing_cnt_num,
+                );
+            }
+        }
+
+        /* 기간 전에 시작된 장애는 기간 밖의 발생 횟수를 알 수 없으므로 실행 시각만 집계한다. */
+        if first_seen < start || last_seen >= end {
+            continue;
+        }
+
+        for _ in failed_slot_cnt..*failure.occurrence_count() {
+            records.push(CheckRecord::new(
+                failure.error_type().to_string(),
+                last_seen,
+                indexing_cnt_num,
+            ));
+        }
+    }
+
+    records.sort_by_key(|record| *record.checked_at());
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kst(datetime: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn hourly_schedule() -> IndexSchedules {
+        toml::from_str(
+            r#"
+            index_name = "product_a"
+            time = "0 0 * * * * *"
+            duration = 600
+            size = 100
+            indexing_type = "static index"
+            "#,
+        )
+        .unwrap()
+    }
+
+    fn incident(
+        error_type: &str,
+        first_seen: &str,
+        last_seen: &str,
+        occurrence_count: u64,
+    ) -> ErrorAlarmInfo {
+        let mut info: ErrorAlarmInfo = ErrorAlarmInfo::new(
+            last_seen.to_string(),
+            error_type.to_string(),
+            String::from("product_a"),
+            String::from("static index"),
+            30,
+            100,
+        );
+        info.set_first_seen(first_seen.to_string())
+            .set_last_seen(last_seen.to_string())
+            .set_occurrence_count(occurrence_count);
+        info
+    }
+
+    fn outcomes(records: &[CheckRecord]) -> Vec<&str> {
+        records.iter().map(|r| r.outcome().as_str()).collect()
+    }
+
+    #[test]
+    fn evaluate_indexing_log_outcomes() {
+        assert_eq!(
+            evaluate_indexing_log("static index worked (1,234)", 1000).unwrap(),
+            ("Success", 1234)
+        );
+        assert_eq!(
+            evaluate_indexing_log("static index Worked (99)", 100).unwrap(),
+            ("Partial Error", 99)
+        );
+        assert_eq!(
+            evaluate_indexing_log("static index worked (100)", 100).unwrap(),
+            ("Success", 100)
+        );
+        assert_eq!(
+            evaluate_indexing_log("static index finished", 100).unwrap(),
+            ("Unknown", 0)
+        );
+    }
+
+    #[test]
+    fn evaluate_indexing_log_rejects_overflowing_count() {
+        assert!(evaluate_indexing_log("worked (99999999999999999999999)", 1).is_err());
+    }
+
+    #[test]
+    fn build_check_records_without_failures_is_all_success() {
+        let records: Vec<CheckRecord> = build_check_records(
+            &hourly_schedule(),
+            &[],
+            kst("2025-01-01 00:00:00"),
+            kst("2025-01-01 06:00:00"),
+        )
+        .unwrap();
+
+        assert_eq!(records.len(), 6);
+        assert!(records.iter().all(|r| r.is_success()));
+        assert_eq!(*records[0].checked_at(), kst("2025-01-01 00:00:00"));
+        assert_eq!(*records[5].checked_at(), kst("2025-01-01 05:00:00"));
+    }
+
+    #[test]
+    fn build_check_records_expands_incident_over_slots() {
+        let failure: ErrorAlarmInfo = incident(
+            "Full Error",
+            "2025-01-01T01:00:05Z",
+            "2025-01-01T03:00:04Z",
+            3,
+        );
+
+        let records: Vec<CheckRecord> = build_check_records(
+            &hourly_schedule(),
+            &[&failure],
+            kst("2025-01-01 00:00:00"),
+            kst("2025-01-01 06:00:00"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            outcomes(&records),
+            vec![
+                "Success",
+                "Full Error",
+                "Full Error",
+                "Full Error",
+                "Success",
+                "Success"
+            ]
+        );
+    }
+
+    #[test]
+    fn build_check_records_counts_extra_occurrences_as_manual_checks() {
+        let failure: ErrorAlarmInfo = incident(
+            "Partial Error",
+            "2025-01-01T01:00:05Z",
+            "2025-01-01T02:30:00Z",
+            4,
+        );
+
+        let records: Vec<CheckRecord> = build_check_records(
+            &hourly_schedule(),
+            &[&failure],
+            kst("2025-01-01 00:00:00"),
+            kst("2025-01-01 06:00:00"),
+        )
+        .unwrap();
+
+        /* 실행 시각 2개(01시, 02시) + 수동 체크 2건 */
+        let failures: Vec<&CheckRecord> = records.iter().filter(|r| r.is_failure()).collect();
+        assert_eq!(records.len(), 8);
+        assert_eq!(failures.len(), 4);
+        assert!(failures.iter().all(|r| *r.indexing_cnt_num() == Some(30)));
+        assert_eq!(
+            records
+                .iter()
+                .filter(|r| *r.checked_at() == kst("2025-01-01 02:30:00"))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn build_check_records_reads_legacy_documents_by_timestamp() {
+        /* first_seen / last_seen 이 없는 이전 버전 문서 */
+        let failure: ErrorAlarmInfo = ErrorAlarmInfo::new(
+            String::from("2025-01-01T02:00:03Z"),
+            String::from("Full Error"),
+            String::from("product_a"),
+            String::from("static index"),
+            0,
+            100,
+        );
+
+        let records: Vec<CheckRecord> = build_check_records(
+            &hourly_schedule(),
+            &[&failure],
+            kst("2025-01-01 00:00:00"),
+            kst("2025-01-01 06:00:00"),
+        )
+        .unwrap();
+
+        assert_eq!(records.len(), 6);
+        assert_eq!(records[2].outcome(), "Full Error");
+        assert_eq!(records.iter().filter(|r| r.is_failure()).count(), 1);
+    }
+
+    #[test]
+    fn build_check_records_skips_unparseable_documents() {
+        let broken: ErrorAlarmInfo = ErrorAlarmInfo::new(
+            String::from("2025-01-01 02:00:03"),
+            String::from("Full Error"),
+            String::from("product_a"),
+            String::from("static index"),
+            0,
+            100,
+        );
+        let failure: ErrorAlarmInfo = incident(
+            "Full Error",
+            "2025-01-01T04:00:05Z",
+            "2025-01-01T04:00:05Z",
+            1,
+        );
+
+        let records: Vec<CheckRecord> = build_check_records(
+            &hourly_schedule(),
+            &[&broken, &failure],
+            kst("2025-01-01 00:00:00"),
+            kst("2025-01-01 06:00:00"),
+        )
+        .unwrap();
+
+        assert_eq!(records.len(), 6);
+        assert_eq!(records[4].outcome(), "Full Error");
+        assert_eq!(records.iter().filter(|r| r.is_failure()).count(), 1);
+    }
+
+    #[test]
+    fn build_check_records_clips_incident_started_before_period() {
+        let failure: ErrorAlarmInfo = incident(
+            "Full Error",
+            "2024-12-31T20:00:05Z",
+            "2025-01-01T01:00:04Z",
+            10,
+        );
+
+        let records: Vec<CheckRecord> = build_check_records(
+            &hourly_schedule(),
+            &[&failure],
+            kst("2025-01-01 00:00:00"),
+            kst("2025-01-01 06:00:00"),
+        )
+        .unwrap();
+
+        /* 기간 밖의 발생 횟수는 집계하지 않는다. */
+        assert_eq!(records.len(), 6);
+        assert_eq!(
+            outcomes(&records)[..3],
+            ["Full Error", "Full Error", "Success"]
+        );
+    }
 }
@@ -1 +1,3 @@
+pub mod cli_command_handler;
 pub mod main_handler;
+pub mod telegram_command_handler;
@@ -0,0 +1,341 @@
+use crate::common::*;
+
+use crate::handler::main_handler::*;
+
+use crate::model::{
+    es_node_status::*, index_check_status::*, index_schedules_config::*, telegram_config::*,
+    telegram_update::*, total_config::*,
+};
+
+use crate::repository::telegram_repository::*;
+
+use crate::traits::repository_traits::telegram_repository_trait::*;
+use crate::traits::service_traits::{
+    notification_service_trait::*, query_service_trait::*, status_service_trait::*,
+};
+
+use crate::utils_modules::{message_utils::*, time_utils::*};
+
+/* /snooze 로 알람을 멈출 수 있는 최대 기간 (일) */
+const MAX_SNOOZE_DAYS: i64 = 30;
+
+pub struct TelegramCommandHandler<N: NotificationService, Q: QueryService, S: StatusService> {
+    main_handler: Arc<MainHandler<N, Q, S>>,
+    index_schedules: Vec<IndexSchedules>,
+}
+
+impl<N: NotificationService, Q: QueryService, S: StatusService> TelegramCommandHandler<N, Q, S> {
+    pub fn new(
+        main_handler: Arc<MainHandler<N, Q, S>>,
+        index_schedules: Vec<IndexSchedules>,
+    ) -> Self {
+        Self {
+            main_handler,
+            index_schedules,
+        }
+    }
+
+    #[doc = "Telegram 봇으로 들어오는 명령어를 계속 polling 하며 처리해주는 함수"]
+    pub async fn command_poll_task(&self) -> Result<(), anyhow::Error> {
+        let tele_repo: Arc<TelebotRepositoryPub> = get_telegram_repo();
+        let telegram_config: Arc<TelegramConfig> = get_telegram_config_info();
+
+        /* 프로그램 시작 이전에 쌓여있던 명령어는 실행하지 않는다. */
+        let started_at: i64 = Utc::now().timestamp();
+        let mut offset: i64 = 0;
+
+        loop {
+            /* 같은 봇을 여러 인스턴스가 polling 하면 충돌하므로 리더만 명령어를 받는다. */
+            if !self.main_handler.is_leader() {
+                sleep(Duration::from_secs(5)).await;
+                continue;
+            }
+
+            let updates: Vec<TelegramUpdate> = match tele_repo
+                .get_updates(offset, *telegram_config.command_poll_timeout())
+                .await
+            {
+                Ok(updates) => updates,
+                Err(e) => {
+                    error!("[TelegramCommandHandler->command_poll_task] {:?}", e);
+                    sleep(Duration::from_secs(5)).await;
+                    continue;
+                }
+            };
+
+            for update in updates {
+                offset = update.update_id + 1;
+
+                let message: TelegramMessage = match update.message {
+                    Some(message) => message,
+                    None => continue,
+                };
+
+                if message.date < started_at {
+                    continue;
+                }
+
+                let text: &str = match message.text.as_deref() {
+                    Some(text) => text,
+                    None => continue,
+                };
+
+                let chat_id: String = message.chat.id.to_string();
+
+                if !telegram_config.is_command_allowed(&chat_id) {
+                    info!(
+                        "[TelegramCommandHandler] Ignored command from unauthorized chat: {}",
+                        chat_id
+                    );
+                    continue;
+                }
+
+                info!("[TelegramCommandHandler] chat={} command={}", chat_id, text);
+
+                let reply: String = self.handle_command(text).await;
+
+                for chunk in split_message(&reply, TELEGRAM_MSG_MAX_LEN) {
+                    if let Err(e) = tele_repo.send_to_chat(&chat_id, &chunk).await {
+                        error!("[TelegramCommandHandler->command_poll_task] {:?}", e);
+                    }
+                }
+            }
+        }
+    }
+
+    #[doc = "명령어 한 건을 해석하고 응답 메시지를 만들어주는 함수"]
+    /// # Arguments
+    /// * `text` - 사용자가 보낸 메시지 원문
+    ///
+    /// # Returns
+    /// * String - 응답 메시지
+    async fn handle_command(&self, text: &str) -> String {
+        let mut parts = text.split_whitespace();
+
+        /* 그룹 채팅에서는 '/status@bot_name' 형태로 들어온다. */
+        let command: &str = parts
+            .next()
+            .and_then(|cmd| cmd.split('@').next())
+            .unwrap_or("");
+        let args: Vec<&str> = parts.collect();
+
+        let result: Result<String, anyhow::Error> = match (command, args.as_slice()) {
+            ("/status", []) => self.status_all(),
+            ("/status", [index_name]) => self.status_index(index_name),
+            ("/next", []) => self.next_checks(),
+            ("/ack", [index_name]) => self.ack_index(index_name).await,
+            ("/snooze", [index_name, duration]) => self.snooze_index(index_name, duration).await,
+            ("/check", [index_name]) => self.check_index(index_name).await,
+            ("/nodes", []) => self.node_states(),
+            _ => Ok(help_message()),
+        };
+
+        result.unwrap_or_else(|e| format!("[Error] {}", e))
+    }
+
+    #[doc = "설정 파일에 등록된 인덱스 스케쥴을 찾아주는 함수"]
+    fn find_schedule(&self, index_name: &str) -> Result<&IndexSchedules, anyhow::Error> {
+        self.index_schedules
+            .iter()
+            .find(|schedule| schedule.index_name() == index_name)
+            .ok_or_else(|| anyhow!("Unknown index: {}", index_name))
+    }
+
+    #[doc = "인덱스 하나의 상태를 한 줄 문자열로 만들어주는 함수"]
+    fn format_status_line(&self, index_schedule: &IndexSchedules) -> String {
+        let status_service: &S = self.main_handler.status_service();
+        let index_name: &str = index_schedule.index_name();
+
+        let mut line: String = match status_service.get_check_status(index_name) {
+            Some(check_status) => format_check_status(&check_status),
+            None => format!("- {}: not checked yet", index_name),
+        };
+
+        if let Some(until) =
+            status_service.get_snoozed_until(index_name, get_current_kor_naive_datetime())
+        {
+            line.push_str(&format!(
+                " [snoozed until {}]",
+                until.format("%Y-%m-%d %H:%M")
+            ));
+        }
+
+        line
+    }
+
+    #[doc = "/status - 모든 인덱스의 마지막 체크 결과"]
+    fn status_all(&self) -> Result<String, anyhow::Error> {
+        let mut msg: String = String::from("[Indexing Status]\n");
+
+        for index_schedule in &self.index_schedules {
+            msg.push_str(&self.format_status_line(index_schedule));
+            msg.push('\n');
+        }
+
+        Ok(msg)
+    }
+
+    #[doc = "/status <index> - 특정 인덱스의 마지막 체크 결과"]
+    fn status_index(&self, index_name: &str) -> Result<String, anyhow::Error> {
+        let index_schedule: &IndexSchedules = self.find_schedule(index_name)?;
+
+        Ok(format!(
+            "[Indexing Status]\n{}\n   - cron: {}\n   - indexing type: {}",
+            self.format_status_line(index_schedule),
+            index_schedule.time(),
+            index_schedule.indexing_type()
+        ))
+    }
+
+    #[doc = "/next - 앞으로 실행될 색인 체크 목록 (시간순)"]
+    fn next_checks(&self) -> Result<String, anyhow::Error> {
+        let kst_offset: FixedOffset = get_kst_offset()?;
+
+        let mut upcoming: Vec<(DateTime<FixedOffset>, &str)> = Vec::new();
+
+        for index_schedule in &self.index_schedules {
+            let schedule: Schedule = Schedule::from_str(index_schedule.time())
+                .map_err(|e| anyhow!("Invalid cron for {}: {}", index_schedule.index_name(), e))?;
+
+            if let Some(next) = schedule.upcoming(kst_offset).next() {
+                upcoming.push((next, index_schedule.index_name()));
+            }
+        }
+
+        upcoming.sort_by_key(|(next, _)| *next);
+
+        let mut msg: String = String::from("[Upcoming Checks (KST)]\n");
+
+        for (next, index_name) in upcoming {
+            msg.push_str(&format!(
+                "- {} : {}\n",
+                next.format("%Y-%m-%d %H:%M:%S"),
+                index_name
+            ));
+        }
+
+        Ok(msg)
+    }
+
+    #[doc = "/ack <index> - 열린 알람을 확인 처리"]
+    async fn ack_index(&self, index_name: &str) -> Result<String, anyhow::Error> {
+        self.find_schedule(index_name)?;
+
+        let ack_cnt: u64 = self.main_handler.acknowledge_index(index_name).await?;
+
+        Ok(format!(
+            "{} alarm(s) of '{}' acknowledged.",
+            ack_cnt, index_name
+        ))
+    }
+
+    #[doc = "/snooze <index> <duration> - 일정 시간 동안 알람 발송 중지"]
+    async fn snooze_index(
+        &self,
+        index_name: &str,
+        duration: &str,
+    ) -> Result<String, anyhow::Error> {
+        self.find_schedule(index_name)?;
+
+        let snooze_duration: chrono::Duration = parse_duration_str(duration)?;
+
+        if snooze_duration <= chrono::Duration::zero() {
+            return Err(anyhow!("Snooze duration must be positive: {}", duration));
+        }
+
+        if snooze_duration > chrono::Duration::days(MAX_SNOOZE_DAYS) {
+            return Err(anyhow!(
+                "Snooze duration must be at most {}d: {}",
+                MAX_SNOOZE_DAYS,
+                duration
+            ));
+        }
+
+        let until: NaiveDateTime = get_current_kor_naive_datetime()
+            .checked_add_signed(snooze_duration)
+            .ok_or_else(|| anyhow!("Snooze duration is out of range: {}", duration))?;
+
+        self.main_handler.snooze_index(index_name, until).await?;
+
+        Ok(format!(
+            "Alarms of '{}' snoozed until {} (KST).",
+            index_name,
+            until.format("%Y-%m-%d %H:%M:%S")
+        ))
+    }
+
+    #[doc = "/check <index> - 즉시 색인 체크 실행"]
+    async fn check_index(&self, index_name: &str) -> Result<String, anyhow::Error> {
+        let index_schedule: &IndexSchedules = self.find_schedule(index_name)?;
+
+        self.main_handler.main_task(index_schedule, None).await?;
+
+        Ok(format!(
+            "[Check Result]\n{}",
+            self.format_status_line(index_schedule)
+        ))
+    }
+
+    #[doc = "/nodes - Elasticsearch 노드별 연결 상태"]
+    fn node_states(&self) -> Result<String, anyhow::Error> {
+        let mut msg: String = String::from("[Elasticsearch Nodes]\n");
+
+        for node_status in self.main_handler.es_node_states() {
+            msg.push_str(&format_node_status(&node_status));
+            msg.push('\n');
+        }
+
+        Ok(msg)
+    }
+}
+
+#[doc = "노드 상태를 한 줄 문자열로 변환해주는 함수"]
+fn format_node_status(node_status: &EsNodeStatus) -> String {
+    let mut line: String = format!(
+        "- [{}] {}: {} (failures: {})",
+        node_status.cluster_name(),
+        node_status.url(),
+        node_status.state(),
+        node_status.consecutive_failures()
+    );
+
+    if let Some(retry_in_sec) = node_status.retry_in_sec() {
+        line.push_str(&format!(", retry in {}s", retry_in_sec));
+    }
+
+    if let Some(last_error) = node_status.last_error() {
+        line.push_str(&format!("\n   - last error: {}", last_error));
+    }
+
+    line
+}
+
+#[doc = "체크 결과를 한 줄 문자열로 변환해주는 함수"]
+pub fn format_check_status(check_status: &IndexCheckStatus) -> String {
+    format!(
+        "- {}: {} ({} / {}) at {}",
+        check_status.index_name(),
+        check_status.outcome(),
+        check_status
+            .indexing_cnt_num()
+            .to_formatted_string(&Locale::en),
+        check_status
+            .declare_index_size()
+            .to_formatted_string(&Locale::en),
+        check_status.checked_at()
+    )
+}
+
+#[doc = "봇 명령어 도움말"]
+fn help_message() -> String {
+    String::from(
+        "[Commands]\n\
+         /status - all indexes with last outcome\n\
+         /status <index> - last outcome of an index\n\
+         /next - upcoming checks\n\
+         /ack <index> - acknowledge open alarms\n\
+         /snooze <index> <duration> - mute alarms (e.g. 30m, 2h, 1d, max 30d)\n\
+         /check <index> - run the check now\n\
+         /nodes - Elasticsearch node health",
+    )
+}
@@ -27,67 +27,351 @@ use utils_modules::io_utils::*;
 use utils_modules::logger_utils::*;
 
 mod model;
-use model::index_schedules_config::*;
+use model::{
+    cli_args::*, config_validation::*, digest_config::*, history_config::*,
+    index_management_config::*, index_schedules_config::*, leader_election_config::*,
+    outbox_config::*, report_config::*, sla_report::*, telegram_config::*, total_config::*,
+};
 
 mod handler;
-use handler::main_handler::*;
+use handler::{cli_command_handler::*, main_handler::*, telegram_command_handler::*};
 
 mod repository;
+use repository::es_repository::*;
 
 mod service;
-use service::{notification_service::*, query_service::*};
+use service::{notification_service::*, query_service::*, status_service::*};
 
 mod env_configuration;
 use env_configuration::env_config::*;
 
 mod traits;
 
+mod errors;
+use errors::app_error::*;
+
+#[doc = "에러를 로그 / 표준에러로 남기고, 에러 종류에 맞는 exit code 로 종료해주는 함수"]
+fn exit_with_error(context: &str, err: impl Into<anyhow::Error>) -> ! {
+    let err: anyhow::Error = err.into();
+
+    error!("[Error][main() -> {}] {:?}", context, err);
+    eprintln!("{:?}", err);
+    std::process::exit(AppError::exit_code_of(&err));
+}
+
 #[tokio::main]
 async fn main() {
-    /* 전역 로거설정 */
+    /* .env 를 먼저 읽어야 명령어 인자로 지정하지 않은 설정 경로를 환경변수에서 채울 수 있다. */
     dotenv().ok();
-    set_global_logger();
+
+    let cli_args: CliArgs = CliArgs::parse();
+
+    /* 전역 로거설정 */
+    if let Err(e) = set_global_logger() {
+        exit_with_error("set_global_logger()", e);
+    }
+
+    /* 설정 파일 경로 - 명령어 인자 > 환경변수 */
+    if let Err(e) = initialize_config_paths(cli_args.config_paths().to_path_map()) {
+        exit_with_error("initialize_config_paths()", e);
+    }
 
     info!("Program start!");
 
+    /* 설정 파일 검증 - 문제가 하나라도 있으면 아무 작업도 시작하지 않는다. */
+    let validation_report: ConfigValidationReport = ConfigValidationReport::validate();
+    validation_report.log_issues();
+
+    let config_exit_code: i32 = AppError::Config(String::new()).exit_code();
+
+    if let Some(CliCommand::Validate) = cli_args.command() {
+        for issue in validation_report.issues() {
+            println!("{}", issue.describe());
+        }
+
+        println!("{}", validation_report.summary());
+        std::process::exit(if validation_report.has_errors() {
+            config_exit_code
+        } else {
+            0
+        });
+    }
+
+    if validation_report.has_errors() {
+        for issue in validation_report.issues() {
+            eprintln!("{}", issue.describe());
+        }
+
+        eprintln!("{} - startup aborted", validation_report.summary());
+        std::process::exit(config_exit_code);
+    }
+
+    /* 전역 설정 / Elasticsearch 클라이언트 초기화 */
+    if let Err(e) = initialize_server_config() {
+        exit_with_error("initialize_server_config()", e);
+    }
+
+    if let Err(e) = initialize_elastic_clusters() {
+        exit_with_error("initialize_elastic_clusters()", e);
+    }
+
     let query_service: QueryServicePub = QueryServicePub::new();
-    let notification_service: NotificationServicePub = NotificationServicePub::new();
+    let notification_service: NotificationServicePub = match NotificationServicePub::new() {
+        Ok(notification_service) => notification_service,
+        Err(e) => exit_with_error("NotificationServicePub::new()", e),
+    };
+    let dry_run: bool = *cli_args.dry_run();
+    let leader_election_config: Arc<LeaderElectionConfig> = get_leader_election_config_info();
+    /* dry-run 은 리더 임대를 쓰지 않고 항상 리더로 동작 */
+    let leader_election_enabled: bool = *leader_election_config.enabled() && !dry_run;
+    let status_service: StatusServicePub = StatusServicePub::new(leader_election_enabled);
+
+    let handler_arc: Arc<MainHandler<NotificationServicePub, QueryServicePub, StatusServicePub>> =
+        Arc::new(MainHandler::new(
+            notification_service,
+            query_service,
+            status_service,
+            dry_run,
+        ));
+
+    if dry_run {
+        info!("Dry-run mode: alarms, deletes and notifications are printed instead of performed");
+    }
+
+    /* 모니터링 대상이 되는 색인될 인덱스 정보들 */
+    let index_schdules: IndexSchedulesConfig = match get_index_list_path().and_then(|path| {
+        read_toml_from_file::<IndexSchedulesConfig>(&path)
+            .map_err(|e| AppError::Config(format!("Failed to load '{}': {}", path, e)))
+    }) {
+        Ok(index_schdules) => index_schdules,
+        Err(e) => exit_with_error("load index schedules config", e),
+    };
+
+    /* 하위 명령어 실행 후 종료 (run 이거나 명령어가 없으면 모니터링 서버로 동작) */
+    if let Some(command) = cli_args
+        .command()
+        .as_ref()
+        .filter(|command| !matches!(command, CliCommand::Run))
+    {
+        let cli_handler: CliCommandHandler<
+            NotificationServicePub,
+            QueryServicePub,
+            StatusServicePub,
+        > = CliCommandHandler::new(Arc::clone(&handler_arc), index_schdules.index.clone());
+
+        if let Err(e) = cli_handler.run(command).await {
+            exit_with_error(&format!("{:?}", command), e);
+        }
+
+        return;
+    }
+
+    /* index template / ILM policy 설치 후 종료 (여러 번 실행해도 안전) */
+    if *cli_args.bootstrap() {
+        if let Err(e) = handler_arc.bootstrap_indices().await {
+            exit_with_error("bootstrap_indices()", e);
+        }
+
+        println!("Bootstrap completed.");
+        return;
+    }
+
+    /* 모니터링 인덱스 준비 - 실패해도 모니터링은 계속 진행 */
+    let index_management_config: Arc<IndexManagementConfig> = get_index_management_config_info();
+
+    if *index_management_config.bootstrap_on_startup() {
+        if let Err(e) = handler_arc.bootstrap_indices().await {
+            error!("[Error][main() -> bootstrap_indices()] {:?}", e);
+        }
+    }
+
+    /* 리더 선출 테스크 - 리더만 체크 / 알림 / 리포트를 실행하고, 나머지는 대기 */
+    if leader_election_enabled {
+        let leader_handler: Arc<
+            MainHandler<NotificationServicePub, QueryServicePub, StatusServicePub>,
+        > = Arc::clone(&handler_arc);
 
-    let handler_arc: Arc<MainHandler<NotificationServicePub, QueryServicePub>> =
-        Arc::new(MainHandler::new(notification_service, query_service));
+        tokio::spawn(async move {
+            if let Err(e) = leader_handler.leader_election_task().await {
+                error!("[Error][main() -> leader_election_task()] {:?}", e);
+            }
+        });
+    }
 
-    let alarm_handler: Arc<MainHandler<NotificationServicePub, QueryServicePub>> =
-        Arc::clone(&handler_arc);
+    /*
+        dry-run 에서는 운영 중인 알람 / 발송 대기열 문서를 보여주지 않도록 알람 / 발송 테스크를 띄우지 않는다.
+        (이 프로세스의 체크 결과로 만들어질 알림은 장애를 기록할 때 바로 출력)
+    */
+    if !dry_run {
+        let alarm_handler: Arc<
+            MainHandler<NotificationServicePub, QueryServicePub, StatusServicePub>,
+        > = Arc::clone(&handler_arc);
 
-    /* 알람 테스크 */
-    tokio::spawn(async move {
-        let mut other_interval: Interval = tokio::time::interval(Duration::from_secs(60));
+        /* 알람 테스크 */
+        tokio::spawn(async move {
+            let mut other_interval: Interval = tokio::time::interval(Duration::from_secs(60));
 
-        loop {
-            other_interval.tick().await;
+            loop {
+                other_interval.tick().await;
 
-            match alarm_handler.alarm_task().await {
-                Ok(_) => (),
-                Err(e) => {
-                    error!("[Error][main() -> alarm_task()] {:?}", e);
+                if !alarm_handler.is_leader() {
+                    continue;
+                }
+
+                match alarm_handler.alarm_task().await {
+                    Ok(_) => (),
+                    Err(e) => {
+                        error!("[Error][main() -> alarm_task()] {:?}", e);
+                    }
                 }
             }
-        }
-    });
+        });
 
-    /* 모니터링 대상이 되는 색인될 인덱스 정보들 */
-    let index_schdules: IndexSchedulesConfig =
-        match read_toml_from_file::<IndexSchedulesConfig>(&INDEX_LIST_PATH) {
-            Ok(index_schdules) => index_schdules,
-            Err(e) => {
-                error!("[Error][Failed to load index schedules config] {:?}", e);
-                panic!(
-                    "[Fatal] Cannot continue without valid configuration: {:?}",
-                    e
-                );
+        let outbox_handler: Arc<
+            MainHandler<NotificationServicePub, QueryServicePub, StatusServicePub>,
+        > = Arc::clone(&handler_arc);
+
+        /* 알림 발송 테스크 - 발송 대기열(outbox)을 확인해서 발송/재시도 */
+        tokio::spawn(async move {
+            let outbox_config: Arc<OutboxConfig> = get_outbox_config_info();
+            let mut outbox_interval: Interval =
+                tokio::time::interval(Duration::from_secs(*outbox_config.dispatch_interval_sec()));
+
+            loop {
+                outbox_interval.tick().await;
+
+                if !outbox_handler.is_leader() {
+                    continue;
+                }
+
+                if let Err(e) = outbox_handler.outbox_task().await {
+                    error!("[Error][main() -> outbox_task()] {:?}", e);
+                }
+            }
+        });
+    }
+
+    /* 체크 결과 이력 저장 테스크 - 버퍼에 쌓인 이력을 bulk 로 저장 */
+    let history_config: Arc<HistoryConfig> = get_history_config_info();
+
+    if *history_config.enabled() {
+        let history_handler: Arc<
+            MainHandler<NotificationServicePub, QueryServicePub, StatusServicePub>,
+        > = Arc::clone(&handler_arc);
+        let flush_interval_sec: u64 = *history_config.flush_interval_sec();
+
+        tokio::spawn(async move {
+            let mut flush_interval: Interval =
+                tokio::time::interval(Duration::from_secs(flush_interval_sec));
+
+            loop {
+                flush_interval.tick().await;
+                history_handler.flush_check_history().await;
+            }
+        });
+    }
+
+    /* 보관 기간이 지난 알람 / 발송 대기열 문서 정리 테스크 */
+    if let Some(cleanup_cron) = index_management_config.cleanup_cron().clone() {
+        let cleanup_handler: Arc<
+            MainHandler<NotificationServicePub, QueryServicePub, StatusServicePub>,
+        > = Arc::clone(&handler_arc);
+
+        tokio::spawn(async move {
+            if let Err(e) = cleanup_handler.cleanup_schedule_task(cleanup_cron).await {
+                error!("[Error][main() -> cleanup_schedule_task()] {:?}", e);
             }
+        });
+    }
+
+    /* Elasticsearch 노드 목록 갱신(sniffing) 테스크 - 클러스터별 */
+    for cluster_config in get_cluster_configs_info().iter() {
+        let Some(sniff_interval_sec) = *cluster_config.elastic_sniff_interval_sec() else {
+            continue;
         };
 
+        let sniff_handler: Arc<
+            MainHandler<NotificationServicePub, QueryServicePub, StatusServicePub>,
+        > = Arc::clone(&handler_arc);
+        let cluster_name: String = cluster_config.elastic_cluster_name().to_string();
+
+        tokio::spawn(async move {
+            let mut sniff_interval: Interval =
+                tokio::time::interval(Duration::from_secs(sniff_interval_sec));
+
+            loop {
+                sniff_interval.tick().await;
+
+                if let Err(e) = sniff_handler.refresh_es_nodes(&cluster_name).await {
+                    error!(
+                        "[Error][main() -> refresh_es_nodes({})] {:?}",
+                        cluster_name, e
+                    );
+                }
+            }
+        });
+    }
+
+    /* Telegram 봇 명령어 테스크 (/status, /ack, /snooze, /check ...) */
+    let telegram_config: Arc<TelegramConfig> = get_telegram_config_info();
+
+    /* 명령어 응답도 발송이므로 dry-run 에서는 받지 않는다. */
+    if *telegram_config.command_enabled() && !dry_run {
+        let command_handler: TelegramCommandHandler<
+            NotificationServicePub,
+            QueryServicePub,
+            StatusServicePub,
+        > = TelegramCommandHandler::new(Arc::clone(&handler_arc), index_schdules.index.clone());
+
+        tokio::spawn(async move {
+            if let Err(e) = command_handler.command_poll_task().await {
+                error!("[Error][main() -> command_poll_task()] {:?}", e);
+            }
+        });
+    }
+
+    /* 일일 상태 요약(digest) 테스크 */
+    let digest_config: Arc<DigestConfig> = get_digest_config_info();
+
+    if *digest_config.enabled() {
+        let digest_handler: Arc<
+            MainHandler<NotificationServicePub, QueryServicePub, StatusServicePub>,
+        > = Arc::clone(&handler_arc);
+        let digest_schedules: Vec<IndexSchedules> = index_schdules.index.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = digest_handler.digest_schedule_task(digest_schedules).await {
+                error!("[Error][main() -> digest_schedule_task()] {:?}", e);
+            }
+        });
+    }
+
+    /* 주간 / 월간 SLA 리포트 테스크 */
+    let report_config: Arc<ReportConfig> = get_report_config_info();
+
+    for (period, cron) in [
+        (ReportPeriod::Weekly, report_config.weekly_cron()),
+        (ReportPeriod::Monthly, report_config.monthly_cron()),
+    ] {
+        let Some(cron) = cron.clone() else {
+            continue;
+        };
+
+        let report_handler: Arc<
+            MainHandler<NotificationServicePub, QueryServicePub, StatusServicePub>,
+        > = Arc::clone(&handler_arc);
+        let report_schedules: Vec<IndexSchedules> = index_schdules.index.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = report_handler
+                .report_schedule_task(report_schedules, period, cron)
+                .await
+            {
+                error!("[Error][main() -> report_schedule_task()] {:?}", e);
+            }
+        });
+    }
+
     /*
         각 인덱스 별로 모니터링을 비동기적으로 실시해준다.
         스케쥴링 대기 작업 진행
@@ -95,8 +379,9 @@ async fn main() {
     for index in index_schdules.index {
         let index_clone: IndexSchedules = index.clone();
 
-        let handler_arc_clone: Arc<MainHandler<NotificationServicePub, QueryServicePub>> =
-            Arc::clone(&handler_arc);
+        let handler_arc_clone: Arc<
+            MainHandler<NotificationServicePub, QueryServicePub, StatusServicePub>,
+        > = Arc::clone(&handler_arc);
 
         tokio::spawn(async move {
             if let Err(e) = handler_arc_clone
@@ -117,4 +402,12 @@ async fn main() {
             info!("Received Ctrl+C, shutting down...");
         }
     }
+
+    /* 아직 저장하지 못한 체크 결과 이력 저장 */
+    handler_arc.flush_check_history().await;
+
+    /* 대기 인스턴스가 바로 넘겨받을 수 있도록 리더 임대 반납 */
+    if let Err(e) = handler_arc.release_leader_lease().await {
+        error!("[Error][main() -> release_leader_lease()] {:?}", e);
+    }
 }
@@ -0,0 +1,71 @@
+use crate::common::*;
+
+use crate::model::leader_lease::*;
+
+use crate::utils_modules::traits::*;
+
+/*
+    알람 일시중지(/snooze) 문서 - 재시작 / 리더 변경 후에도 유지되도록 임대 인덱스에 인덱스별로 하나씩 저장한다.
+    시각은 임대 문서와 같이 UTC 로 저장
+*/
+#[derive(Serialize, Deserialize, Debug, Getters, Clone)]
+#[getset(get = "pub")]
+pub struct AlarmSnooze {
+    pub index_name: String,
+    pub snoozed_at: String,
+    pub snoozed_until: String,
+}
+
+impl AlarmSnooze {
+    #[doc = "now 부터 until 까지 알람을 보내지 않는 일시중지 문서를 만들어주는 함수"]
+    /// # Arguments
+    /// * `index_name` - 알람을 멈출 색인 대상 인덱스 이름
+    /// * `now` - 현재 시각 (UTC)
+    /// * `until` - 일시중지 만료 시각 (UTC)
+    ///
+    /// # Returns
+    /// * AlarmSnooze
+    pub fn new(index_name: &str, now: NaiveDateTime, until: NaiveDateTime) -> Self {
+        Self {
+            index_name: index_name.to_string(),
+            snoozed_at: now.format(LEADER_LEASE_TIME_FORMAT).to_string(),
+            snoozed_until: until.format(LEADER_LEASE_TIME_FORMAT).to_string(),
+        }
+    }
+
+    #[doc = "인덱스마다 문서 하나 - 다시 /snooze 하면 덮어쓴다."]
+    pub fn doc_id(&self) -> String {
+        format!("snooze-{}", self.index_name)
+    }
+
+    #[doc = "일시중지 만료 시각 (UTC) - 형식이 잘못된 문서는 None"]
+    pub fn snoozed_until_utc(&self) -> Option<NaiveDateTime> {
+        NaiveDateTime::parse_from_str(&self.snoozed_until, LEADER_LEASE_TIME_FORMAT).ok()
+    }
+}
+
+impl FromSearchHit<AlarmSnooze> for AlarmSnooze {
+    fn from_search_hit(_doc_id: String, alarm_snooze: AlarmSnooze) -> Self {
+        alarm_snooze
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alarm_snooze_round_trips_until() {
+        let now: NaiveDateTime = NaiveDate::from_ymd_opt(2025, 1, 1)
+            .unwrap()
+            .and_hms_opt(1, 0, 0)
+            .unwrap();
+        let until: NaiveDateTime = now + chrono::Duration::hours(2);
+
+        let alarm_snooze: AlarmSnooze = AlarmSnooze::new("product_a", now, until);
+
+        assert_eq!(alarm_snooze.doc_id(), "snooze-product_a");
+        assert_eq!(alarm_snooze.snoozed_until(), "2025-01-01T03:00:00Z");
+        assert_eq!(alarm_snooze.snoozed_until_utc(), Some(until));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::common::*;
+
+use crate::model::{error_alarm_info::*, error_alarm_info_format::*};
+
+#[derive(Serialize, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct AlarmTemplateItem {
+    pub cluster_name: String,
+    pub index_name: String,
+    pub index_type: String,
+    pub error_type: String,
+    pub severity: String,
+    pub reason: String,
+    pub duration: i64,
+    pub indexing_cnt_num: usize,
+    pub declare_index_size: usize,
+    pub indexing_cnt: String,
+    pub declare_size: String,
+    pub timestamp: String,
+    pub alarm_state: String,
+    pub first_seen: String,
+    pub last_seen: String,
+    pub occurrence_count: u64,
+}
+
+impl AlarmTemplateItem {
+    #[doc = "에러 알람 정보를 템플릿 렌더링용 객체로 변환해주는 함수"]
+    /// # Arguments
+    /// * `default_cluster_name` - 클러스터 이름이 없는 이전 버전 알람에 표시할 클러스터 이름
+    /// * `error_alarm_info` - 에러 알람 정보
+    ///
+    /// # Returns
+    /// * Self
+    pub fn from_alarm_info(default_cluster_name: &str, error_alarm_info: &ErrorAlarmInfo) -> Self {
+        let severity: &str = if error_alarm_info.error_type() == "Full Error" {
+            "critical"
+        } else {
+            "warning"
+        };
+
+        let cluster_name: &str = if error_alarm_info.cluster_name().is_empty() {
+            default_cluster_name
+        } else {
+            error_alarm_info.cluster_name()
+        };
+
+        Self {
+            cluster_name: cluster_name.to_string(),
+            index_name: error_alarm_info.index_name().to_string(),
+            index_type: error_alarm_info.index_type().to_string(),
+            error_type: error_alarm_info.error_type().to_string(),
+            severity: severity.to_string(),
+            reason: error_alarm_info.reason().to_string(),
+            duration: *error_alarm_info.duration(),
+            indexing_cnt_num: error_alarm_info.indexing_cnt_num,
+            declare_index_size: error_alarm_info.declare_index_size,
+            indexing_cnt: error_alarm_info
+                .indexing_cnt_num
+                .to_formatted_string(&Locale::en),
+            declare_size: error_alarm_info
+                .declare_index_size
+                .to_formatted_string(&Locale::en),
+            timestamp: error_alarm_info.timestamp().to_string(),
+            alarm_state: error_alarm_info.alarm_state().to_string(),
+            first_seen: error_alarm_info.first_seen_or_timestamp().to_string(),
+            last_seen: error_alarm_info.last_seen_or_timestamp().to_string(),
+            occurrence_count: *error_alarm_info.occurrence_count(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct AlarmEmailContext {
+    /* 알람에 포함된 클러스터 이름들 (", " 로 연결) */
+    pub cluster_name: String,
+    pub alarm_cnt: usize,
+    pub full_error_cnt: usize,
+    pub partial_error_cnt: usize,
+    pub consistency_error_cnt: usize,
+    pub alarms: Vec<AlarmTemplateItem>,
+}
+
+impl AlarmEmailContext {
+    pub fn new(default_cluster_name: &str, error_alarm_infos: &[ErrorAlarmInfoFormat]) -> Self {
+        let alarms: Vec<AlarmTemplateItem> = error_alarm_infos
+            .iter()
+            .map(|alarm| {
+                AlarmTemplateItem::from_alarm_info(default_cluster_name, alarm.error_alarm_info())
+            })
+            .collect();
+
+        let mut cluster_names: Vec<&str> = Vec::new();
+
+        for alarm in &alarms {
+            if !cluster_names.contains(&alarm.cluster_name.as_str()) {
+                cluster_names.push(&alarm.cluster_name);
+            }
+        }
+
+        let cluster_name: String = if cluster_names.is_empty() {
+            default_cluster_name.to_string()
+        } else {
+            cluster_names.join(", ")
+        };
+
+        let full_error_cnt: usize = alarms
+            .iter()
+            .filter(|alarm| alarm.error_type == "Full Error")
+            .count();
+        let consistency_error_cnt: usize = alarms
+            .iter()
+            .filter(|alarm| alarm.error_type == "Consistency Error")
+            .count();
+
+        Self {
+            cluster_name,
+            alarm_cnt: alarms.len(),
+            full_error_cnt,
+            partial_error_cnt: alarms.len() - full_error_cnt - consistency_error_cnt,
+            consistency_error_cnt,
+            alarms,
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use crate::common::*;
+
+/* 한번에 에러 메시지로 보여줄 실패 항목 수 */
+const BULK_ERROR_PREVIEW_CNT: usize = 3;
+
+#[doc = "_bulk 요청 한 건에 들어가는 작업 (index / create / update / delete)"]
+#[derive(Debug, Clone)]
+pub enum BulkOperation {
+    /* doc_id 가 없으면 자동 생성 */
+    Index {
+        doc_id: Option<String>,
+        document: Value,
+    },
+    /* data stream 은 create 만 허용 */
+    Create {
+        document: Value,
+    },
+    Update {
+        doc_id: String,
+        partial_doc: Value,
+    },
+    Delete {
+        doc_id: String,
+    },
+}
+
+impl BulkOperation {
+    #[doc = "구조체를 문서로 변환해서 자동 생성 id 의 index 작업을 만들어주는 함수"]
+    pub fn index_from_struct<T: Serialize>(param_struct: &T) -> Result<Self, anyhow::Error> {
+        Ok(Self::Index {
+            doc_id: None,
+            document: serde_json::to_value(param_struct)?,
+        })
+    }
+
+    #[doc = "구조체를 문서로 변환해서 자동 생성 id 의 create 작업을 만들어주는 함수 (data stream 용)"]
+    pub fn create_from_struct<T: Serialize>(param_struct: &T) -> Result<Self, anyhow::Error> {
+        Ok(Self::Create {
+            document: serde_json::to_value(param_struct)?,
+        })
+    }
+
+    #[doc = "부분 수정(update) 작업을 만들어주는 함수"]
+    pub fn update(doc_id: &str, partial_doc: Value) -> Self {
+        Self::Update {
+            doc_id: doc_id.to_string(),
+            partial_doc,
+        }
+    }
+
+    #[doc = "다시 보내도 결과가 같은 작업인지 - 자동 생성 id 의 index / create 는 중복 문서가 생긴다."]
+    pub fn is_idempotent(&self) -> bool {
+        match self {
+            Self::Index { doc_id, .. } => doc_id.is_some(),
+            Self::Create { .. } => false,
+            Self::Update { .. } | Self::Delete { .. } => true,
+        }
+    }
+
+    #[doc = "_bulk 본문(NDJSON)의 줄들로 변환해주는 함수 - action 줄 + (delete 가 아니면) source 줄"]
+    /// # Arguments
+    /// * `index_name` - 작업 대상 인덱스 이름
+    ///
+    /// # Returns
+    /// * Vec<Value>
+    pub fn to_bulk_lines(&self, index_name: &str) -> Vec<Value> {
+        match self {
+            Self::Index { doc_id, document } => {
+                let mut action: Value = json!({ "index": { "_index": index_name } });
+
+                if let Some(doc_id) = doc_id {
+                    action["index"]["_id"] = json!(doc_id);
+                }
+
+                vec![action, document.clone()]
+            }
+            Self::Create { document } => {
+                vec![
+                    json!({ "create": { "_index": index_name } }),
+                    document.clone(),
+                ]
+            }
+            Self::Update {
+                doc_id,
+                partial_doc,
+            } => {
+                vec![
+                    json!({ "update": { "_index": index_name, "_id": doc_id } }),
+                    json!({ "doc": partial_doc }),
+                ]
+            }
+            Self::Delete { doc_id } => {
+                vec![json!({ "delete": { "_index": index_name, "_id": doc_id } })]
+            }
+        }
+    }
+}
+
+#[doc = "_bulk 응답의 항목 하나의 결과"]
+#[derive(Debug, Getters, Clone, new)]
+#[getset(get = "pub")]
+pub struct BulkItemResult {
+    /* 요청한 작업 목록에서의 순서 */
+    pub position: usize,
+    /* index | create | update | delete */
+    pub action: String,
+    pub doc_id: Option<String>,
+    pub status: u16,
+    pub error: Option<String>,
+}
+
+impl BulkItemResult {
+    #[doc = "_bulk 응답의 items 항목 하나를 해석해주는 함수"]
+    /// # Arguments
+    /// * `position` - 요청한 작업 목록에서의 순서
+    /// * `item` - `{ "<action>": { "_id": .., "status": .., "error": .. } }`
+    ///
+    /// # Returns
+    /// * Result<Self, anyhow::Error>
+    pub fn from_response_item(position: usize, item: &Value) -> Result<Self, anyhow::Error> {
+        let (action, detail) = item
+            .as_object()
+            .and_then(|obj| obj.iter().next())
+            .ok_or_else(|| {
+                anyhow!(
+                    "[BulkItemResult->from_response_item] Invalid bulk item: {}",
+                    item
+                )
+            })?;
+
+        let error: Option<String> =
+            detail
+                .get("error")
+                .map(|err| match (err["type"].as_str(), err["reason"].as_str()) {
+                    (Some(err_type), Some(reason)) => format!("{}: {}", err_type, reason),
+                    _ => err.to_string(),
+                });
+
+        Ok(Self {
+            position,
+            action: action.to_string(),
+            doc_id: detail["_id"].as_str().map(str::to_string),
+            status: detail["status"].as_u64().unwrap_or(0) as u16,
+            error,
+        })
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.error.is_none() && (200..300).contains(&self.status)
+    }
+}
+
+#[doc = "_bulk 요청 전체의 결과 - 요청 자체는 성공해도 항목별로 실패할 수 있다."]
+#[derive(Debug, Getters, Default)]
+#[getset(get = "pub")]
+pub struct BulkResult {
+    pub items: Vec<BulkItemResult>,
+}
+
+impl BulkResult {
+    #[doc = "_bulk 응답 본문을 항목별 결과로 변환해주는 함수"]
+    pub fn from_response(response_body: &Value) -> Result<Self, anyhow::Error> {
+        let items: &Vec<Value> = response_body["items"].as_array().ok_or_else(|| {
+            anyhow!("[BulkResult->from_response] Missing 'items' field in bulk response")
+        })?;
+
+        let items: Vec<BulkItemResult> = items
+            .iter()
+            .enumerate()
+            .map(|(position, item)| BulkItemResult::from_response_item(position, item))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { items })
+    }
+
+    pub fn success_cnt(&self) -> usize {
+        self.items.iter().filter(|item| item.is_success()).count()
+    }
+
+    pub fn failures(&self) -> Vec<&BulkItemResult> {
+        self.items
+            .iter()
+            .filter(|item| !item.is_success())
+            .collect()
+    }
+
+    #[doc = "실패한 항목이 있으면 (앞의 몇 건을 예시로) 에러로 바꿔주는 함수"]
+    pub fn ensure_success(&self) -> Result<(), anyhow::Error> {
+        let failures: Vec<&BulkItemResult> = self.failures();
+
+        if failures.is_empty() {
+            return Ok(());
+        }
+
+        let preview: Vec<String> = failures
+            .iter()
+            .take(BULK_ERROR_PREVIEW_CNT)
+            .map(|item| {
+                format!(
+                    "#{} {}({}) status={} {}",
+                    item.position,
+                    item.action,
+                    item.doc_id.as_deref().unwrap_or("-"),
+                    item.status,
+                    item.error.as_deref().unwrap_or("")
+                )
+            })
+            .collect();
+
+        Err(anyhow!(
+            "[BulkResult->ensure_success] {} of {} bulk item(s) failed: {}",
+            failures.len(),
+            self.items.len(),
+            preview.join(", ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bulk_response() -> Value {
+        json!({
+            "took": 3,
+            "errors": true,
+            "items": [
+                { "index": { "_id": "a1", "status": 201 } },
+                { "update": { "_id": "a2", "status": 200 } },
+                {
+                    "update": {
+                        "_id": "a3",
+                        "status": 404,
+                        "error": { "type": "document_missing_exception", "reason": "[a3]: document missing" }
+                    }
+                },
+                { "create": { "status": 409, "error": "version conflict" } }
+            ]
+        })
+    }
+
+    #[test]
+    fn to_bulk_lines_per_action() {
+        let index_lines: Vec<Value> = BulkOperation::Index {
+            doc_id: Some(String::from("a1")),
+            document: json!({ "field": 1 }),
+        }
+        .to_bulk_lines("test-index");
+        assert_eq!(
+            index_lines,
+            vec![
+                json!({ "index": { "_index": "test-index", "_id": "a1" } }),
+                json!({ "field": 1 })
+            ]
+        );
+
+        let update_lines: Vec<Value> =
+            BulkOperation::update("a2", json!({ "field": 2 })).to_bulk_lines("test-index");
+        assert_eq!(update_lines[1], json!({ "doc": { "field": 2 } }));
+
+        let delete_lines: Vec<Value> = BulkOperation::Delete {
+            doc_id: String::from("a3"),
+        }
+        .to_bulk_lines("test-index");
+        assert_eq!(delete_lines.len(), 1);
+    }
+
+    #[test]
+    fn is_idempotent_only_without_auto_ids() {
+        assert!(BulkOperation::update("a1", json!({})).is_idempotent());
+        assert!(BulkOperation::Delete {
+            doc_id: String::from("a1")
+        }
+        .is_idempotent());
+        assert!(BulkOperation::Index {
+            doc_id: Some(String::from("a1")),
+            document: json!({})
+        }
+        .is_idempotent());
+        assert!(!BulkOperation::index_from_struct(&json!({}))
+            .unwrap()
+            .is_idempotent());
+        assert!(!BulkOperation::create_from_struct(&json!({}))
+            .unwrap()
+            .is_idempotent());
+    }
+
+    #[test]
+    fn from_response_parses_each_item() {
+        let bulk_result: BulkResult = BulkResult::from_response(&bulk_response()).unwrap();
+
+        assert_eq!(bulk_result.items().len(), 4);
+        assert_eq!(bulk_result.success_cnt(), 2);
+
+        let failures: Vec<&BulkItemResult> = bulk_result.failures();
+        assert_eq!(failures.len(), 2);
+        assert_eq!(*failures[0].position(), 2);
+        assert_eq!(failures[0].action(), "update");
+        assert_eq!(failures[0].doc_id().as_deref(), Some("a3"));
+        assert_eq!(
+            failures[0].error().as_deref(),
+            Some("document_missing_exception: [a3]: document missing")
+        );
+        assert_eq!(failures[1].doc_id(), &None);
+        assert_eq!(failures[1].error().as_deref(), Some("\"version conflict\""));
+    }
+
+    #[test]
+    fn from_response_without_items_is_error() {
+        assert!(BulkResult::from_response(&json!({ "errors": false })).is_err());
+        assert!(BulkResult::from_response(&json!({ "items": ["index"] })).is_err());
+    }
+
+    #[test]
+    fn ensure_success_reports_failed_items() {
+        let bulk_result: BulkResult = BulkResult::from_response(&bulk_response()).unwrap();
+        let err_msg: String = bulk_result.ensure_success().unwrap_err().to_string();
+
+        assert!(err_msg.contains("2 of 4 bulk item(s) failed"));
+        assert!(err_msg.contains("#2 update(a3) status=404"));
+        assert!(err_msg.contains("#3 create(-) status=409"));
+
+        let ok_result: BulkResult = BulkResult::from_response(&json!({
+            "items": [{ "delete": { "_id": "a1", "status": 200 } }]
+        }))
+        .unwrap();
+        assert!(ok_result.ensure_success().is_ok());
+    }
+}
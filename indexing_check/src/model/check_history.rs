@@ -0,0 +1,130 @@
+use crate::common::*;
+
+use crate::model::check_record::*;
+use crate::model::index_check_status::*;
+use crate::model::index_schedules_config::*;
+use crate::utils_modules::traits::*;
+
+/* 체크 이력의 시각은 모두 UTC 로 저장 (Kibana 에서 그대로 사용) */
+pub const CHECK_HISTORY_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+/* 스케쥴에 의한 체크 / 봇 명령어(/check) 등으로 실행한 체크 */
+pub const CHECK_TRIGGER_SCHEDULE: &str = "schedule";
+pub const CHECK_TRIGGER_MANUAL: &str = "manual";
+
+#[derive(Serialize, Deserialize, Debug, Getters, Setters, Clone)]
+#[getset(get = "pub", set = "pub")]
+pub struct CheckHistory {
+    /* 체크가 끝난 시각 (UTC) */
+    #[serde(rename = "@timestamp")]
+    pub timestamp: String,
+    pub cluster_name: String,
+    pub index_name: String,
+    pub index_type: String,
+    pub trigger: String,
+    /* CRON 실행 예정 시각 (UTC) - 수동 체크인 경우 None */
+    pub scheduled_at: Option<String>,
+    pub outcome: String,
+    /* 색인 개수를 알 수 없는 경우 None */
+    pub indexing_cnt_num: Option<usize>,
+    pub declare_index_size: usize,
+    /* 로그 조회부터 결과 기록까지 걸린 시간(ms) */
+    pub check_latency_ms: u64,
+    /* 판단에 사용한 색인 로그 */
+    pub log_index: String,
+    pub log_doc_id: Option<String>,
+}
+
+impl CheckHistory {
+    #[doc = "색인 체크 결과로 이력 문서를 만들어주는 함수"]
+    /// # Arguments
+    /// * `cluster_name` - Elasticsearch 클러스터 이름
+    /// * `index_schedule` - 인덱스 스케쥴 객체
+    /// * `check_status` - 색인 체크 결과
+    /// * `checked_at` - 체크가 끝난 시각 (UTC)
+    pub fn new(
+        cluster_name: &str,
+        index_schedule: &IndexSchedules,
+        check_status: &IndexCheckStatus,
+        checked_at: NaiveDateTime,
+    ) -> Self {
+        /* Full Error / Unknown 은 색인 개수를 확인하지 못한 경우 */
+        let indexing_cnt_num: Option<usize> = match check_status.outcome().as_str() {
+            "Success" | "Partial Error" => Some(*check_status.indexing_cnt_num()),
+            _ => None,
+        };
+
+        Self {
+            timestamp: checked_at.format(CHECK_HISTORY_TIME_FORMAT).to_string(),
+            cluster_name: cluster_name.to_string(),
+            index_name: index_schedule.index_name().to_string(),
+            index_type: index_schedule.indexing_type().to_string(),
+            trigger: CHECK_TRIGGER_MANUAL.to_string(),
+            scheduled_at: None,
+            outcome: check_status.outcome().to_string(),
+            indexing_cnt_num,
+            declare_index_size: *check_status.declare_index_size(),
+            check_latency_ms: 0,
+            log_index: String::new(),
+            log_doc_id: None,
+        }
+    }
+
+    #[doc = "CRON 실행 예정 시각을 기록하고 스케쥴 체크로 표시해주는 함수"]
+    pub fn set_scheduled(&mut self, scheduled_at: NaiveDateTime) -> &mut Self {
+        self.scheduled_at = Some(scheduled_at.format(CHECK_HISTORY_TIME_FORMAT).to_string());
+        self.trigger = CHECK_TRIGGER_SCHEDULE.to_string();
+        self
+    }
+
+    #[doc = "리포트 집계용 체크 결과로 변환 - 스케쥴 체크는 CRON 실행 시각, 수동 체크는 체크 시각 기준 (한국시간)"]
+    pub fn to_check_record(&self, kst_offset: FixedOffset) -> Result<CheckRecord, anyhow::Error> {
+        let checked_at_str: &str = self.scheduled_at.as_deref().unwrap_or(&self.timestamp);
+        let checked_at_utc: NaiveDateTime =
+            NaiveDateTime::parse_from_str(checked_at_str, CHECK_HISTORY_TIME_FORMAT)?;
+        let checked_at_kst: NaiveDateTime = checked_at_utc
+            .and_utc()
+            .with_timezone(&kst_offset)
+            .naive_local();
+
+        Ok(CheckRecord::new(
+            self.outcome.clone(),
+            checked_at_kst,
+            self.indexing_cnt_num,
+        ))
+    }
+}
+
+#[doc = "체크 이력 data stream 의 mapping - 모든 필드를 명시하고 정의되지 않은 필드는 거부한다."]
+pub fn check_history_mapping() -> Value {
+    json!({
+        "dynamic": "strict",
+        "properties": {
+            "@timestamp": { "type": "date" },
+            "cluster_name": { "type": "keyword" },
+            "index_name": { "type": "keyword" },
+            "index_type": { "type": "keyword" },
+            "trigger": { "type": "keyword" },
+            "scheduled_at": { "type": "date" },
+            "outcome": { "type": "keyword" },
+            "indexing_cnt_num": { "type": "long" },
+            "declare_index_size": { "type": "long" },
+            "check_latency_ms": { "type": "long" },
+            "log_index": { "type": "keyword" },
+            "log_doc_id": { "type": "keyword" }
+        }
+    })
+}
+
+#[derive(Serialize, Deserialize, Debug, Getters, new)]
+#[getset(get = "pub")]
+pub struct CheckHistoryFormat {
+    pub doc_id: String,
+    pub check_history: CheckHistory,
+}
+
+impl FromSearchHit<CheckHistory> for CheckHistoryFormat {
+    fn from_search_hit(doc_id: String, check_history: CheckHistory) -> Self {
+        CheckHistoryFormat::new(doc_id, check_history)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::common::*;
+
+/* 리포트 집계에 사용하는 색인 체크 한 건의 결과 */
+#[derive(Debug, Clone, Getters, new)]
+#[getset(get = "pub")]
+pub struct CheckRecord {
+    pub outcome: String,
+    /* 체크 시각 (한국시간) */
+    pub checked_at: NaiveDateTime,
+    /* 색인 개수를 알 수 없는 경우 None */
+    pub indexing_cnt_num: Option<usize>,
+}
+
+impl CheckRecord {
+    pub fn is_success(&self) -> bool {
+        self.outcome == "Success"
+    }
+
+    pub fn is_failure(&self) -> bool {
+        self.outcome == "Partial Error" || self.outcome == "Full Error"
+    }
+}
@@ -0,0 +1,245 @@
+use crate::common::*;
+
+use crate::model::sla_report::*;
+
+#[derive(Parser, Debug, Getters)]
+#[getset(get = "pub")]
+#[command(
+    name = "indexing_check",
+    about = "Checks that scheduled Elasticsearch indexing jobs completed",
+    args_conflicts_with_subcommands = true
+)]
+pub struct CliArgs {
+    /* 하위 명령어가 없으면 모니터링 서버로 동작 */
+    #[command(subcommand)]
+    pub command: Option<CliCommand>,
+    /// Install index templates and lifecycle policies for the monitor's own indices, then exit
+    #[arg(long)]
+    pub bootstrap: bool,
+    /// Run queries as usual, but print the alarms, deletes and notifications instead of performing them
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+    #[command(flatten)]
+    pub config_paths: ConfigPathArgs,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CliCommand {
+    /// Run the monitoring server (same as running without a command)
+    Run,
+    /// Run the check of one index now and print the result
+    Check(CheckArgs),
+    /// List index schedules with their next check times (KST)
+    List(ListArgs),
+    /// Print the open alerts
+    Alerts(AlertsArgs),
+    /// Send a test message to every notification channel and receiver
+    TestNotify,
+    /// Export the per-index SLA report as CSV or JSON
+    Report(ReportArgs),
+    /// Re-evaluate past check slots against the historical indexing logs and print per-slot outcomes (no alerts are written)
+    #[command(alias = "backfill")]
+    Replay(ReplayArgs),
+    /// Check every configuration file and print all problems, then exit (exit code 2 on errors)
+    Validate,
+}
+
+#[doc = "설정 파일 경로 - 지정하지 않으면 같은 이름의 환경변수(.env) 값을 사용"]
+#[derive(Args, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct ConfigPathArgs {
+    /// system_config.toml path
+    #[arg(long, global = true, value_name = "PATH", env = "SYSTEM_CONFIG_PATH")]
+    pub system_config: Option<String>,
+    /// index_list.toml path
+    #[arg(long, global = true, value_name = "PATH", env = "INDEX_LIST_PATH")]
+    pub index_list: Option<String>,
+    /// email_receiver_info.toml path
+    #[arg(long, global = true, value_name = "PATH", env = "EMAIL_RECEIVER_PATH")]
+    pub email_receiver: Option<String>,
+    /// SQL Server connection info path (iMailer / procedure notifiers)
+    #[arg(long, global = true, value_name = "PATH", env = "SQL_SERVER_INFO_PATH")]
+    pub sql_server_info: Option<String>,
+    /// Alarm email HTML template path
+    #[arg(long, global = true, value_name = "PATH", env = "HTML_TEMPLATE_PATH")]
+    pub html_template: Option<String>,
+    /// Alarm Telegram / text template path
+    #[arg(long, global = true, value_name = "PATH", env = "TEXT_TEMPLATE_PATH")]
+    pub text_template: Option<String>,
+    /// Digest email HTML template path
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        env = "DIGEST_HTML_TEMPLATE_PATH"
+    )]
+    pub digest_html_template: Option<String>,
+    /// Digest text template path
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        env = "DIGEST_TEXT_TEMPLATE_PATH"
+    )]
+    pub digest_text_template: Option<String>,
+    /// SLA report email HTML template path
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        env = "REPORT_HTML_TEMPLATE_PATH"
+    )]
+    pub report_html_template: Option<String>,
+    /// SLA report text template path
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        env = "REPORT_TEXT_TEMPLATE_PATH"
+    )]
+    pub report_text_template: Option<String>,
+}
+
+impl ConfigPathArgs {
+    #[doc = "지정된 경로들을 환경변수 이름 -> 경로 형태로 모아주는 함수"]
+    pub fn to_path_map(&self) -> HashMap<&'static str, String> {
+        [
+            ("SYSTEM_CONFIG_PATH", &self.system_config),
+            ("INDEX_LIST_PATH", &self.index_list),
+            ("EMAIL_RECEIVER_PATH", &self.email_receiver),
+            ("SQL_SERVER_INFO_PATH", &self.sql_server_info),
+            ("HTML_TEMPLATE_PATH", &self.html_template),
+            ("TEXT_TEMPLATE_PATH", &self.text_template),
+            ("DIGEST_HTML_TEMPLATE_PATH", &self.digest_html_template),
+            ("DIGEST_TEXT_TEMPLATE_PATH", &self.digest_text_template),
+            ("REPORT_HTML_TEMPLATE_PATH", &self.report_html_template),
+            ("REPORT_TEXT_TEMPLATE_PATH", &self.report_text_template),
+        ]
+        .into_iter()
+        .filter_map(|(key, path)| path.clone().map(|path| (key, path)))
+        .collect()
+    }
+}
+
+#[derive(Args, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct CheckArgs {
+    /// index_name in index_list.toml
+    pub index: String,
+}
+
+#[derive(Args, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct ListArgs {
+    /// Number of upcoming check times to show per index
+    #[arg(long, default_value_t = 1)]
+    pub count: usize,
+}
+
+#[derive(Args, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct AlertsArgs {
+    /// Only show the alerts of this index
+    #[arg(long)]
+    pub index: Option<String>,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Csv,
+    Json,
+}
+
+#[derive(Args, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct ReportArgs {
+    /// Report the last full week or month (default: weekly)
+    #[arg(long, value_enum, conflicts_with_all = ["from", "to"])]
+    pub period: Option<ReportPeriod>,
+    /// First day of a custom period (KST, YYYY-MM-DD)
+    #[arg(long, requires = "to")]
+    pub from: Option<NaiveDate>,
+    /// Last day of a custom period, inclusive (KST, YYYY-MM-DD)
+    #[arg(long, requires = "from")]
+    pub to: Option<NaiveDate>,
+    #[arg(long, value_enum, default_value_t = ReportFormat::Csv)]
+    pub format: ReportFormat,
+    /// Write to this file instead of stdout
+    #[arg(long)]
+    pub output: Option<String>,
+}
+
+impl ReportArgs {
+    #[doc = "명령어 인자로 리포트 기간 이름과 [start, end) 를 구해주는 함수 (한국시간)"]
+    pub fn resolve_range(
+        &self,
+        now: NaiveDateTime,
+    ) -> Result<(String, NaiveDateTime, NaiveDateTime), anyhow::Error> {
+        match (self.from, self.to) {
+            (Some(from), Some(to)) => {
+                if to < from {
+                    return Err(anyhow!(
+                        "[ReportArgs->resolve_range] --to ({}) is before --from ({})",
+                        to,
+                        from
+                    ));
+                }
+
+                Ok((
+                    String::from("custom"),
+                    from.and_time(NaiveTime::MIN),
+                    (to + chrono::Duration::days(1)).and_time(NaiveTime::MIN),
+                ))
+            }
+            _ => {
+                let period: ReportPeriod = self.period.unwrap_or(ReportPeriod::Weekly);
+                let (start, end) = period.range_before(now)?;
+                Ok((period.name().to_string(), start, end))
+            }
+        }
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayFormat {
+    Table,
+    Csv,
+    Json,
+}
+
+#[derive(Args, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct ReplayArgs {
+    /// First day to replay (KST, YYYY-MM-DD)
+    #[arg(long)]
+    pub from: NaiveDate,
+    /// Last day to replay, inclusive (KST, YYYY-MM-DD)
+    #[arg(long)]
+    pub to: NaiveDate,
+    /// Only replay this index (repeatable, default: every index)
+    #[arg(long = "index", value_name = "INDEX")]
+    pub indexes: Vec<String>,
+    #[arg(long, value_enum, default_value_t = ReplayFormat::Table)]
+    pub format: ReplayFormat,
+    /// Write to this file instead of stdout
+    #[arg(long)]
+    pub output: Option<String>,
+}
+
+impl ReplayArgs {
+    #[doc = "명령어 인자로 replay 기간 [start, end) 를 구해주는 함수 (한국시간)"]
+    pub fn resolve_range(&self) -> Result<(NaiveDateTime, NaiveDateTime), anyhow::Error> {
+        if self.to < self.from {
+            return Err(anyhow!(
+                "[ReplayArgs->resolve_range] --to ({}) is before --from ({})",
+                self.to,
+                self.from
+            ));
+        }
+
+        Ok((
+            self.from.and_time(NaiveTime::MIN),
+            (self.to + chrono::Duration::days(1)).and_time(NaiveTime::MIN),
+        ))
+    }
+}
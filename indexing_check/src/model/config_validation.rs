@@ -0,0 +1,805 @@
+use crate::common::*;
+
+use crate::model::{
+    index_schedules_config::*, procedure_config::*, rdb_config::*, receiver_email_config::*,
+    total_config::*,
+};
+
+use crate::service::template_service::*;
+
+use crate::utils_modules::io_utils::*;
+
+use crate::env_configuration::env_config::*;
+
+/* 인덱스 체크 주기(가장 짧은 CRON 실행 간격)를 구할 때 살펴볼 다음 실행 시각 개수 */
+const CRON_SPACING_SAMPLE_CNT: usize = 64;
+
+/* 색인 로그에 기록되는 색인 종류 */
+const INDEXING_TYPES: [&str; 2] = ["static index", "dynamic index"];
+const ELASTIC_SCHEMES: [&str; 2] = ["http", "https"];
+const EMAIL_BACKENDS: [&str; 2] = ["imailer", "smtp"];
+const SMTP_SECURITIES: [&str; 3] = ["tls", "starttls", "none"];
+const PROCEDURE_PARAM_SOURCES: [&str; 5] = [
+    "recipient",
+    "subject",
+    "html_content",
+    "text_content",
+    "summary",
+];
+
+#[doc = "설정 문제의 심각도 - Error 가 하나라도 있으면 시작하지 않는다."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigIssueLevel {
+    Error,
+    Warning,
+}
+
+#[doc = "설정 파일 검증에서 발견한 문제 하나"]
+#[derive(Debug, Getters, Clone, new)]
+#[getset(get = "pub")]
+pub struct ConfigIssue {
+    pub level: ConfigIssueLevel,
+    /* 문제가 있는 파일 경로 (환경변수 문제는 '.env') */
+    pub file: String,
+    /* 파일 안의 위치 (ex. "[[index]] #2 'product_a'") */
+    pub entry: String,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    #[doc = "문제를 한 줄로 표현해주는 함수"]
+    pub fn describe(&self) -> String {
+        let level: &str = match self.level {
+            ConfigIssueLevel::Error => "ERROR",
+            ConfigIssueLevel::Warning => "WARN",
+        };
+
+        format!(
+            "[{}] {} - {}: {}",
+            level, self.file, self.entry, self.message
+        )
+    }
+}
+
+#[doc = "전체 설정 파일 검증 결과"]
+#[derive(Debug, Getters, Default)]
+#[getset(get = "pub")]
+pub struct ConfigValidationReport {
+    pub issues: Vec<ConfigIssue>,
+}
+
+impl ConfigValidationReport {
+    #[doc = "환경변수가 가리키는 설정 파일들을 모두 읽어서 검증해주는 함수"]
+    /// 전역 설정(once_lazy)은 잘못된 값이 있으면 처음 사용할 때 panic 이 발생하므로,
+    /// 여기서는 파일을 직접 읽어서 문제를 모두 모은 뒤 한번에 보여준다.
+    ///
+    /// # Returns
+    /// * ConfigValidationReport
+    pub fn validate() -> Self {
+        let mut report: Self = Self::default();
+
+        let system_config_path: Option<String> = report.required_env("SYSTEM_CONFIG_PATH");
+        let index_list_path: Option<String> = report.required_env("INDEX_LIST_PATH");
+        let email_receiver_path: Option<String> = report.required_env("EMAIL_RECEIVER_PATH");
+        let html_template_path: Option<String> = report.required_env("HTML_TEMPLATE_PATH");
+
+        let mut system_config: Option<ConfigNotSafe> = system_config_path
+            .as_deref()
+            .and_then(|path| report.load_toml::<ConfigNotSafe>(path));
+
+        if let (Some(path), Some(config)) = (&system_config_path, system_config.as_mut()) {
+            report.resolve_system_secrets(path, config);
+        }
+
+        if let (Some(path), Some(config)) = (&system_config_path, &system_config) {
+            report.check_system_config(path, config);
+
+            /* iMailer / 프로시저 알림 채널을 쓰는 경우에만 SQL Server 접속 정보가 필요 */
+            if config.email.backend == "imailer" || !config.procedure_notifier.is_empty() {
+                if let Some(rdb_path) = report.required_env("SQL_SERVER_INFO_PATH") {
+                    if let Some(mut rdb_config) = report.load_toml::<RdbConfig>(&rdb_path) {
+                        if let Err(e) = rdb_config.resolve_secret_files() {
+                            report.error(&rdb_path, "user_pw", e.to_string());
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(path) = &index_list_path {
+            if let Some(index_schedules) = report.load_toml::<IndexSchedulesConfig>(path) {
+                let cluster_names: Option<Vec<&str>> = system_config.as_ref().map(|config| {
+                    std::iter::once(&config.elasticsearch)
+                        .chain(config.clusters.iter())
+                        .map(|cluster| cluster.elastic_cluster_name().as_str())
+                        .collect()
+                });
+
+                report.check_index_schedules(path, &index_schedules, cluster_names.as_deref());
+            }
+        }
+
+        if let Some(path) = &email_receiver_path {
+            if let Some(receivers) = report.load_toml::<ReceiverEmailConfig>(path) {
+                report.check_receivers(path, &receivers);
+            }
+        }
+
+        /* 템플릿 파일이 없거나 문법 오류가 있으면 알람을 보낼 수 없다. */
+        if let Some(path) = &html_template_path {
+            if let Err(e) = TemplateServicePub::new() {
+                report.error(path, "template", format!("{:#}", e));
+            }
+        }
+
+        report
+    }
+
+    fn error(&mut self, file: &str, entry: &str, message: String) {
+        self.issues.push(ConfigIssue::new(
+            ConfigIssueLevel::Error,
+            file.to_string(),
+            entry.to_string(),
+            message,
+        ));
+    }
+
+    fn warning(&mut self, file: &str, entry: &str, message: String) {
+        self.issues.push(ConfigIssue::new(
+            ConfigIssueLevel::Warning,
+            file.to_string(),
+            entry.to_string(),
+            message,
+        ));
+    }
+
+    pub fn error_cnt(&self) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.level == ConfigIssueLevel::Error)
+            .count()
+    }
+
+    pub fn warning_cnt(&self) -> usize {
+        self.issues.len() - self.error_cnt()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.error_cnt() > 0
+    }
+
+    #[doc = "검증 결과 요약 문구"]
+    pub fn summary(&self) -> String {
+        format!(
+            "Configuration check: {} error(s), {} warning(s)",
+            self.error_cnt(),
+            self.warning_cnt()
+        )
+    }
+
+    #[doc = "발견한 문제들을 로그로 남겨주는 함수"]
+    pub fn log_issues(&self) {
+        for issue in &self.issues {
+            match issue.level {
+                ConfigIssueLevel::Error => error!("[ConfigValidation] {}", issue.describe()),
+                ConfigIssueLevel::Warning => warn!("[ConfigValidation] {}", issue.describe()),
+            }
+        }
+    }
+
+    #[doc = "필수 설정 파일 경로(명령어 인자 / 환경변수)를 읽어주는 함수 - 없으면 문제로 기록하고 None"]
+    fn required_env(&mut self, key: &str) -> Option<String> {
+        let path: Option<String> = get_config_path(key);
+
+        if path.is_none() {
+            self.error(".env", key, String::from("must be set"));
+        }
+
+        path
+    }
+
+    #[doc = "toml 파일을 읽어주는 함수 - 읽기 / 파싱 실패는 문제로 기록하고 None"]
+    fn load_toml<T: DeserializeOwned>(&mut self, path: &str) -> Option<T> {
+        match read_toml_from_file::<T>(path) {
+            Ok(config) => Some(config),
+            Err(e) => {
+                self.error(path, "file", e.to_string().trim_end().to_string());
+                None
+            }
+        }
+    }
+
+    #[doc = "system_config.toml 의 비밀값 `<항목>_file` 을 읽어보는 함수 - 실패는 섹션별로 기록"]
+    fn resolve_system_secrets(&mut self, file: &str, config: &mut ConfigNotSafe) {
+        if let Err(e) = config.elasticsearch.resolve_secret_files() {
+            self.error(file, "[elasticsearch]", e.to_string());
+        }
+
+        for (position, cluster) in config.clusters.iter_mut().enumerate() {
+            if let Err(e) = cluster.resolve_secret_files() {
+                let entry: String = format!(
+                    "[[clusters]] #{} '{}'",
+                    position,
+                    cluster.elastic_cluster_name()
+                );
+                self.error(file, &entry, e.to_string());
+            }
+        }
+
+        if let Some(smtp) = config.smtp.as_mut() {
+            if let Err(e) = smtp.resolve_secret_files() {
+                self.error(file, "[smtp]", e.to_string());
+            }
+        }
+
+        if let Err(e) = config.telegram.resolve_secret_files() {
+            self.error(file, "[telegram]", e.to_string());
+        }
+    }
+
+    #[doc = "CRON 표현식을 확인해주는 함수 - 파싱할 수 없거나 다시 실행되지 않으면 문제로 기록"]
+    /// # Arguments
+    /// * `file` - 설정 파일 경로
+    /// * `entry` - 설정 파일 안의 위치
+    /// * `cron` - CRON 표현식
+    ///
+    /// # Returns
+    /// * Option<Schedule>
+    fn check_cron(&mut self, file: &str, entry: &str, cron: &str) -> Option<Schedule> {
+        match Schedule::from_str(cron) {
+            Ok(schedule) => {
+                if schedule.upcoming(Utc).next().is_none() {
+                    self.error(
+                        file,
+                        entry,
+                        format!("CRON expression '{}' never fires again", cron),
+                    );
+                    return None;
+                }
+
+                Some(schedule)
+            }
+            Err(e) => {
+                self.error(
+                    file,
+                    entry,
+                    format!("invalid CRON expression '{}': {}", cron, e),
+                );
+                None
+            }
+        }
+    }
+
+    #[doc = "system_config.toml 의 값들을 확인해주는 함수"]
+    fn check_system_config(&mut self, file: &str, config: &ConfigNotSafe) {
+        /* Elasticsearch 클러스터 - 첫 번째는 [elasticsearch] */
+        let mut cluster_names: HashSet<&str> = HashSet::new();
+
+        for (position, cluster) in std::iter::once(&config.elasticsearch)
+            .chain(config.clusters.iter())
+            .enumerate()
+        {
+            let entry: String = if position == 0 {
+                String::from("[elasticsearch]")
+            } else {
+                format!(
+                    "[[clusters]] #{} '{}'",
+                    position - 1,
+                    cluster.elastic_cluster_name()
+                )
+            };
+
+            if cluster.elastic_cluster_name().trim().is_empty() {
+                self.error(file, &entry, String::from("elastic_cluster_name is empty"));
+            } else if !cluster_names.insert(cluster.elastic_cluster_name()) {
+                self.error(
+                    file,
+                    &entry,
+                    format!(
+                        "duplicate elastic_cluster_name '{}'",
+                        cluster.elastic_cluster_name()
+                    ),
+                );
+            }
+
+            if cluster.elastic_host().is_empty() {
+                self.error(file, &entry, String::from("elastic_host is empty"));
+            }
+
+            if !ELASTIC_SCHEMES.contains(&cluster.elastic_scheme().as_str()) {
+                self.error(
+                    file,
+                    &entry,
+                    format!(
+                        "elastic_scheme '{}' must be one of {:?}",
+                        cluster.elastic_scheme(),
+                        ELASTIC_SCHEMES
+                    ),
+                );
+            }
+
+            if *cluster.elastic_pool_cnt() <= 0 {
+                self.error(
+                    file,
+                    &entry,
+                    format!(
+                        "elastic_pool_cnt must be greater than 0 (got {})",
+                        cluster.elastic_pool_cnt()
+                    ),
+                );
+            }
+
+            if *cluster.elastic_sniff_interval_sec() == Some(0) {
+                self.error(
+                    file,
+                    &entry,
+                    String::from("elastic_sniff_interval_sec must be greater than 0"),
+                );
+            }
+
+            if *cluster.elastic_retry().max_attempts() == 0 {
+                self.error(
+                    file,
+                    &entry,
+                    String::from("elastic_retry.max_attempts must be greater than 0"),
+                );
+            }
+        }
+
+        /* [system] */
+        if config.system.schedule_term == 0 {
+            self.error(
+                file,
+                "[system]",
+                String::from("schedule_term must be greater than 0"),
+            );
+        }
+
+        if config.system.message_chunk_size == 0 {
+            self.error(
+                file,
+                "[system]",
+                String::from("message_chunk_size must be greater than 0"),
+            );
+        }
+
+        /* [telegram] */
+        if config.telegram.bot_token.is_empty() {
+            self.error(file, "[telegram]", String::from("bot_token is empty"));
+        }
+
+        if config.telegram.chat_room_id.trim().is_empty() {
+            self.error(file, "[telegram]", String::from("chat_room_id is empty"));
+        }
+
+        /* [email] / [smtp] */
+        match config.email.backend.as_str() {
+            "imailer" => self.check_procedure(file, "[email.imailer]", &config.email.imailer),
+            "smtp" => {
+                if config.smtp.is_none() {
+                    self.error(
+                        file,
+                        "[email]",
+                        String::from("backend = \"smtp\" requires an [smtp] section"),
+                    );
+                }
+            }
+            backend => self.error(
+                file,
+                "[email]",
+                format!("backend '{}' must be one of {:?}", backend, EMAIL_BACKENDS),
+            ),
+        }
+
+        if let Some(smtp) = &config.smtp {
+            if !SMTP_SECURITIES.contains(&smtp.smtp_security.as_str()) {
+                self.error(
+                    file,
+                    "[smtp]",
+                    format!(
+                        "smtp_security '{}' must be one of {:?}",
+                        smtp.smtp_security, SMTP_SECURITIES
+                    ),
+                );
+            }
+
+            let sender_email: &str = smtp
+                .sender_email
+                .as_deref()
+                .unwrap_or(smtp.credential_id.as_str());
+
+            if config.email.backend == "smtp" && sender_email.parse::<Mailbox>().is_err() {
+                self.error(
+                    file,
+                    "[smtp]",
+                    format!(
+                        "sender address '{}' is not a valid email address (set sender_email)",
+                        sender_email
+                    ),
+                );
+            }
+        }
+
+        /* [[procedure_notifier]] */
+        for (position, notifier) in config.procedure_notifier.iter().enumerate() {
+            let entry: String = format!("[[procedure_notifier]] #{} '{}'", position, notifier.name);
+
+            if notifier.receivers.is_empty() {
+                self.warning(file, &entry, String::from("receivers is empty"));
+            }
+
+            self.check_procedure(file, &entry, &notifier.procedure);
+        }
+
+        /* 스케쥴(CRON) 설정들 */
+        if config.digest.enabled {
+            self.check_cron(file, "[digest] cron", &config.digest.cron);
+        }
+
+        if let Some(cron) = &config.report.weekly_cron {
+            self.check_cron(file, "[report] weekly_cron", cron);
+        }
+
+        if let Some(cron) = &config.report.monthly_cron {
+            self.check_cron(file, "[report] monthly_cron", cron);
+        }
+
+        if let Some(cron) = &config.index_management.cleanup_cron {
+            self.check_cron(file, "[index_management] cleanup_cron", cron);
+        }
+
+        /* 주기 값이 0 이면 tokio interval 이 panic 한다. */
+        if config.outbox.dispatch_interval_sec == 0 {
+            self.error(
+                file,
+                "[outbox]",
+                String::from("dispatch_interval_sec must be greater than 0"),
+            );
+        }
+
+        if config.history.enabled && config.history.flush_interval_sec == 0 {
+            self.error(
+                file,
+                "[history]",
+                String::from("flush_interval_sec must be greater than 0"),
+            );
+        }
+
+        let leader_election = &config.leader_election;
+
+        if leader_election.enabled
+            && (leader_election.renew_interval_sec == 0
+                || leader_election.renew_interval_sec as i64 >= leader_election.lease_duration_sec)
+        {
+            self.error(
+                file,
+                "[leader_election]",
+                format!(
+                    "renew_interval_sec ({}) must be greater than 0 and shorter than lease_duration_sec ({})",
+                    leader_election.renew_interval_sec, leader_election.lease_duration_sec
+                ),
+            );
+        }
+    }
+
+    #[doc = "프로시저 파라미터 설정을 확인해주는 함수"]
+    fn check_procedure(&mut self, file: &str, entry: &str, procedure: &ProcedureConfig) {
+        if procedure.procedure_name.trim().is_empty() {
+            self.error(file, entry, String::from("procedure_name is empty"));
+        }
+
+        for param in &procedure.params {
+            match (&param.value, param.source.as_deref()) {
+                (None, None) => self.error(
+                    file,
+                    entry,
+                    format!("param '{}' needs either 'value' or 'source'", param.name),
+                ),
+                (_, Some(source)) if !PROCEDURE_PARAM_SOURCES.contains(&source) => self.error(
+                    file,
+                    entry,
+                    format!(
+                        "param '{}' has unknown source '{}' (expected one of {:?})",
+                        param.name, source, PROCEDURE_PARAM_SOURCES
+                    ),
+                ),
+                _ => (),
+            }
+        }
+    }
+
+    #[doc = "index_list.toml 의 인덱스 스케쥴들을 확인해주는 함수"]
+    /// # Arguments
+    /// * `file` - index_list.toml 경로
+    /// * `index_schedules` - 인덱스 스케쥴 목록
+    /// * `cluster_names` - 설정된 클러스터 이름들 (system_config 를 읽지 못했으면 None -> 확인 생략)
+    fn check_index_schedules(
+        &mut self,
+        file: &str,
+        index_schedules: &IndexSchedulesConfig,
+        cluster_names: Option<&[&str]>,
+    ) {
+        if index_schedules.index.is_empty() {
+            self.warning(
+                file,
+                "[[index]]",
+                String::from("no index schedules configured"),
+            );
+        }
+
+        let mut seen_index_names: HashMap<&str, usize> = HashMap::new();
+
+        for (position, index_schedule) in index_schedules.index.iter().enumerate() {
+            let entry: String = format!("[[index]] #{} '{}'", position, index_schedule.index_name);
+
+            /* 알람 / 이력 / 봇 명령어가 인덱스 이름으로 구분되므로 겹치면 안된다. */
+            if let Some(first_position) =
+                seen_index_names.insert(index_schedule.index_name.as_str(), position)
+            {
+                self.error(
+                    file,
+                    &entry,
+                    format!(
+                        "duplicate index_name (already used by [[index]] #{})",
+                        first_position
+                    ),
+                );
+            }
+
+            if !INDEXING_TYPES.contains(&index_schedule.indexing_type.as_str()) {
+                self.error(
+                    file,
+                    &entry,
+                    format!(
+                        "indexing_type '{}' must be one of {:?}",
+                        index_schedule.indexing_type, INDEXING_TYPES
+                    ),
+                );
+            }
+
+            if index_schedule.duration <= 0 {
+                self.error(
+                    file,
+                    &entry,
+                    format!(
+                        "duration must be greater than 0 seconds (got {})",
+                        index_schedule.duration
+                    ),
+                );
+            }
+
+            if let Some(schedule) = self.check_cron(file, &entry, &index_schedule.time) {
+                /*
+                    duration 은 체크 시점부터 거슬러 올라가서 색인 로그를 찾는 범위.
+                    체크 간격보다 길면 한 번의 색인 로그가 여러 번의 체크를 통과시켜서 실패를 놓칠 수 있다.
+                */
+                let upcoming: Vec<DateTime<Utc>> = schedule
+                    .upcoming(Utc)
+                    .take(CRON_SPACING_SAMPLE_CNT)
+                    .collect();
+                let min_spacing_sec: Option<i64> = upcoming
+                    .windows(2)
+                    .map(|pair| (pair[1] - pair[0]).num_seconds())
+                    .min();
+
+                if let Some(min_spacing_sec) = min_spacing_sec {
+                    if index_schedule.duration > min_spacing_sec {
+                        self.warning(
+                            file,
+                            &entry,
+                            format!(
+                                "duration ({}s) is longer than the shortest interval between checks ({}s) of '{}'; one indexing log can satisfy several checks",
+                                index_schedule.duration, min_spacing_sec, index_schedule.time
+                            ),
+                        );
+                    }
+                }
+            }
+
+            if let Some(cluster_names) = cluster_names {
+                for cluster_name in index_schedule.referenced_clusters() {
+                    if !cluster_names.contains(&cluster_name) {
+                        self.error(
+                            file,
+                            &entry,
+                            format!(
+                                "unknown cluster '{}' (expected one of {:?})",
+                                cluster_name, cluster_names
+                            ),
+                        );
+                    }
+                }
+            }
+
+            if let Some(consistency) = &index_schedule.consistency {
+                if let Some(ratio) = consistency.max_count_diff_ratio {
+                    if !(0.0..=1.0).contains(&ratio) {
+                        self.error(
+                            file,
+                            &entry,
+                            format!(
+                                "consistency.max_count_diff_ratio must be between 0 and 1 (got {})",
+                                ratio
+                            ),
+                        );
+                    }
+                }
+
+                if consistency.fingerprint_field.is_some() && consistency.fingerprint_size == 0 {
+                    self.error(
+                        file,
+                        &entry,
+                        String::from("consistency.fingerprint_size must be greater than 0"),
+                    );
+                }
+
+                let primary_cluster: Option<&str> = consistency
+                    .primary_cluster
+                    .as_deref()
+                    .or(index_schedule.target_cluster.as_deref())
+                    .or(cluster_names.and_then(|names| names.first().copied()));
+
+                if primary_cluster == Some(consistency.replica_cluster.as_str()) {
+                    self.error(
+                        file,
+                        &entry,
+                        format!(
+                            "consistency.replica_cluster '{}' is the same as the primary cluster",
+                            consistency.replica_cluster
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    #[doc = "email_receiver_info.toml 의 수신자 주소들을 확인해주는 함수"]
+    fn check_receivers(&mut self, file: &str, receivers: &ReceiverEmailConfig) {
+        if receivers.emails.is_empty() {
+            self.warning(
+                file,
+                "[[emails]]",
+                String::from("no email receivers configured"),
+            );
+        }
+
+        for (position, receiver) in receivers.emails.iter().enumerate() {
+            if receiver.email_id.parse::<Mailbox>().is_err() {
+                self.error(
+                    file,
+                    &format!("[[emails]] #{}", position),
+                    format!("'{}' is not a valid email address", receiver.email_id),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index_schedules(entries: &str) -> IndexSchedulesConfig {
+        toml::from_str(entries).unwrap()
+    }
+
+    #[test]
+    fn check_cron_accepts_valid_expression() {
+        let mut report: ConfigValidationReport = ConfigValidationReport::default();
+
+        assert!(report
+            .check_cron("index_list.toml", "[[index]] #0", "0 0 * * * * *")
+            .is_some());
+        assert!(report.issues().is_empty());
+    }
+
+    #[test]
+    fn check_cron_rejects_invalid_expression() {
+        let mut report: ConfigValidationReport = ConfigValidationReport::default();
+
+        assert!(report
+            .check_cron("index_list.toml", "[[index]] #0", "every hour")
+            .is_none());
+        assert_eq!(report.error_cnt(), 1);
+        assert!(report.issues()[0]
+            .message()
+            .starts_with("invalid CRON expression 'every hour'"));
+    }
+
+    #[test]
+    fn check_cron_rejects_expression_that_never_fires() {
+        let mut report: ConfigValidationReport = ConfigValidationReport::default();
+
+        assert!(report
+            .check_cron("index_list.toml", "[[index]] #0", "0 0 0 1 1 * 2000")
+            .is_none());
+        assert_eq!(report.error_cnt(), 1);
+        assert!(report.issues()[0].message().contains("never fires again"));
+    }
+
+    #[test]
+    fn duration_longer_than_cron_spacing_is_warning() {
+        let mut report: ConfigValidationReport = ConfigValidationReport::default();
+        let index_schedules: IndexSchedulesConfig = index_schedules(
+            r#"
+            [[index]]
+            index_name = "product_a"
+            time = "0 0 * * * * *"
+            duration = 7200
+            size = 100
+            indexing_type = "static index"
+            "#,
+        );
+
+        report.check_index_schedules("index_list.toml", &index_schedules, None);
+
+        assert_eq!(report.error_cnt(), 0);
+        assert_eq!(report.warning_cnt(), 1);
+        assert!(report.issues()[0].message().contains(
+            "duration (7200s) is longer than the shortest interval between checks (3600s)"
+        ));
+    }
+
+    #[test]
+    fn duration_within_cron_spacing_is_ok() {
+        let mut report: ConfigValidationReport = ConfigValidationReport::default();
+        let index_schedules: IndexSchedulesConfig = index_schedules(
+            r#"
+            [[index]]
+            index_name = "product_a"
+            time = "0 0 * * * * *"
+            duration = 3600
+            size = 100
+            indexing_type = "static index"
+            "#,
+        );
+
+        report.check_index_schedules("index_list.toml", &index_schedules, None);
+
+        assert!(report.issues().is_empty());
+    }
+
+    #[test]
+    fn check_index_schedules_reports_each_problem() {
+        let mut report: ConfigValidationReport = ConfigValidationReport::default();
+        let index_schedules: IndexSchedulesConfig = index_schedules(
+            r#"
+            [[index]]
+            index_name = "product_a"
+            time = "0 0 * * * * *"
+            duration = 600
+            size = 100
+            indexing_type = "static index"
+
+            [[index]]
+            index_name = "product_a"
+            time = "0 30 * * * * *"
+            duration = 0
+            size = 100
+            indexing_type = "weekly index"
+            log_cluster = "dr"
+            "#,
+        );
+
+        report.check_index_schedules("index_list.toml", &index_schedules, Some(&["main"]));
+
+        let messages: Vec<&str> = report
+            .issues()
+            .iter()
+            .map(|issue| issue.message().as_str())
+            .collect();
+
+        assert_eq!(report.error_cnt(), 4, "{:?}", messages);
+        assert!(messages[0].starts_with("duplicate index_name (already used by [[index]] #0)"));
+        assert!(messages[1].starts_with("indexing_type 'weekly index'"));
+        assert!(messages[2].starts_with("duration must be greater than 0"));
+        assert!(messages[3].starts_with("unknown cluster 'dr'"));
+        assert_eq!(
+            report.issues()[0].describe(),
+            format!(
+                "[ERROR] index_list.toml - [[index]] #1 'product_a': {}",
+                messages[0]
+            )
+        );
+    }
+}
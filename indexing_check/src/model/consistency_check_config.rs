@@ -0,0 +1,47 @@
+use crate::common::*;
+
+/* 같은 인덱스를 주(primary) 클러스터와 DR 클러스터에 같이 색인하는 경우, 두 클러스터의 문서 수를 비교하는 설정 */
+#[derive(Debug, Deserialize, Serialize, Getters, Clone)]
+#[getset(get = "pub")]
+pub struct ConsistencyCheckConfig {
+    /* 비교할 DR 클러스터 이름 */
+    pub replica_cluster: String,
+    /* 기준 클러스터 이름 - 없으면 스케쥴의 target_cluster */
+    #[serde(default)]
+    pub primary_cluster: Option<String>,
+    /* 허용하는 문서 수 차이 (개수) */
+    #[serde(default)]
+    pub max_count_diff: u64,
+    /* 허용하는 문서 수 차이 (기준 클러스터 문서 수 대비 비율, ex. 0.001) - 개수와 비율 중 큰 값을 허용 */
+    #[serde(default)]
+    pub max_count_diff_ratio: Option<f64>,
+    /* 지정하면 이 필드의 terms aggregation 결과(값별 문서 수)도 비교 */
+    #[serde(default)]
+    pub fingerprint_field: Option<String>,
+    #[serde(default = "default_fingerprint_size")]
+    pub fingerprint_size: usize,
+    /* 정해진 체크가 끝난 뒤 비교 전에 기다릴 시간(초) - DR 클러스터 반영 지연 */
+    #[serde(default)]
+    pub delay_sec: u64,
+}
+
+fn default_fingerprint_size() -> usize {
+    100
+}
+
+impl ConsistencyCheckConfig {
+    #[doc = "기준 문서 수에 대해 허용하는 차이를 계산해주는 함수"]
+    /// # Arguments
+    /// * `primary_cnt` - 기준 클러스터의 문서 수
+    ///
+    /// # Returns
+    /// * u64
+    pub fn tolerance(&self, primary_cnt: u64) -> u64 {
+        let ratio_tolerance: u64 = self
+            .max_count_diff_ratio
+            .map(|ratio| (primary_cnt as f64 * ratio).floor() as u64)
+            .unwrap_or(0);
+
+        self.max_count_diff.max(ratio_tolerance)
+    }
+}
@@ -0,0 +1,130 @@
+use crate::common::*;
+
+/* 알람 사유에 보여줄 값이 다른 term 개수 */
+const DIVERGED_TERM_PREVIEW_CNT: usize = 5;
+
+#[doc = "terms aggregation 결과에서 값(term)별 문서 수가 다른 항목"]
+#[derive(Debug, Getters, Clone, new)]
+#[getset(get = "pub")]
+pub struct DivergedTerm {
+    pub term: String,
+    pub primary_cnt: u64,
+    pub replica_cnt: u64,
+}
+
+#[doc = "주(primary) / DR 클러스터 사이의 같은 인덱스 비교 결과"]
+#[derive(Debug, Getters, new)]
+#[getset(get = "pub")]
+pub struct ConsistencyCheckResult {
+    pub primary_cluster: String,
+    pub replica_cluster: String,
+    pub primary_cnt: u64,
+    pub replica_cnt: u64,
+    pub tolerance: u64,
+    /* fingerprint_field 를 지정한 경우에만 채워진다. */
+    #[new(default)]
+    pub primary_fingerprint: Option<String>,
+    #[new(default)]
+    pub replica_fingerprint: Option<String>,
+    #[new(default)]
+    pub diverged_terms: Vec<DivergedTerm>,
+}
+
+impl ConsistencyCheckResult {
+    pub fn count_diff(&self) -> u64 {
+        self.primary_cnt.abs_diff(self.replica_cnt)
+    }
+
+    #[doc = "문서 수 차이와 term 별 문서 수 차이가 모두 허용 범위 안이면 true"]
+    pub fn is_consistent(&self) -> bool {
+        self.count_diff() <= self.tolerance && self.diverged_terms.is_empty()
+    }
+
+    #[doc = "두 클러스터의 terms aggregation 결과를 비교해서 fingerprint 와 차이나는 term 을 채워주는 함수"]
+    /// # Arguments
+    /// * `primary_terms` - 기준 클러스터의 값별 문서 수
+    /// * `replica_terms` - DR 클러스터의 값별 문서 수
+    /// * `tolerance` - term 하나에 대해 허용하는 문서 수 차이를 계산하는 함수
+    ///
+    /// # Returns
+    /// * &mut Self
+    pub fn compare_terms<F>(
+        &mut self,
+        primary_terms: &BTreeMap<String, u64>,
+        replica_terms: &BTreeMap<String, u64>,
+        tolerance: F,
+    ) -> &mut Self
+    where
+        F: Fn(u64) -> u64,
+    {
+        let terms: BTreeSet<&String> = primary_terms.keys().chain(replica_terms.keys()).collect();
+
+        self.diverged_terms = terms
+            .into_iter()
+            .filter_map(|term| {
+                let primary_cnt: u64 = primary_terms.get(term).copied().unwrap_or(0);
+                let replica_cnt: u64 = replica_terms.get(term).copied().unwrap_or(0);
+
+                (primary_cnt.abs_diff(replica_cnt) > tolerance(primary_cnt))
+                    .then(|| DivergedTerm::new(term.to_string(), primary_cnt, replica_cnt))
+            })
+            .collect();
+        self.primary_fingerprint = Some(term_counts_fingerprint(primary_terms));
+        self.replica_fingerprint = Some(term_counts_fingerprint(replica_terms));
+
+        self
+    }
+
+    #[doc = "알람 사유 문자열을 만들어주는 함수"]
+    pub fn reason(&self) -> String {
+        let mut reason: String = format!(
+            "Document count differs between {} ({}) and {} ({}) by {}, tolerance {}.",
+            self.primary_cluster,
+            self.primary_cnt.to_formatted_string(&Locale::en),
+            self.replica_cluster,
+            self.replica_cnt.to_formatted_string(&Locale::en),
+            self.count_diff().to_formatted_string(&Locale::en),
+            self.tolerance.to_formatted_string(&Locale::en)
+        );
+
+        if !self.diverged_terms.is_empty() {
+            let preview: Vec<String> = self
+                .diverged_terms
+                .iter()
+                .take(DIVERGED_TERM_PREVIEW_CNT)
+                .map(|term| format!("{}={}/{}", term.term, term.primary_cnt, term.replica_cnt))
+                .collect();
+
+            reason.push_str(&format!(
+                " {} term(s) differ (fingerprint {} / {}): {}",
+                self.diverged_terms.len(),
+                short_fingerprint(self.primary_fingerprint.as_deref()),
+                short_fingerprint(self.replica_fingerprint.as_deref()),
+                preview.join(", ")
+            ));
+        }
+
+        reason
+    }
+}
+
+#[doc = "값별 문서 수를 정렬된 순서로 이어붙인 SHA-256 hex - 같으면 두 클러스터의 분포가 같다."]
+pub fn term_counts_fingerprint(term_counts: &BTreeMap<String, u64>) -> String {
+    let mut hasher: Sha256 = Sha256::new();
+
+    for (term, cnt) in term_counts {
+        hasher.update(term.as_bytes());
+        hasher.update([0x1f]);
+        hasher.update(cnt.to_be_bytes());
+    }
+
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn short_fingerprint(fingerprint: Option<&str>) -> &str {
+    fingerprint.map_or("-", |fingerprint| &fingerprint[..fingerprint.len().min(12)])
+}
@@ -0,0 +1,24 @@
+use crate::common::*;
+
+#[derive(Serialize, Deserialize, Debug, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct DigestConfig {
+    /* 일일 상태 요약(digest) 발송 여부 */
+    pub enabled: bool,
+    /* 발송 시각 - CRON 표현식 (한국시간) */
+    pub cron: String,
+    pub send_telegram: bool,
+    pub send_email: bool,
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            cron: String::from("0 0 9 * * * *"),
+            send_telegram: true,
+            send_email: true,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::common::*;
+
+use crate::model::{index_check_status::*, index_schedules_config::*};
+
+#[derive(Serialize, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct DigestTemplateItem {
+    pub index_name: String,
+    pub index_type: String,
+    pub cron: String,
+    pub outcome: String,
+    pub severity: String,
+    pub checked_at: String,
+    pub indexing_cnt: String,
+    pub declare_size: String,
+    pub open_alarm_cnt: usize,
+    pub snoozed_until: String,
+}
+
+impl DigestTemplateItem {
+    #[doc = "인덱스 스케쥴과 마지막 체크 결과를 digest 템플릿 렌더링용 객체로 변환해주는 함수"]
+    /// # Arguments
+    /// * `index_schedule` - 인덱스 스케쥴 객체
+    /// * `check_status` - 마지막 색인 체크 결과 (아직 체크 전이면 None)
+    /// * `open_alarm_cnt` - 확인(ack)되지 않은 알람 개수
+    /// * `snoozed_until` - 알람 일시중지 만료 시각
+    pub fn new(
+        index_schedule: &IndexSchedules,
+        check_status: Option<&IndexCheckStatus>,
+        open_alarm_cnt: usize,
+        snoozed_until: Option<NaiveDateTime>,
+    ) -> Self {
+        let (outcome, checked_at, indexing_cnt) = match check_status {
+            Some(status) => (
+                status.outcome().to_string(),
+                status.checked_at().to_string(),
+                status.indexing_cnt_num().to_formatted_string(&Locale::en),
+            ),
+            None => (
+                String::from("Not Checked"),
+                String::from("-"),
+                String::from("-"),
+            ),
+        };
+
+        let severity: &str = match outcome.as_str() {
+            "Success" => "ok",
+            "Partial Error" => "warning",
+            "Full Error" => "critical",
+            _ => "unknown",
+        };
+
+        Self {
+            index_name: index_schedule.index_name().to_string(),
+            index_type: index_schedule.indexing_type().to_string(),
+            cron: index_schedule.time().to_string(),
+            severity: severity.to_string(),
+            outcome,
+            checked_at,
+            indexing_cnt,
+            declare_size: index_schedule.size().to_formatted_string(&Locale::en),
+            open_alarm_cnt,
+            snoozed_until: snoozed_until
+                .map(|until| until.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct DigestEmailContext {
+    pub cluster_name: String,
+    pub generated_at: String,
+    pub index_cnt: usize,
+    pub success_cnt: usize,
+    pub failure_cnt: usize,
+    pub unknown_cnt: usize,
+    pub open_alarm_cnt: usize,
+    pub items: Vec<DigestTemplateItem>,
+}
+
+impl DigestEmailContext {
+    pub fn new(cluster_name: &str, generated_at: &str, items: Vec<DigestTemplateItem>) -> Self {
+        let success_cnt: usize = items.iter().filter(|item| item.severity == "ok").count();
+        let failure_cnt: usize = items
+            .iter()
+            .filter(|item| item.severity == "warning" || item.severity == "critical")
+            .count();
+
+        Self {
+            cluster_name: cluster_name.to_string(),
+            generated_at: generated_at.to_string(),
+            index_cnt: items.len(),
+            success_cnt,
+            failure_cnt,
+            unknown_cnt: items.len() - success_cnt - failure_cnt,
+            open_alarm_cnt: items.iter().map(|item| item.open_alarm_cnt).sum(),
+            items,
+        }
+    }
+}
@@ -1,11 +1,312 @@
 use crate::common::*;
 
+use crate::model::secret_value::*;
+
 #[derive(Debug, Deserialize, Serialize, Getters)]
 #[getset(get = "pub")]
 pub struct ElasticServerConfig {
+    /* "host:port" 또는 "https://host:port" - scheme 이 없으면 elastic_scheme 사용 */
     pub elastic_host: Vec<String>,
+    /* "http" | "https" */
+    #[serde(default = "default_elastic_scheme")]
+    pub elastic_scheme: String,
     pub elastic_id: Option<String>,
-    pub elastic_pw: Option<String>,
+    /* secret 항목 - "${ENV_VAR}" 로 환경변수 참조, 또는 <항목>_file 로 파일에서 읽기 */
+    #[serde(default)]
+    pub elastic_pw: SecretValue,
+    #[serde(default)]
+    pub elastic_pw_file: Option<String>,
+    /* API key 인증 - Kibana 에서 발급한 base64 인코딩 값 (id:api_key) */
+    #[serde(default)]
+    pub elastic_api_key: SecretValue,
+    #[serde(default)]
+    pub elastic_api_key_file: Option<String>,
+    /* Bearer 토큰 인증 (service account token 등) */
+    #[serde(default)]
+    pub elastic_bearer_token: SecretValue,
+    #[serde(default)]
+    pub elastic_bearer_token_file: Option<String>,
+    /* 사설 CA 인증서(PEM) 경로 */
+    #[serde(default)]
+    pub elastic_ca_cert_path: Option<String>,
+    /* 사설 CA 사용 시 hostname 검증 여부 */
+    #[serde(default = "default_verify_hostname")]
+    pub elastic_verify_hostname: bool,
+    /* 서버 인증서 SHA-256 지문 (ex. "AB:CD:..."), 지정하면 CA 검증 대신 지문으로 확인 */
+    #[serde(default)]
+    pub elastic_cert_fingerprint: Option<String>,
+    /* 클라이언트 인증서(PKCS#12) 경로 및 비밀번호 */
+    #[serde(default)]
+    pub elastic_client_cert_path: Option<String>,
+    #[serde(default)]
+    pub elastic_client_cert_pw: SecretValue,
+    #[serde(default)]
+    pub elastic_client_cert_pw_file: Option<String>,
     pub elastic_pool_cnt: i32,
     pub elastic_cluster_name: String,
+    /* "elasticsearch" | "opensearch" - API 가 다른 부분(PIT, lifecycle policy)만 엔진에 맞게 요청 */
+    #[serde(default)]
+    pub elastic_engine: SearchEngine,
+    /* 노드별 연속 실패 횟수가 이 값에 도달하면 해당 노드로 요청을 보내지 않는다. (circuit open) */
+    #[serde(default = "default_breaker_failure_threshold")]
+    pub elastic_breaker_failure_threshold: u32,
+    /* 차단된 노드에 다시 요청을 보내보기까지 대기 시간(초) */
+    #[serde(default = "default_breaker_open_sec")]
+    pub elastic_breaker_open_sec: u64,
+    /* 클러스터에서 노드 목록을 다시 가져오는 주기(초) - 없으면 elastic_host 만 사용 */
+    #[serde(default)]
+    pub elastic_sniff_interval_sec: Option<u64>,
+    /* 429 / 502 / 503 / 504 응답 및 연결 실패 시 재시도 정책 */
+    #[serde(default)]
+    pub elastic_retry: EsRetryConfig,
+    /* 요청 종류별 timeout */
+    #[serde(default)]
+    pub elastic_timeout: EsTimeoutConfig,
+}
+
+#[doc = "클러스터 검색엔진 종류"]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchEngine {
+    #[default]
+    Elasticsearch,
+    Opensearch,
+}
+
+impl SearchEngine {
+    #[doc = "PIT + search_after 조회에서 같은 정렬값의 문서 순서를 정해주는 정렬 조건"]
+    /// OpenSearch 는 _shard_doc 을 지원하지 않는 버전이 있어서 _id 를 사용한다.
+    pub fn pit_tiebreaker_sort(&self) -> Value {
+        match self {
+            Self::Elasticsearch => json!({ "_shard_doc": "asc" }),
+            Self::Opensearch => json!({ "_id": "asc" }),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct EsRetryConfig {
+    /* 요청 한 건당 최대 시도 횟수 (첫 시도 포함) - 시도할 때마다 다음 노드로 넘어간다. */
+    pub max_attempts: u32,
+    /* 재시도 대기 시간(ms) - 실패할 때마다 2배씩 증가, 절반은 랜덤(jitter) */
+    pub base_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+}
+
+impl Default for EsRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_backoff_ms: 200,
+            max_backoff_ms: 5000,
+        }
+    }
+}
+
+impl EsRetryConfig {
+    #[doc = "retry 번째 재시도 전에 기다릴 시간 - 지수 backoff 의 절반 + 랜덤 jitter"]
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exp: u32 = retry.saturating_sub(1).min(20);
+        let backoff_ms: u64 = self
+            .base_backoff_ms
+            .saturating_mul(1_u64 << exp)
+            .min(self.max_backoff_ms);
+        let jitter_ms: u64 = rand::thread_rng().gen_range(0..=backoff_ms / 2);
+
+        Duration::from_millis(backoff_ms - backoff_ms / 2 + jitter_ms)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct EsTimeoutConfig {
+    /* 검색 (색인 로그, 알람, 이력 조회) */
+    pub search_sec: u64,
+    /* 문서 색인 / 수정 / 삭제 */
+    pub write_sec: u64,
+    /* update by query (알람 ack) */
+    pub update_by_query_sec: u64,
+    /* 인덱스 생성, mapping, 노드 목록 조회 */
+    pub admin_sec: u64,
+}
+
+impl Default for EsTimeoutConfig {
+    fn default() -> Self {
+        Self {
+            search_sec: 10,
+            write_sec: 5,
+            update_by_query_sec: 30,
+            admin_sec: 30,
+        }
+    }
+}
+
+impl EsTimeoutConfig {
+    pub fn search(&self) -> Duration {
+        Duration::from_secs(self.search_sec)
+    }
+
+    pub fn write(&self) -> Duration {
+        Duration::from_secs(self.write_sec)
+    }
+
+    pub fn update_by_query(&self) -> Duration {
+        Duration::from_secs(self.update_by_query_sec)
+    }
+
+    pub fn admin(&self) -> Duration {
+        Duration::from_secs(self.admin_sec)
+    }
+}
+
+fn default_elastic_scheme() -> String {
+    String::from("http")
+}
+
+fn default_verify_hostname() -> bool {
+    true
+}
+
+fn default_breaker_failure_threshold() -> u32 {
+    3
+}
+
+fn default_breaker_open_sec() -> u64 {
+    30
+}
+
+impl ElasticServerConfig {
+    #[doc = "elastic_host 하나를 scheme 을 포함한 URL 로 만들어주는 함수"]
+    pub fn build_node_url(&self, host: &str) -> Result<Url, anyhow::Error> {
+        let scheme: &str = self.elastic_scheme.as_str();
+
+        if scheme != "http" && scheme != "https" {
+            return Err(anyhow!(
+                "[ElasticServerConfig->build_node_url] Unsupported elastic_scheme '{}' (use http/https)",
+                scheme
+            ));
+        }
+
+        let node_url: String = if host.contains("://") {
+            host.to_string()
+        } else {
+            format!("{}://{}", scheme, host)
+        };
+
+        let url: Url = Url::parse(&node_url)?;
+
+        /* 계정 정보는 URL 이 아니라 인증 헤더로 전달한다. */
+        if !url.username().is_empty() || url.password().is_some() {
+            return Err(anyhow!(
+                "[ElasticServerConfig->build_node_url] Credentials must not be embedded in elastic_host: {}",
+                url.host_str().unwrap_or(host)
+            ));
+        }
+
+        Ok(url)
+    }
+
+    #[doc = "secret 항목의 <항목>_file 을 읽어서 값을 채워주는 함수"]
+    pub fn resolve_secret_files(&mut self) -> Result<(), anyhow::Error> {
+        self.elastic_pw
+            .resolve_file("elastic_pw", self.elastic_pw_file.as_deref())?;
+        self.elastic_api_key
+            .resolve_file("elastic_api_key", self.elastic_api_key_file.as_deref())?;
+        self.elastic_bearer_token.resolve_file(
+            "elastic_bearer_token",
+            self.elastic_bearer_token_file.as_deref(),
+        )?;
+        self.elastic_client_cert_pw.resolve_file(
+            "elastic_client_cert_pw",
+            self.elastic_client_cert_pw_file.as_deref(),
+        )?;
+
+        Ok(())
+    }
+
+    #[doc = "설정된 인증 방식으로 Authorization 헤더 값을 만들어주는 함수 (API key > Bearer > Basic 순)"]
+    pub fn build_auth_header(&self) -> Result<Option<HeaderValue>, anyhow::Error> {
+        let auth_value: Option<String> = match (
+            self.elastic_api_key.non_empty(),
+            self.elastic_bearer_token.non_empty(),
+            &self.elastic_id,
+        ) {
+            (Some(api_key), _, _) => Some(format!("ApiKey {}", api_key)),
+            (None, Some(token), _) => Some(format!("Bearer {}", token)),
+            (None, None, Some(id)) if !id.is_empty() => {
+                let pw: &str = self.elastic_pw.expose();
+                Some(format!(
+                    "Basic {}",
+                    BASE64_STANDARD.encode(format!("{}:{}", id, pw))
+                ))
+            }
+            _ => None,
+        };
+
+        auth_value
+            .map(|value| {
+                let mut header_value: HeaderValue = HeaderValue::from_str(&value).map_err(|e| {
+                    anyhow!(
+                        "[ElasticServerConfig->build_auth_header] Invalid credential: {}",
+                        e
+                    )
+                })?;
+                /* 로그 / Debug 출력에서 값이 노출되지 않도록 표시 */
+                header_value.set_sensitive(true);
+                Ok(header_value)
+            })
+            .transpose()
+    }
+
+    #[doc = "클라이언트 인증서(PKCS#12) 를 읽어주는 함수"]
+    pub fn load_client_certificate(&self) -> Result<Option<ClientCertificate>, anyhow::Error> {
+        self.elastic_client_cert_path
+            .as_ref()
+            .map(|path| {
+                let cert_bytes: Vec<u8> = fs::read(path).map_err(|e| {
+                    anyhow!(
+                        "[ElasticServerConfig->load_client_certificate] Failed to read '{}': {}",
+                        path,
+                        e
+                    )
+                })?;
+
+                Ok(ClientCertificate::Pkcs12(
+                    cert_bytes,
+                    self.elastic_client_cert_pw.non_empty().map(str::to_string),
+                ))
+            })
+            .transpose()
+    }
+
+    #[doc = "사설 CA 설정으로 인증서 검증 방식을 만들어주는 함수 - CA 가 없으면 None (시스템 기본 CA 사용)"]
+    pub fn load_ca_validation(&self) -> Result<Option<CertificateValidation>, anyhow::Error> {
+        let Some(path) = &self.elastic_ca_cert_path else {
+            return Ok(None);
+        };
+
+        if self.elastic_cert_fingerprint.is_some() {
+            return Err(anyhow!(
+                "[ElasticServerConfig->load_ca_validation] Use either elastic_ca_cert_path or elastic_cert_fingerprint, not both"
+            ));
+        }
+
+        let ca_bytes: Vec<u8> = fs::read(path).map_err(|e| {
+            anyhow!(
+                "[ElasticServerConfig->load_ca_validation] Failed to read '{}': {}",
+                path,
+                e
+            )
+        })?;
+        let ca_cert: Certificate = Certificate::from_pem(&ca_bytes)?;
+
+        if self.elastic_verify_hostname {
+            Ok(Some(CertificateValidation::Full(ca_cert)))
+        } else {
+            Ok(Some(CertificateValidation::Certificate(ca_cert)))
+        }
+    }
 }
@@ -0,0 +1,79 @@
+use crate::common::*;
+
+use crate::model::procedure_config::*;
+
+#[derive(Serialize, Deserialize, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct EmailConfig {
+    /* "imailer" (SQL Server 프로시저) | "smtp" */
+    #[serde(default = "default_email_backend")]
+    pub backend: String,
+    /* backend = "imailer" 인 경우 호출할 프로시저 정보 - 미지정 시 기존 iMailer 프로시저 / 발신자 */
+    #[serde(default = "default_imailer_procedure")]
+    pub imailer: ProcedureConfig,
+}
+
+impl Default for EmailConfig {
+    fn default() -> Self {
+        Self {
+            backend: default_email_backend(),
+            imailer: default_imailer_procedure(),
+        }
+    }
+}
+
+fn default_email_backend() -> String {
+    String::from("imailer")
+}
+
+#[doc = "[email.imailer] 를 지정하지 않은 기존 설정을 위한 iMailer 프로시저 기본값 (이전 버전에서 코드에 고정되어 있던 값)"]
+fn default_imailer_procedure() -> ProcedureConfig {
+    let param = |name: &str, value: Option<&str>, source: Option<&str>| ProcedureParam {
+        name: name.to_string(),
+        value: value.map(str::to_string),
+        source: source.map(str::to_string),
+    };
+
+    ProcedureConfig {
+        procedure_name: String::from("NEWSLETTER.dbo.IM_DMAIL_INFO_INS_TEMPLATE_PROC"),
+        params: vec![
+            param("GUBUN", Some("ALBA"), None),
+            param("SENDNAME", Some("알바천국"), None),
+            param("SENDEMAIL", Some("alba@alba.co.kr"), None),
+            param("RECVNAME", Some(""), None),
+            param("RECVEMAIL", None, Some("recipient")),
+            param("SUBJECT", None, Some("subject")),
+            param("CONTENT", None, Some("html_content")),
+            param("QRY", Some(""), None),
+        ],
+        success_return_codes: None,
+        failure_return_codes: vec![0],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_imailer_section_uses_previous_procedure() {
+        let email_config: EmailConfig = toml::from_str(r#"backend = "imailer""#).unwrap();
+        let imailer: &ProcedureConfig = email_config.imailer();
+
+        assert_eq!(
+            imailer.procedure_name(),
+            "NEWSLETTER.dbo.IM_DMAIL_INFO_INS_TEMPLATE_PROC"
+        );
+        assert_eq!(imailer.params().len(), 8);
+        assert_eq!(imailer.params()[4].source().as_deref(), Some("recipient"));
+        assert!(!imailer.is_success_code(0));
+        assert!(imailer.is_success_code(1));
+    }
+
+    #[test]
+    fn empty_email_section_defaults_to_imailer() {
+        let email_config: EmailConfig = toml::from_str("").unwrap();
+
+        assert_eq!(email_config.backend(), "imailer");
+    }
+}
@@ -1,5 +1,25 @@
 use crate::common::*;
 
+use crate::model::index_template::*;
+
+/*
+    알람 상태값
+    - open: 알람 발송 대상
+    - acknowledged: 담당자가 확인한 알람
+    - dispatched: 한번만 알람을 주는 증분색인 알람이 한 채널 이상으로 발송된 상태
+    - resolved: 이후 체크가 성공해서 끝난 장애(incident)
+    리포트 집계를 위해 알람 문서는 지우지 않고 상태만 바꾼다.
+*/
+pub const ALARM_STATE_OPEN: &str = "open";
+pub const ALARM_STATE_ACKNOWLEDGED: &str = "acknowledged";
+pub const ALARM_STATE_DISPATCHED: &str = "dispatched";
+pub const ALARM_STATE_RESOLVED: &str = "resolved";
+
+/*
+    알람 문서는 장애(incident) 단위 - (클러스터, 인덱스, 에러 종류) 가 같은 진행중인 장애가 있으면
+    그 문서의 last_seen / occurrence_count 만 갱신한다. 해결된 장애는 이력으로 남고, 다시 실패하면
+    새 문서(id: fingerprint + first_seen)로 연다.
+*/
 #[derive(Serialize, Deserialize, Debug, Setters, Getters, new)]
 #[getset(get = "pub", set = "pub")]
 pub struct ErrorAlarmInfo {
@@ -10,33 +30,218 @@ pub struct ErrorAlarmInfo {
     pub index_type: String,
     pub indexing_cnt_num: usize,
     pub declare_index_size: usize,
+    #[serde(default)]
+    #[new(default)]
+    pub reason: String,
+    #[serde(default)]
+    #[new(default)]
+    pub duration: i64,
+    #[serde(default = "default_alarm_state")]
+    #[new(value = "ALARM_STATE_OPEN.to_string()")]
+    pub alarm_state: String,
+    #[serde(default)]
+    #[new(default)]
+    pub cluster_name: String,
+    #[serde(default)]
+    #[new(default)]
+    pub fingerprint: String,
+    /* 장애가 처음 / 마지막으로 발견된 시각 (한국시간) - 이전 버전 문서는 @timestamp 와 같다고 본다. */
+    #[serde(default)]
+    #[new(default)]
+    pub first_seen: String,
+    #[serde(default)]
+    #[new(default)]
+    pub last_seen: String,
+    #[serde(default = "default_occurrence_count")]
+    #[new(value = "1")]
+    pub occurrence_count: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[new(default)]
+    pub resolved_at: Option<String>,
+}
+
+fn default_alarm_state() -> String {
+    ALARM_STATE_OPEN.to_string()
+}
+
+fn default_occurrence_count() -> u64 {
+    1
 }
 
 impl ErrorAlarmInfo {
-    #[doc = "인덱스 에러 정보를 이메일 구조로 변환해주는 함수"]
-    pub fn convert_email_struct(&self) -> Result<String, anyhow::Error> {
-        let color: &str = if self.error_type == "Full Error" {
-            "red"
+    #[doc = "클러스터 이름과 fingerprint 를 채워서 장애(incident) 문서로 만들어주는 함수"]
+    /// # Arguments
+    /// * `cluster_name` - Elasticsearch 클러스터 이름
+    ///
+    /// # Returns
+    /// * &mut Self
+    pub fn set_incident(&mut self, cluster_name: &str) -> &mut Self {
+        self.fingerprint = incident_fingerprint(cluster_name, &self.index_name, &self.error_type);
+        self.cluster_name = cluster_name.to_string();
+        self.first_seen = self.timestamp.clone();
+        self.last_seen = self.timestamp.clone();
+        self.occurrence_count = 1;
+        self
+    }
+
+    #[doc = "장애가 처음 발견된 시각 - 이전 버전 문서는 @timestamp"]
+    pub fn first_seen_or_timestamp(&self) -> &str {
+        if self.first_seen.is_empty() {
+            &self.timestamp
+        } else {
+            &self.first_seen
+        }
+    }
+
+    #[doc = "장애가 마지막으로 발견된 시각 - 이전 버전 문서는 @timestamp"]
+    pub fn last_seen_or_timestamp(&self) -> &str {
+        if self.last_seen.is_empty() {
+            &self.timestamp
         } else {
-            "yellow"
-        };
-
-        let html_form: String = format!(
-            "<tr>
-                <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
-                <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'><span style='color: red;'>{}</span> ({})</td>
-                <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
-                <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
-                <td style='border: 1px solid #ddd; padding: 8px; text-align: left; color: {};'>{}</td>
-            </tr>",
-            self.index_name,
-            self.indexing_cnt_num.to_formatted_string(&Locale::en), self.declare_index_size.to_formatted_string(&Locale::en),
-            self.index_type(),
-            self.timestamp(),
-            color, self.error_type
-        );
-
-        Ok(html_form)
+            &self.last_seen
+        }
+    }
+}
+
+#[doc = "(클러스터, 인덱스, 에러 종류) 로 장애 fingerprint 를 만들어주는 함수 - 진행중인 장애를 찾는 keyword"]
+pub fn incident_fingerprint(cluster_name: &str, index_name: &str, error_type: &str) -> String {
+    let mut hasher: Sha256 = Sha256::new();
+
+    for part in [cluster_name, index_name, error_type] {
+        hasher.update(part.as_bytes());
+        /* 구분자 - ("a", "bc") 와 ("ab", "c") 가 같아지지 않도록 */
+        hasher.update([0x1f]);
+    }
+
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[doc = "새 장애 문서의 id 를 만들어주는 함수 - 같은 fingerprint 의 이전(해결된) 장애 문서를 덮어쓰지 않도록 처음 발견 시각을 붙인다."]
+/// # Arguments
+/// * `fingerprint` - 장애 fingerprint
+/// * `first_seen` - 장애가 처음 발견된 시각 (한국시간 문자열)
+///
+/// # Returns
+/// * String
+pub fn incident_doc_id(fingerprint: &str, first_seen: &str) -> String {
+    let first_seen_digits: String = first_seen.chars().filter(char::is_ascii_digit).collect();
+    format!("{}-{}", fingerprint, first_seen_digits)
+}
+
+#[doc = "err_monitor_index 의 mapping"]
+pub fn error_alarm_info_mapping() -> Value {
+    json!({
+        "properties": {
+            "@timestamp": { "type": "date" },
+            "error_type": text_keyword_mapping(),
+            "index_name": text_keyword_mapping(),
+            "index_type": text_keyword_mapping(),
+            "indexing_cnt_num": { "type": "long" },
+            "declare_index_size": { "type": "long" },
+            "reason": text_keyword_mapping(),
+            "duration": { "type": "long" },
+            "alarm_state": text_keyword_mapping(),
+            "cluster_name": text_keyword_mapping(),
+            "fingerprint": text_keyword_mapping(),
+            "first_seen": { "type": "date" },
+            "last_seen": { "type": "date" },
+            "occurrence_count": { "type": "long" },
+            "resolved_at": { "type": "date" }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn incident_fingerprint_is_stable_sha256_hex() {
+        let fingerprint: String = incident_fingerprint("main", "product_a", "Full Error");
+
+        assert_eq!(fingerprint.len(), 64);
+        assert!(fingerprint.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(
+            fingerprint,
+            incident_fingerprint("main", "product_a", "Full Error")
+        );
+    }
+
+    #[test]
+    fn incident_fingerprint_differs_per_part() {
+        let base: String = incident_fingerprint("main", "product_a", "Full Error");
+
+        assert_ne!(base, incident_fingerprint("dr", "product_a", "Full Error"));
+        assert_ne!(
+            base,
+            incident_fingerprint("main", "product_b", "Full Error")
+        );
+        assert_ne!(
+            base,
+            incident_fingerprint("main", "product_a", "Partial Error")
+        );
+        /* 구분자가 있으므로 경계가 달라지면 다른 값 */
+        assert_ne!(
+            incident_fingerprint("a", "bc", "Full Error"),
+            incident_fingerprint("ab", "c", "Full Error")
+        );
+    }
+
+    #[test]
+    fn incident_doc_id_differs_per_first_seen() {
+        let fingerprint: String = incident_fingerprint("main", "product_a", "Full Error");
+
+        assert_eq!(
+            incident_doc_id(&fingerprint, "2025-01-01T01:00:05Z"),
+            format!("{}-20250101010005", fingerprint)
+        );
+        assert_ne!(
+            incident_doc_id(&fingerprint, "2025-01-01T01:00:05Z"),
+            incident_doc_id(&fingerprint, "2025-01-02T01:00:05Z")
+        );
+    }
+
+    #[test]
+    fn set_incident_initializes_incident_fields() {
+        let mut info: ErrorAlarmInfo = ErrorAlarmInfo::new(
+            String::from("2025-01-01T01:00:05Z"),
+            String::from("Full Error"),
+            String::from("product_a"),
+            String::from("static index"),
+            0,
+            100,
+        );
+        info.set_incident("main");
+
+        assert_eq!(
+            info.fingerprint(),
+            &incident_fingerprint("main", "product_a", "Full Error")
+        );
+        assert_eq!(info.first_seen(), "2025-01-01T01:00:05Z");
+        assert_eq!(info.last_seen(), "2025-01-01T01:00:05Z");
+        assert_eq!(*info.occurrence_count(), 1);
+        assert_eq!(info.alarm_state(), ALARM_STATE_OPEN);
+    }
+
+    #[test]
+    fn legacy_document_falls_back_to_timestamp() {
+        let info: ErrorAlarmInfo = serde_json::from_value(json!({
+            "@timestamp": "2025-01-01T01:00:05Z",
+            "error_type": "Full Error",
+            "index_name": "product_a",
+            "index_type": "static index",
+            "indexing_cnt_num": 0,
+            "declare_index_size": 100
+        }))
+        .unwrap();
 
+        assert_eq!(info.first_seen_or_timestamp(), "2025-01-01T01:00:05Z");
+        assert_eq!(info.last_seen_or_timestamp(), "2025-01-01T01:00:05Z");
+        assert_eq!(*info.occurrence_count(), 1);
+        assert_eq!(info.alarm_state(), ALARM_STATE_OPEN);
     }
 }
@@ -0,0 +1,17 @@
+use crate::common::*;
+
+/* Elasticsearch 노드 하나의 연결 상태 (진단용) */
+#[derive(Serialize, Debug, Getters, Clone, new)]
+#[getset(get = "pub")]
+pub struct EsNodeStatus {
+    /* 노드가 속한 클러스터 (elastic_cluster_name) */
+    #[new(default)]
+    pub cluster_name: String,
+    pub url: String,
+    /* healthy | open | half-open */
+    pub state: String,
+    pub consecutive_failures: u32,
+    pub last_error: Option<String>,
+    /* 차단(open) 상태에서 다시 시도할 수 있을 때까지 남은 시간(초) */
+    pub retry_in_sec: Option<u64>,
+}
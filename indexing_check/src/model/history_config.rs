@@ -0,0 +1,28 @@
+use crate::common::*;
+
+#[derive(Serialize, Deserialize, Debug, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct HistoryConfig {
+    /* 체크 결과 이력 기록 여부 */
+    pub enabled: bool,
+    /* 모든 색인 체크 결과를 저장할 Elasticsearch 인덱스 */
+    pub history_index: String,
+    /* 체크 결과는 모아서 bulk 로 저장 - 저장 주기(초) 와 한번에 저장할 최대 개수 */
+    pub flush_interval_sec: u64,
+    pub flush_batch_size: usize,
+    /* Elasticsearch 장애로 저장하지 못한 이력을 메모리에 보관할 최대 개수 (초과 시 오래된 것부터 버림) */
+    pub max_buffered: usize,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            history_index: String::from("indexing-check-history"),
+            flush_interval_sec: 5,
+            flush_batch_size: 500,
+            max_buffered: 10000,
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use crate::common::*;
+
+#[derive(Serialize, Deserialize, Debug, Getters, Clone, new)]
+#[getset(get = "pub")]
+pub struct IndexCheckStatus {
+    pub index_name: String,
+    pub outcome: String,
+    pub checked_at: String,
+    pub indexing_cnt_num: usize,
+    pub declare_index_size: usize,
+}
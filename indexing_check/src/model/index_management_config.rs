@@ -0,0 +1,117 @@
+use crate::common::*;
+
+use crate::model::{elastic_server_config::*, index_template::*};
+
+#[derive(Serialize, Deserialize, Debug, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct IndexManagementConfig {
+    /* 프로그램 시작 시 index template / ILM policy 설치 여부 (--bootstrap 과 같은 동작) */
+    pub bootstrap_on_startup: bool,
+    /* ILM 을 지원하지 않는 클러스터면 false - 체크 이력 rollover / 보관기간이 적용되지 않는다. */
+    pub ilm_enabled: bool,
+    /* 체크 이력(data stream) rollover 조건 및 보관 기간 - Elasticsearch 시간/크기 단위 */
+    pub history_rollover_max_age: String,
+    pub history_rollover_max_primary_shard_size: String,
+    pub history_retention: String,
+    /* 확인(ack) / 발송된 알람 문서 보관 기간(일) - 열린 알람은 지우지 않는다. */
+    pub alarm_retention_days: i64,
+    /* 재시도를 포기한(expired) 발송 대기 알림 보관 기간(일) */
+    pub outbox_retention_days: i64,
+    /* 보관 기간이 지난 문서 정리 시각 - CRON 표현식 (한국시간), 없으면 정리하지 않음 */
+    pub cleanup_cron: Option<String>,
+}
+
+impl Default for IndexManagementConfig {
+    fn default() -> Self {
+        Self {
+            bootstrap_on_startup: true,
+            ilm_enabled: true,
+            history_rollover_max_age: String::from("7d"),
+            history_rollover_max_primary_shard_size: String::from("10gb"),
+            history_retention: String::from("90d"),
+            alarm_retention_days: 180,
+            outbox_retention_days: 7,
+            cleanup_cron: Some(String::from("0 30 4 * * * *")),
+        }
+    }
+}
+
+impl IndexManagementConfig {
+    #[doc = "검색엔진에 맞는 체크 이력 lifecycle policy - Elasticsearch 는 ILM, OpenSearch 는 ISM"]
+    /// # Arguments
+    /// * `engine` - 모니터링 인덱스가 있는 클러스터의 검색엔진
+    /// * `history_index` - 체크 이력 data stream 이름
+    ///
+    /// # Returns
+    /// * Value
+    pub fn history_lifecycle_policy(&self, engine: SearchEngine, history_index: &str) -> Value {
+        match engine {
+            SearchEngine::Elasticsearch => self.history_ilm_policy(),
+            SearchEngine::Opensearch => self.history_ism_policy(history_index),
+        }
+    }
+
+    #[doc = "체크 이력 data stream 에 적용할 ILM policy (hot: rollover -> delete)"]
+    pub fn history_ilm_policy(&self) -> Value {
+        json!({
+            "policy": {
+                "phases": {
+                    "hot": {
+                        "actions": {
+                            "rollover": {
+                                "max_age": self.history_rollover_max_age,
+                                "max_primary_shard_size": self.history_rollover_max_primary_shard_size
+                            }
+                        }
+                    },
+                    "delete": {
+                        "min_age": self.history_retention,
+                        "actions": { "delete": {} }
+                    }
+                }
+            }
+        })
+    }
+
+    #[doc = "체크 이력 data stream 에 적용할 OpenSearch ISM policy (hot: rollover -> delete)"]
+    /// ISM 은 index template 의 설정이 아니라 policy 의 ism_template 으로 새 backing index 에 연결된다.
+    pub fn history_ism_policy(&self, history_index: &str) -> Value {
+        json!({
+            "policy": {
+                "description": format!("{} rollover / retention (managed by indexing_check)", history_index),
+                "default_state": "hot",
+                "states": [
+                    {
+                        "name": "hot",
+                        "actions": [
+                            {
+                                "rollover": {
+                                    "min_index_age": self.history_rollover_max_age,
+                                    "min_primary_shard_size": self.history_rollover_max_primary_shard_size
+                                }
+                            }
+                        ],
+                        "transitions": [
+                            {
+                                "state_name": "delete",
+                                "conditions": { "min_index_age": self.history_retention }
+                            }
+                        ]
+                    },
+                    {
+                        "name": "delete",
+                        "actions": [ { "delete": {} } ],
+                        "transitions": []
+                    }
+                ],
+                "ism_template": [
+                    {
+                        "index_patterns": [format!(".ds-{}-*", history_index)],
+                        "priority": INDEX_TEMPLATE_PRIORITY
+                    }
+                ]
+            }
+        })
+    }
+}
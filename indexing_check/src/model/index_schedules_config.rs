@@ -1,5 +1,7 @@
 use crate::common::*;
 
+use crate::model::consistency_check_config::*;
+
 #[derive(Debug, Deserialize, Serialize, Getters, Clone)]
 #[getset(get = "pub")]
 pub struct IndexSchedules {
@@ -8,6 +10,41 @@ pub struct IndexSchedules {
     pub duration: i64,
     pub size: usize,
     pub indexing_type: String,
+    /* 색인 로그가 있는 클러스터 이름 - 없으면 기본 클러스터([elasticsearch]) */
+    #[serde(default)]
+    pub log_cluster: Option<String>,
+    /* 색인 대상 인덱스가 있는 클러스터 이름 - 알람 / 체크 이력에 기록, 없으면 기본 클러스터 */
+    #[serde(default)]
+    pub target_cluster: Option<String>,
+    /* 지정하면 정해진 체크 후 DR 클러스터와 문서 수를 비교 */
+    #[serde(default)]
+    pub consistency: Option<ConsistencyCheckConfig>,
+}
+
+impl IndexSchedules {
+    #[doc = "색인 로그가 있는 클러스터 이름 (지정하지 않았으면 default_cluster)"]
+    pub fn log_cluster_or<'a>(&'a self, default_cluster: &'a str) -> &'a str {
+        self.log_cluster.as_deref().unwrap_or(default_cluster)
+    }
+
+    #[doc = "색인 대상 인덱스가 있는 클러스터 이름 (지정하지 않았으면 default_cluster)"]
+    pub fn target_cluster_or<'a>(&'a self, default_cluster: &'a str) -> &'a str {
+        self.target_cluster.as_deref().unwrap_or(default_cluster)
+    }
+
+    #[doc = "스케쥴이 이름으로 참조하는 클러스터들 (설정 확인용)"]
+    pub fn referenced_clusters(&self) -> Vec<&str> {
+        let mut cluster_names: Vec<&str> = Vec::new();
+        cluster_names.extend(self.log_cluster.as_deref());
+        cluster_names.extend(self.target_cluster.as_deref());
+
+        if let Some(consistency) = &self.consistency {
+            cluster_names.push(consistency.replica_cluster());
+            cluster_names.extend(consistency.primary_cluster().as_deref());
+        }
+
+        cluster_names
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Getters, Clone)]
@@ -0,0 +1,150 @@
+use crate::common::*;
+
+/* 다른 index template 보다 우선 적용되도록 (기본 제공 template 은 100) */
+pub const INDEX_TEMPLATE_PRIORITY: u32 = 200;
+
+#[doc = "문자열 필드 mapping - dynamic mapping 과 같은 text + keyword 로 두어서 기존 인덱스/쿼리(.keyword)와 호환"]
+pub fn text_keyword_mapping() -> Value {
+    json!({
+        "type": "text",
+        "fields": {
+            "keyword": { "type": "keyword", "ignore_above": 256 }
+        }
+    })
+}
+
+#[doc = "이미 있는 인덱스의 mapping 중 template mapping 과 타입이 다른 필드들을 찾아주는 함수"]
+/// dynamic mapping 으로 `@timestamp` 가 text 로 만들어진 인덱스처럼, put mapping 으로는 바꿀 수 없는 필드를 찾는다.
+///
+/// # Arguments
+/// * `mapping` - template 의 mapping (`properties`)
+/// * `current_mappings` - get mapping 응답 (`{ "<index>": { "mappings": { ... } } }`)
+///
+/// # Returns
+/// * Vec<String> - "인덱스.필드: 현재 타입 -> 필요한 타입" 목록
+pub fn find_mapping_conflicts(mapping: &Value, current_mappings: &Value) -> Vec<String> {
+    let (expected_fields, indices) = match (
+        mapping["properties"].as_object(),
+        current_mappings.as_object(),
+    ) {
+        (Some(expected_fields), Some(indices)) => (expected_fields, indices),
+        _ => return Vec::new(),
+    };
+
+    let mut conflicts: Vec<String> = Vec::new();
+
+    for (concrete_index, index_mapping) in indices {
+        for (field_name, expected_field) in expected_fields {
+            let current_field: &Value = &index_mapping["mappings"]["properties"][field_name];
+
+            /* 아직 없는 필드는 put mapping 으로 추가된다. */
+            if current_field.is_null() {
+                continue;
+            }
+
+            /* type 이 없으면 object */
+            let expected_type: &str = expected_field["type"].as_str().unwrap_or("object");
+            let current_type: &str = current_field["type"].as_str().unwrap_or("object");
+
+            if expected_type != current_type {
+                conflicts.push(format!(
+                    "{}.{}: {} -> {}",
+                    concrete_index, field_name, current_type, expected_type
+                ));
+            }
+        }
+    }
+
+    conflicts
+}
+
+#[doc = "composable index template 본문을 만들어주는 함수"]
+/// # Arguments
+/// * `index_pattern` - 적용할 인덱스 이름 (또는 패턴)
+/// * `mappings` - 인덱스 mapping
+/// * `lifecycle_policy` - 적용할 ILM policy 이름 (없으면 미적용)
+/// * `data_stream` - data stream 으로 만들지 여부
+///
+/// # Returns
+/// * Value
+pub fn build_index_template(
+    index_pattern: &str,
+    mappings: Value,
+    lifecycle_policy: Option<&str>,
+    data_stream: bool,
+) -> Value {
+    let mut template: Value = json!({
+        "index_patterns": [index_pattern],
+        "priority": INDEX_TEMPLATE_PRIORITY,
+        "template": {
+            "mappings": mappings
+        },
+        "_meta": {
+            "managed_by": "indexing_check"
+        }
+    });
+
+    if let Some(policy_name) = lifecycle_policy {
+        template["template"]["settings"] = json!({ "index.lifecycle.name": policy_name });
+    }
+
+    if data_stream {
+        template["data_stream"] = json!({});
+    }
+
+    template
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alarm_mapping() -> Value {
+        json!({
+            "properties": {
+                "@timestamp": { "type": "date" },
+                "index_name": text_keyword_mapping(),
+                "occurrence_count": { "type": "long" }
+            }
+        })
+    }
+
+    #[test]
+    fn dynamic_text_timestamp_is_conflict() {
+        let current_mappings: Value = json!({
+            "err-monitor": {
+                "mappings": {
+                    "properties": {
+                        "@timestamp": text_keyword_mapping(),
+                        "index_name": text_keyword_mapping()
+                    }
+                }
+            }
+        });
+
+        assert_eq!(
+            find_mapping_conflicts(&alarm_mapping(), &current_mappings),
+            vec!["err-monitor.@timestamp: text -> date"]
+        );
+    }
+
+    #[test]
+    fn same_or_missing_fields_are_not_conflicts() {
+        let current_mappings: Value = json!({
+            "err-monitor": {
+                "mappings": {
+                    "properties": {
+                        "@timestamp": { "type": "date" },
+                        "index_name": text_keyword_mapping()
+                    }
+                }
+            }
+        });
+
+        assert!(find_mapping_conflicts(&alarm_mapping(), &current_mappings).is_empty());
+        assert!(
+            find_mapping_conflicts(&alarm_mapping(), &json!({ "empty": { "mappings": {} } }))
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use crate::common::*;
+
+#[derive(Serialize, Deserialize, Debug, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct LeaderElectionConfig {
+    /* 여러 인스턴스를 같이 띄울 때만 사용 - 꺼져 있으면 항상 리더로 동작 */
+    pub enabled: bool,
+    /*
+        리더 임대(lease) 문서를 저장할 인덱스 / 문서 id (같은 id 를 쓰는 인스턴스끼리 경쟁)
+        알람 일시중지(/snooze) 문서도 이 인덱스에 저장한다. (리더 선출을 쓰지 않아도 사용)
+    */
+    pub lease_index: String,
+    pub lease_id: String,
+    /* 갱신하지 못하면 이 시간 뒤에 다른 인스턴스가 리더를 가져간다. */
+    pub lease_duration_sec: i64,
+    /* 임대 갱신 주기 - lease_duration_sec 보다 충분히 짧아야 한다. */
+    pub renew_interval_sec: u64,
+    /* 인스턴스 이름 - 미지정 시 '호스트명-pid' */
+    pub instance_id: Option<String>,
+}
+
+impl Default for LeaderElectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            lease_index: String::from("indexing-check-lease"),
+            lease_id: String::from("indexing-check-leader"),
+            lease_duration_sec: 30,
+            renew_interval_sec: 10,
+            instance_id: None,
+        }
+    }
+}
+
+impl LeaderElectionConfig {
+    #[doc = "이 인스턴스의 이름 - 설정값이 없으면 호스트명과 pid 로 만든다."]
+    pub fn resolve_instance_id(&self) -> String {
+        match &self.instance_id {
+            Some(instance_id) => instance_id.clone(),
+            None => {
+                let host_name: String = env::var("HOSTNAME")
+                    .or_else(|_| env::var("COMPUTERNAME"))
+                    .or_else(|_| fs::read_to_string("/etc/hostname").map(|h| h.trim().to_string()))
+                    .unwrap_or_else(|_| String::from("indexing-check"));
+
+                format!("{}-{}", host_name, std::process::id())
+            }
+        }
+    }
+}
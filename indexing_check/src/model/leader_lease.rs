@@ -0,0 +1,77 @@
+use crate::common::*;
+
+use crate::model::index_template::*;
+
+/* 임대 문서의 시각은 모두 UTC 로 저장 */
+pub const LEADER_LEASE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+#[doc = "리더 임대(lease) 문서 - 임대 만료 전까지는 holder 만 체크/알림을 실행한다."]
+#[derive(Serialize, Deserialize, Debug, Getters, Clone)]
+#[getset(get = "pub")]
+pub struct LeaderLease {
+    pub holder: String,
+    pub acquired_at: String,
+    pub renewed_at: String,
+    pub expires_at: String,
+}
+
+impl LeaderLease {
+    #[doc = "now 부터 lease_duration 동안 유효한 임대 문서를 만들어주는 함수"]
+    /// # Arguments
+    /// * `holder` - 임대를 가진 인스턴스 이름
+    /// * `acquired_at` - 처음 리더가 된 시각 (UTC)
+    /// * `now` - 현재 시각 (UTC)
+    /// * `expires_at` - 임대 만료 시각 (UTC)
+    ///
+    /// # Returns
+    /// * LeaderLease
+    pub fn new(
+        holder: &str,
+        acquired_at: NaiveDateTime,
+        now: NaiveDateTime,
+        expires_at: NaiveDateTime,
+    ) -> Self {
+        Self {
+            holder: holder.to_string(),
+            acquired_at: acquired_at.format(LEADER_LEASE_TIME_FORMAT).to_string(),
+            renewed_at: now.format(LEADER_LEASE_TIME_FORMAT).to_string(),
+            expires_at: expires_at.format(LEADER_LEASE_TIME_FORMAT).to_string(),
+        }
+    }
+
+    #[doc = "임대 만료 여부 - 형식이 잘못된 문서는 만료된 것으로 본다."]
+    pub fn is_expired(&self, now: NaiveDateTime) -> bool {
+        NaiveDateTime::parse_from_str(&self.expires_at, LEADER_LEASE_TIME_FORMAT)
+            .map(|expires_at| expires_at <= now)
+            .unwrap_or(true)
+    }
+
+    #[doc = "처음 리더가 된 시각 - 형식이 잘못되었으면 now"]
+    pub fn acquired_at_or(&self, now: NaiveDateTime) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(&self.acquired_at, LEADER_LEASE_TIME_FORMAT).unwrap_or(now)
+    }
+}
+
+#[doc = "임대 문서 + 낙관적 동시성 제어(seq_no / primary_term) 값"]
+#[derive(Debug, Getters, Clone, new)]
+#[getset(get = "pub")]
+pub struct LeaderLeaseFormat {
+    pub leader_lease: LeaderLease,
+    pub seq_no: i64,
+    pub primary_term: i64,
+}
+
+#[doc = "임대 인덱스의 mapping - 리더 임대 문서와 알람 일시중지(snooze) 문서"]
+pub fn leader_lease_mapping() -> Value {
+    json!({
+        "properties": {
+            "holder": text_keyword_mapping(),
+            "acquired_at": { "type": "date" },
+            "renewed_at": { "type": "date" },
+            "expires_at": { "type": "date" },
+            "index_name": text_keyword_mapping(),
+            "snoozed_at": { "type": "date" },
+            "snoozed_until": { "type": "date" }
+        }
+    })
+}
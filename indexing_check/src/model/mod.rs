@@ -1,13 +1,40 @@
+pub mod alarm_snooze;
+pub mod alarm_template_context;
+pub mod bulk_operation;
+pub mod check_history;
+pub mod check_record;
+pub mod cli_args;
 pub mod code_config;
+pub mod config_validation;
+pub mod consistency_check_config;
+pub mod consistency_check_result;
+pub mod digest_config;
+pub mod digest_template_context;
 pub mod elastic_server_config;
+pub mod email_config;
 pub mod error_alarm_info;
 pub mod error_alarm_info_format;
+pub mod es_node_status;
+pub mod history_config;
+pub mod index_check_status;
+pub mod index_management_config;
 pub mod index_schedules_config;
+pub mod index_template;
+pub mod leader_election_config;
+pub mod leader_lease;
+pub mod notification_outbox;
+pub mod outbox_config;
+pub mod procedure_config;
 pub mod rdb_config;
 pub mod receiver_email_config;
+pub mod replay_report;
+pub mod report_config;
+pub mod secret_value;
 pub mod smtp_config;
+pub mod sla_report;
 pub mod system_config;
 pub mod telegram_config;
+pub mod telegram_update;
 pub mod total_config;
 pub mod vector_index_log;
 pub mod vector_index_log_format;
@@ -0,0 +1,135 @@
+use crate::common::*;
+
+use crate::model::email_struct::*;
+use crate::model::index_template::*;
+use crate::utils_modules::traits::*;
+
+/* 발송 대기열 상태값 - pending: 발송 대기/재시도 중, expired: 재시도 한도 초과 */
+pub const OUTBOX_STATUS_PENDING: &str = "pending";
+pub const OUTBOX_STATUS_EXPIRED: &str = "expired";
+
+/* 채널 이름 - 프로시저 채널은 'procedure:{name}' 형식 */
+pub const OUTBOX_CHANNEL_TELEGRAM: &str = "telegram";
+pub const OUTBOX_CHANNEL_EMAIL: &str = "email";
+pub const OUTBOX_CHANNEL_PROCEDURE_PREFIX: &str = "procedure:";
+
+/* 발송 대기열의 시각은 모두 UTC 로 저장 */
+pub const OUTBOX_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+#[derive(Serialize, Deserialize, Debug, Getters, Setters, Clone)]
+#[getset(get = "pub", set = "pub")]
+pub struct NotificationOutbox {
+    #[serde(rename = "@timestamp")]
+    pub timestamp: String,
+    pub channel: String,
+    pub recipient: String,
+    pub subject: String,
+    pub html_form: String,
+    pub text_form: String,
+    pub summary_form: String,
+    /* 이 알림이 다루는 err_monitor_index 의 문서 id 목록 */
+    pub alarm_doc_ids: Vec<String>,
+    pub status: String,
+    pub attempts: u32,
+    pub next_attempt_at: String,
+    pub expires_at: String,
+    pub last_error: String,
+}
+
+impl NotificationOutbox {
+    #[doc = "채널/수신자별 발송 대기 알림을 만들어주는 함수"]
+    /// # Arguments
+    /// * `channel` - 발송 채널 (telegram, email, procedure:{name})
+    /// * `recipient` - 수신자 (chat id, 이메일 주소, 전화번호 등)
+    /// * `email` - 발송할 내용
+    /// * `alarm_doc_ids` - 이 알림이 다루는 알람 문서 id 목록
+    /// * `created_at` - 생성 시각 (UTC)
+    /// * `expire_sec` - 생성 후 재시도를 포기할 시간(초)
+    pub fn new(
+        channel: &str,
+        recipient: &str,
+        email: &EmailStruct,
+        alarm_doc_ids: Vec<String>,
+        created_at: NaiveDateTime,
+        expire_sec: i64,
+    ) -> Self {
+        let created_at_str: String = created_at.format(OUTBOX_TIME_FORMAT).to_string();
+        let expires_at: NaiveDateTime = created_at + chrono::Duration::seconds(expire_sec);
+
+        Self {
+            timestamp: created_at_str.clone(),
+            channel: channel.to_string(),
+            recipient: recipient.to_string(),
+            subject: email.subject().to_string(),
+            html_form: email.html_form().to_string(),
+            text_form: email.text_form().to_string(),
+            summary_form: email.summary_form().to_string(),
+            alarm_doc_ids,
+            status: OUTBOX_STATUS_PENDING.to_string(),
+            attempts: 0,
+            next_attempt_at: created_at_str,
+            expires_at: expires_at.format(OUTBOX_TIME_FORMAT).to_string(),
+            last_error: String::new(),
+        }
+    }
+
+    #[doc = "재시도 만료 시각이 지났는지 확인해주는 함수"]
+    pub fn is_expired(&self, now_utc: NaiveDateTime) -> bool {
+        NaiveDateTime::parse_from_str(&self.expires_at, OUTBOX_TIME_FORMAT)
+            .map(|expires_at| expires_at <= now_utc)
+            .unwrap_or(true)
+    }
+
+    #[doc = "발송할 내용을 EmailStruct 형태로 돌려주는 함수"]
+    pub fn to_email_struct(&self) -> EmailStruct {
+        EmailStruct::new(
+            self.subject.clone(),
+            self.html_form.clone(),
+            self.text_form.clone(),
+            self.summary_form.clone(),
+        )
+    }
+}
+
+#[doc = "발송을 시도한 뒤 outbox 문서에 반영할 결과"]
+#[derive(Debug, Clone)]
+pub enum OutboxSettlement {
+    /* 발송 완료 - 대기열에서 제거 */
+    Delivered,
+    /* 발송 실패 - 재시도 정보(또는 expired) 를 갱신 */
+    Failed(Box<NotificationOutbox>),
+}
+
+#[doc = "발송 대기열(outbox) 인덱스의 mapping"]
+pub fn notification_outbox_mapping() -> Value {
+    json!({
+        "properties": {
+            "@timestamp": { "type": "date" },
+            "channel": text_keyword_mapping(),
+            "recipient": text_keyword_mapping(),
+            "subject": text_keyword_mapping(),
+            "html_form": text_keyword_mapping(),
+            "text_form": text_keyword_mapping(),
+            "summary_form": text_keyword_mapping(),
+            "alarm_doc_ids": text_keyword_mapping(),
+            "status": text_keyword_mapping(),
+            "attempts": { "type": "long" },
+            "next_attempt_at": { "type": "date" },
+            "expires_at": { "type": "date" },
+            "last_error": text_keyword_mapping()
+        }
+    })
+}
+
+#[derive(Serialize, Deserialize, Debug, Getters, new)]
+#[getset(get = "pub")]
+pub struct NotificationOutboxFormat {
+    pub doc_id: String,
+    pub notification_outbox: NotificationOutbox,
+}
+
+impl FromSearchHit<NotificationOutbox> for NotificationOutboxFormat {
+    fn from_search_hit(doc_id: String, notification_outbox: NotificationOutbox) -> Self {
+        NotificationOutboxFormat::new(doc_id, notification_outbox)
+    }
+}
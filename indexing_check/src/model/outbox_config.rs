@@ -0,0 +1,44 @@
+use crate::common::*;
+
+#[derive(Serialize, Deserialize, Debug, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct OutboxConfig {
+    /* 알림 발송 대기열을 저장할 Elasticsearch 인덱스 */
+    pub outbox_index: String,
+    /* 최대 발송 시도 횟수 */
+    pub max_attempts: u32,
+    /* 재시도 대기 시간(초) - 실패할 때마다 2배씩 증가 */
+    pub base_backoff_sec: i64,
+    pub max_backoff_sec: i64,
+    /* 알림 생성 후 이 시간(초)이 지나면 더 이상 재시도하지 않는다. */
+    pub expire_sec: i64,
+    /* 한번에 꺼내서 발송할 최대 개수 */
+    pub dispatch_batch_size: usize,
+    /* 발송 대기열을 확인하는 주기(초) */
+    pub dispatch_interval_sec: u64,
+}
+
+impl Default for OutboxConfig {
+    fn default() -> Self {
+        Self {
+            outbox_index: String::from("indexing-check-outbox"),
+            max_attempts: 10,
+            base_backoff_sec: 30,
+            max_backoff_sec: 1800,
+            expire_sec: 86400,
+            dispatch_batch_size: 100,
+            dispatch_interval_sec: 10,
+        }
+    }
+}
+
+impl OutboxConfig {
+    #[doc = "attempts 번 실패한 후 다음 재시도까지 기다릴 시간(초)"]
+    pub fn backoff_sec(&self, attempts: u32) -> i64 {
+        let exp: u32 = attempts.saturating_sub(1).min(20);
+        self.base_backoff_sec
+            .saturating_mul(1_i64 << exp)
+            .min(self.max_backoff_sec)
+    }
+}
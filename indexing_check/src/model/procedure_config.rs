@@ -0,0 +1,118 @@
+use crate::common::*;
+
+#[derive(Serialize, Deserialize, Debug, Getters, Clone)]
+#[getset(get = "pub")]
+pub struct ProcedureParam {
+    /* 프로시저 파라미터 이름 ('@' 제외) */
+    pub name: String,
+    /* 고정값 - 발신자 정보 등 */
+    #[serde(default)]
+    pub value: Option<String>,
+    /* 알람마다 바뀌는 값: "recipient" | "subject" | "html_content" | "text_content" | "summary" */
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Getters, Clone)]
+#[getset(get = "pub")]
+pub struct ProcedureConfig {
+    pub procedure_name: String,
+    pub params: Vec<ProcedureParam>,
+    /* 지정 시 해당 리턴코드만 성공으로 간주 */
+    #[serde(default)]
+    pub success_return_codes: Option<Vec<i32>>,
+    /* success_return_codes 가 없을 때 실패로 간주할 리턴코드 */
+    #[serde(default = "default_failure_return_codes")]
+    pub failure_return_codes: Vec<i32>,
+}
+
+fn default_failure_return_codes() -> Vec<i32> {
+    vec![0]
+}
+
+impl ProcedureConfig {
+    #[doc = "프로시저 리턴코드가 성공인지 판단해주는 함수"]
+    pub fn is_success_code(&self, return_code: i32) -> bool {
+        match &self.success_return_codes {
+            Some(success_codes) => success_codes.contains(&return_code),
+            None => !self.failure_return_codes.contains(&return_code),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Getters, Clone)]
+#[getset(get = "pub")]
+pub struct ProcedureNotifierConfig {
+    /* 채널 이름 - 로그 구분용 (예: sms, alimtalk) */
+    pub name: String,
+    pub receivers: Vec<String>,
+    #[serde(flatten)]
+    pub procedure: ProcedureConfig,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn procedure(extra: &str) -> ProcedureConfig {
+        toml::from_str(&format!(
+            r#"
+            procedure_name = "SMS.dbo.SMS_SEND_PROC"
+            params = [{{ name = "RECV_PHONE", source = "recipient" }}]
+            {}
+            "#,
+            extra
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn is_success_code_defaults_to_zero_as_failure() {
+        let procedure: ProcedureConfig = procedure("");
+
+        assert!(!procedure.is_success_code(0));
+        assert!(procedure.is_success_code(1));
+        assert!(procedure.is_success_code(-1));
+    }
+
+    #[test]
+    fn is_success_code_with_failure_codes() {
+        let procedure: ProcedureConfig = procedure("failure_return_codes = [-1, -2]");
+
+        assert!(procedure.is_success_code(0));
+        assert!(!procedure.is_success_code(-1));
+        assert!(!procedure.is_success_code(-2));
+    }
+
+    #[test]
+    fn is_success_code_prefers_success_codes() {
+        let procedure: ProcedureConfig =
+            procedure("success_return_codes = [1]\nfailure_return_codes = [2]");
+
+        assert!(procedure.is_success_code(1));
+        assert!(!procedure.is_success_code(0));
+        assert!(!procedure.is_success_code(3));
+    }
+
+    #[test]
+    fn notifier_config_flattens_procedure() {
+        let notifier: ProcedureNotifierConfig = toml::from_str(
+            r#"
+            name = "sms"
+            receivers = ["01012345678"]
+            procedure_name = "SMS.dbo.SMS_SEND_PROC"
+            params = [{ name = "MSG", source = "summary" }]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            notifier.procedure().procedure_name(),
+            "SMS.dbo.SMS_SEND_PROC"
+        );
+        assert_eq!(
+            notifier.procedure().params()[0].source().as_deref(),
+            Some("summary")
+        );
+    }
+}
@@ -1,11 +1,25 @@
 use crate::common::*;
 
+use crate::model::secret_value::*;
+
 #[derive(Serialize, Deserialize, Debug, Getters)]
 #[getset(get = "pub")]
 pub struct RdbConfig {
     pub host: String,
     pub port: String,
     pub user_id: String,
-    pub user_pw: String,
+    /* "${ENV_VAR}" 로 환경변수 참조, 또는 user_pw_file 로 파일에서 읽기 */
+    #[serde(default)]
+    pub user_pw: SecretValue,
+    #[serde(default)]
+    pub user_pw_file: Option<String>,
     pub db_schema: String,
 }
+
+impl RdbConfig {
+    #[doc = "secret 항목의 <항목>_file 을 읽어서 값을 채워주는 함수"]
+    pub fn resolve_secret_files(&mut self) -> Result<(), anyhow::Error> {
+        self.user_pw
+            .resolve_file("user_pw", self.user_pw_file.as_deref())
+    }
+}
@@ -0,0 +1,306 @@
+use crate::common::*;
+
+use crate::model::index_schedules_config::*;
+
+use crate::utils_modules::io_utils::*;
+
+/* replay 결과 종류 - 체크 결과(Success / Partial Error / Full Error / Unknown) + 조회 실패(Skipped) */
+const REPLAY_OUTCOMES: [&str; 5] = [
+    "Success",
+    "Partial Error",
+    "Full Error",
+    "Unknown",
+    "Skipped",
+];
+
+#[doc = "replay 에서 CRON 실행 시각 하나를 다시 평가한 결과"]
+#[derive(Serialize, Debug, Getters, Clone)]
+#[getset(get = "pub")]
+pub struct ReplaySlot {
+    pub index_name: String,
+    pub index_type: String,
+    /* CRON 실행 시각 (한국시간) */
+    pub scheduled_at: String,
+    /* 조회한 색인 로그 인덱스 */
+    pub log_index: String,
+    /* Success | Partial Error | Full Error | Unknown | Skipped */
+    pub outcome: String,
+    /* 로그에서 읽은 색인 건수 - 로그가 없으면 None */
+    pub indexing_cnt_num: Option<usize>,
+    pub declare_index_size: usize,
+    /* 찾은 색인 로그의 timestamp (UTC) */
+    pub log_timestamp: Option<String>,
+    /* 건너뛴 이유 등 */
+    pub detail: String,
+}
+
+impl ReplaySlot {
+    #[doc = "평가 전 상태(Skipped)의 실행 시각 결과를 만들어주는 함수"]
+    /// # Arguments
+    /// * `index_schedule` - 인덱스 스케쥴 객체
+    /// * `scheduled_at` - CRON 실행 시각 (한국시간)
+    pub fn new(index_schedule: &IndexSchedules, scheduled_at: DateTime<FixedOffset>) -> Self {
+        Self {
+            index_name: index_schedule.index_name().to_string(),
+            index_type: index_schedule.indexing_type().to_string(),
+            scheduled_at: scheduled_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            log_index: String::new(),
+            outcome: String::from("Skipped"),
+            indexing_cnt_num: None,
+            declare_index_size: *index_schedule.size(),
+            log_timestamp: None,
+            detail: String::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct ReplayReport {
+    pub period_start: String,
+    pub period_end: String,
+    pub slots: Vec<ReplaySlot>,
+}
+
+impl ReplayReport {
+    pub fn new(
+        period_start: NaiveDateTime,
+        period_end: NaiveDateTime,
+        slots: Vec<ReplaySlot>,
+    ) -> Self {
+        let fmt: &str = "%Y-%m-%d %H:%M:%S";
+
+        Self {
+            period_start: period_start.format(fmt).to_string(),
+            period_end: period_end.format(fmt).to_string(),
+            slots,
+        }
+    }
+
+    #[doc = "replay 결과를 CSV 문자열로 변환해주는 함수 (실행 시각 하나당 한 줄)"]
+    pub fn to_csv(&self) -> String {
+        let mut csv: String = String::from(
+            "index_name,index_type,scheduled_at,log_index,outcome,indexing_cnt_num,declare_index_size,log_timestamp,detail\n",
+        );
+
+        for slot in &self.slots {
+            let row: Vec<String> = vec![
+                csv_field(&slot.index_name),
+                csv_field(&slot.index_type),
+                csv_field(&slot.scheduled_at),
+                csv_field(&slot.log_index),
+                csv_field(&slot.outcome),
+                slot.indexing_cnt_num
+                    .map(|v| v.to_string())
+                    .unwrap_or_default(),
+                slot.declare_index_size.to_string(),
+                csv_field(slot.log_timestamp.as_deref().unwrap_or_default()),
+                csv_field(&slot.detail),
+            ];
+
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    #[doc = "replay 결과를 표 형태 문자열로 변환해주는 함수 - 마지막에 인덱스별 결과 개수를 붙인다."]
+    pub fn to_table(&self) -> String {
+        let mut table: String = format!(
+            "[Replay] {} ~ {} (KST)\n{:<32} {:<14} {:<20} {:<14} {:>12} {:>12}  DETAIL\n",
+            self.period_start,
+            self.period_end,
+            "INDEX",
+            "TYPE",
+            "SCHEDULED (KST)",
+            "OUTCOME",
+            "INDEXED",
+            "SIZE"
+        );
+
+        for slot in &self.slots {
+            table.push_str(&format!(
+                "{:<32} {:<14} {:<20} {:<14} {:>12} {:>12}  {}\n",
+                slot.index_name,
+                slot.index_type,
+                slot.scheduled_at,
+                slot.outcome,
+                slot.indexing_cnt_num
+                    .map(|v| v.to_string())
+                    .unwrap_or_else(|| String::from("-")),
+                slot.declare_index_size,
+                /* 표가 깨지지 않도록 첫 줄만 (전체 내용은 csv / json) */
+                slot.detail.lines().next().unwrap_or_default()
+            ));
+        }
+
+        table.push_str("\n[Summary]\n");
+
+        for (index_name, outcome_cnts) in self.outcome_counts() {
+            let total_cnt: usize = outcome_cnts.iter().map(|(_, cnt)| cnt).sum();
+            let cnts: Vec<String> = outcome_cnts
+                .iter()
+                .map(|(outcome, cnt)| format!("{} {}", outcome, cnt))
+                .collect();
+
+            table.push_str(&format!(
+                "- {}: {} slot(s) / {}\n",
+                index_name,
+                total_cnt,
+                cnts.join(" / ")
+            ));
+        }
+
+        table
+    }
+
+    #[doc = "인덱스별 결과 종류 개수를 구해주는 함수 (인덱스는 처음 나온 순서)"]
+    pub fn outcome_counts(&self) -> Vec<(&str, Vec<(&'static str, usize)>)> {
+        let mut index_names: Vec<&str> = Vec::new();
+
+        for slot in &self.slots {
+            if !index_names.contains(&slot.index_name.as_str()) {
+                index_names.push(&slot.index_name);
+            }
+        }
+
+        index_names
+            .into_iter()
+            .map(|index_name| {
+                let outcome_cnts: Vec<(&'static str, usize)> = REPLAY_OUTCOMES
+                    .iter()
+                    .map(|outcome| {
+                        let cnt: usize = self
+                            .slots
+                            .iter()
+                            .filter(|slot| {
+                                slot.index_name == index_name && slot.outcome == *outcome
+                            })
+                            .count();
+                        (*outcome, cnt)
+                    })
+                    .collect();
+                (index_name, outcome_cnts)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::TimeZone;
+
+    fn index_schedule(index_name: &str) -> IndexSchedules {
+        toml::from_str(&format!(
+            r#"
+            index_name = "{}"
+            time = "0 0 * * * * *"
+            duration = 3600
+            size = 100
+            indexing_type = "static index"
+            "#,
+            index_name
+        ))
+        .unwrap()
+    }
+
+    fn slot(index_name: &str, hour: u32, outcome: &str) -> ReplaySlot {
+        let scheduled_at: DateTime<FixedOffset> = FixedOffset::east_opt(9 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2026, 10, 1, hour, 0, 0)
+            .unwrap();
+
+        let mut slot: ReplaySlot = ReplaySlot::new(&index_schedule(index_name), scheduled_at);
+        slot.outcome = outcome.to_string();
+        slot
+    }
+
+    fn replay_report() -> ReplayReport {
+        let mut success_slot: ReplaySlot = slot("product_a", 1, "Success");
+        success_slot.log_index = String::from("indexing-log-2026.10");
+        success_slot.indexing_cnt_num = Some(120);
+        success_slot.log_timestamp = Some(String::from("2026-09-30T16:10:00Z"));
+
+        let mut skipped_slot: ReplaySlot = slot("product_b", 1, "Skipped");
+        skipped_slot.detail = String::from("search failed, \"timeout\"\nretry later");
+
+        let period_start: NaiveDateTime = NaiveDate::from_ymd_opt(2026, 10, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+
+        ReplayReport::new(
+            period_start,
+            period_start + chrono::Duration::hours(3),
+            vec![
+                success_slot,
+                skipped_slot,
+                slot("product_a", 2, "Full Error"),
+                slot("product_a", 3, "Success"),
+            ],
+        )
+    }
+
+    #[test]
+    fn new_slot_starts_as_skipped() {
+        let slot: ReplaySlot = slot("product_a", 1, "Skipped");
+
+        assert_eq!(slot.scheduled_at(), "2026-10-01 01:00:00");
+        assert_eq!(slot.index_type(), "static index");
+        assert_eq!(*slot.declare_index_size(), 100);
+        assert_eq!(slot.indexing_cnt_num(), &None);
+    }
+
+    #[test]
+    fn outcome_counts_per_index_in_first_seen_order() {
+        let replay_report: ReplayReport = replay_report();
+        let outcome_counts: Vec<(&str, Vec<(&'static str, usize)>)> =
+            replay_report.outcome_counts();
+
+        assert_eq!(outcome_counts.len(), 2);
+        assert_eq!(outcome_counts[0].0, "product_a");
+        assert_eq!(
+            outcome_counts[0].1,
+            vec![
+                ("Success", 2),
+                ("Partial Error", 0),
+                ("Full Error", 1),
+                ("Unknown", 0),
+                ("Skipped", 0)
+            ]
+        );
+        assert_eq!(outcome_counts[1].0, "product_b");
+        assert_eq!(outcome_counts[1].1[4], ("Skipped", 1));
+    }
+
+    #[test]
+    fn to_csv_writes_one_row_per_slot() {
+        let csv: String = replay_report().to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert!(lines[0].starts_with("index_name,index_type,scheduled_at,"));
+        assert_eq!(
+            lines[1],
+            "product_a,static index,2026-10-01 01:00:00,indexing-log-2026.10,Success,120,100,2026-09-30T16:10:00Z,"
+        );
+        assert!(csv.contains(
+            "product_b,static index,2026-10-01 01:00:00,,Skipped,,100,,\"search failed, \"\"timeout\"\"\nretry later\"\n"
+        ));
+    }
+
+    #[test]
+    fn to_table_shows_first_detail_line_and_summary() {
+        let table: String = replay_report().to_table();
+
+        assert!(table.starts_with("[Replay] 2026-10-01 00:00:00 ~ 2026-10-01 03:00:00 (KST)\n"));
+        assert!(table.contains("search failed, \"timeout\"\n"));
+        assert!(!table.contains("retry later"));
+        assert!(table.contains(
+            "- product_a: 3 slot(s) / Success 2 / Partial Error 0 / Full Error 1 / Unknown 0 / Skipped 0\n"
+        ));
+        assert!(table.contains("- product_b: 1 slot(s) /"));
+    }
+}
@@ -0,0 +1,11 @@
+use crate::common::*;
+
+#[derive(Serialize, Deserialize, Debug, Getters, Default)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct ReportConfig {
+    /* 주간 리포트 발송 시각 - CRON 표현식 (한국시간), 없으면 발송하지 않음 */
+    pub weekly_cron: Option<String>,
+    /* 월간 리포트 발송 시각 - CRON 표현식 (한국시간), 없으면 발송하지 않음 */
+    pub monthly_cron: Option<String>,
+}
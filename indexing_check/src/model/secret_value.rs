@@ -0,0 +1,128 @@
+use crate::common::*;
+
+/* ${ENV_VAR} 형식 - 환경변수 이름은 영문/숫자/'_' */
+static ENV_PLACEHOLDER_RE: once_lazy<Result<Regex, regex::Error>> =
+    once_lazy::new(|| Regex::new(r"\$\{([A-Za-z_][A-Za-z0-9_]*)\}"));
+
+const REDACTED: &str = "***";
+
+#[doc = "비밀번호 / 토큰 등 로그, Debug 출력, 직렬화 결과에 나오면 안되는 설정값"]
+/// 설정 파일에는 값을 직접 적거나 `${ENV_VAR}` 로 환경변수를 참조할 수 있고,
+/// 같은 이름의 `<항목>_file` 을 지정하면 파일 내용을 값으로 사용한다.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretValue(String);
+
+impl SecretValue {
+    #[doc = "실제 값 - 인증 헤더 / 접속 문자열을 만들 때만 사용"]
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[doc = "값이 비어있으면 None"]
+    pub fn non_empty(&self) -> Option<&str> {
+        (!self.0.is_empty()).then_some(self.0.as_str())
+    }
+
+    #[doc = "`<항목>_file` 이 지정되어 있으면 파일 내용으로 값을 채워주는 함수"]
+    /// # Arguments
+    /// * `field` - 설정 항목 이름 (에러 메시지용)
+    /// * `file_path` - `<항목>_file` 설정값
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error> - 값과 파일을 둘 다 지정했거나 파일을 읽지 못하면 에러
+    pub fn resolve_file(
+        &mut self,
+        field: &str,
+        file_path: Option<&str>,
+    ) -> Result<(), anyhow::Error> {
+        let Some(file_path) = file_path else {
+            return Ok(());
+        };
+
+        if !self.0.is_empty() {
+            return Err(anyhow!(
+                "[SecretValue->resolve_file] Use either '{}' or '{}_file', not both",
+                field,
+                field
+            ));
+        }
+
+        let content: String = fs::read_to_string(file_path).map_err(|e| {
+            anyhow!(
+                "[SecretValue->resolve_file] Failed to read '{}_file' ({}): {}",
+                field,
+                file_path,
+                e
+            )
+        })?;
+
+        /* docker / k8s secret 파일은 끝에 개행이 붙는 경우가 많다. */
+        self.0 = content.trim_end_matches(['\r', '\n']).to_string();
+
+        Ok(())
+    }
+}
+
+#[doc = "설정값의 `${ENV_VAR}` 를 환경변수 값으로 바꿔주는 함수"]
+/// # Arguments
+/// * `raw` - 설정 파일에 적힌 값
+///
+/// # Returns
+/// * Result<String, anyhow::Error> - 참조한 환경변수가 없으면 에러
+pub fn interpolate_env(raw: &str) -> Result<String, anyhow::Error> {
+    let placeholder_re: &Regex = ENV_PLACEHOLDER_RE
+        .as_ref()
+        .map_err(|e| anyhow!("[interpolate_env] Invalid placeholder pattern: {}", e))?;
+
+    let mut missing: Vec<String> = Vec::new();
+
+    let interpolated: String = placeholder_re
+        .replace_all(raw, |caps: &regex::Captures| {
+            let key: &str = caps.get(1).map_or("", |m| m.as_str());
+
+            env::var(key).unwrap_or_else(|_| {
+                missing.push(key.to_string());
+                String::new()
+            })
+        })
+        .into_owned();
+
+    if !missing.is_empty() {
+        return Err(anyhow!(
+            "[interpolate_env] Environment variable(s) not set: {}",
+            missing.join(", ")
+        ));
+    }
+
+    Ok(interpolated)
+}
+
+impl std::fmt::Debug for SecretValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            write!(f, "SecretValue(\"\")")
+        } else {
+            write!(f, "SecretValue({})", REDACTED)
+        }
+    }
+}
+
+impl Serialize for SecretValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(if self.0.is_empty() { "" } else { REDACTED })
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretValue {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw: String = String::deserialize(deserializer)?;
+
+        interpolate_env(&raw)
+            .map(Self)
+            .map_err(|e| serde::de::Error::custom(e.to_string()))
+    }
+}
@@ -0,0 +1,335 @@
+use crate::common::*;
+
+use crate::model::{check_record::*, index_schedules_config::*};
+
+use crate::utils_modules::io_utils::*;
+
+/* 리포트 기간 종류 */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ReportPeriod {
+    Weekly,
+    Monthly,
+}
+
+impl ReportPeriod {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ReportPeriod::Weekly => "weekly",
+            ReportPeriod::Monthly => "monthly",
+        }
+    }
+
+    #[doc = "기준 시각 직전의 집계 기간 [start, end) 을 반환해주는 함수 (한국시간)"]
+    /// # Arguments
+    /// * `now` - 기준 시각
+    ///
+    /// # Returns
+    /// * weekly  - 기준일 0시 이전 7일
+    /// * monthly - 지난 달 1일 0시 ~ 이번 달 1일 0시
+    pub fn range_before(
+        &self,
+        now: NaiveDateTime,
+    ) -> Result<(NaiveDateTime, NaiveDateTime), anyhow::Error> {
+        let today: NaiveDate = now.date();
+
+        let (start_date, end_date) = match self {
+            ReportPeriod::Weekly => (today - chrono::Duration::days(7), today),
+            ReportPeriod::Monthly => {
+                let this_month: NaiveDate = today.with_day(1).ok_or_else(|| {
+                    anyhow!("[ReportPeriod->range_before] Invalid date: {}", today)
+                })?;
+                let prev_month: NaiveDate = (this_month - chrono::Duration::days(1))
+                    .with_day(1)
+                    .ok_or_else(|| {
+                        anyhow!("[ReportPeriod->range_before] Invalid date: {}", this_month)
+                    })?;
+                (prev_month, this_month)
+            }
+        };
+
+        Ok((
+            start_date.and_time(NaiveTime::MIN),
+            end_date.and_time(NaiveTime::MIN),
+        ))
+    }
+}
+
+#[derive(Serialize, Debug, Getters, Clone)]
+#[getset(get = "pub")]
+pub struct IndexSlaReport {
+    pub index_name: String,
+    pub index_type: String,
+    pub total_checks: usize,
+    pub success_cnt: usize,
+    pub partial_error_cnt: usize,
+    pub full_error_cnt: usize,
+    pub unknown_cnt: usize,
+    /* 성공률(%) */
+    pub success_rate: f64,
+    /* 실패 후 다음 성공까지 걸린 평균 시간(분) - 복구된 장애가 없으면 None */
+    pub mttr_minutes: Option<f64>,
+    pub recovered_cnt: usize,
+    /* 기간이 끝날 때까지 복구되지 않은 장애 수 (0 또는 1) */
+    pub unrecovered_cnt: usize,
+    /* 색인 개수를 알 수 있는 체크들의 평균 색인 개수 */
+    pub avg_indexing_cnt: Option<f64>,
+}
+
+impl IndexSlaReport {
+    #[doc = "체크 결과들로 인덱스 하나의 SLA 지표를 계산해주는 함수"]
+    /// # Arguments
+    /// * `index_schedule` - 인덱스 스케쥴 객체
+    /// * `records` - 해당 인덱스의 체크 결과 (시간순)
+    pub fn from_records(index_schedule: &IndexSchedules, records: &[CheckRecord]) -> Self {
+        let total_checks: usize = records.len();
+        let success_cnt: usize = records.iter().filter(|r| r.is_success()).count();
+        let partial_error_cnt: usize = records
+            .iter()
+            .filter(|r| r.outcome() == "Partial Error")
+            .count();
+        let full_error_cnt: usize = records
+            .iter()
+            .filter(|r| r.outcome() == "Full Error")
+            .count();
+
+        /* 장애 시작(첫 실패) 부터 다음 성공까지를 복구 시간으로 본다. */
+        let mut failure_started_at: Option<NaiveDateTime> = None;
+        let mut recovery_minutes: Vec<f64> = Vec::new();
+
+        for record in records {
+            if record.is_failure() {
+                failure_started_at.get_or_insert(*record.checked_at());
+            } else if record.is_success() {
+                if let Some(started_at) = failure_started_at.take() {
+                    let elapsed: chrono::Duration = *record.checked_at() - started_at;
+                    recovery_minutes.push(elapsed.num_seconds() as f64 / 60.0);
+                }
+            }
+        }
+
+        let indexing_cnts: Vec<usize> = records
+            .iter()
+            .filter_map(|r| *r.indexing_cnt_num())
+            .collect();
+
+        Self {
+            index_name: index_schedule.index_name().to_string(),
+            index_type: index_schedule.indexing_type().to_string(),
+            total_checks,
+            success_cnt,
+            partial_error_cnt,
+            full_error_cnt,
+            unknown_cnt: total_checks - success_cnt - partial_error_cnt - full_error_cnt,
+            success_rate: if total_checks == 0 {
+                0.0
+            } else {
+                round2(success_cnt as f64 * 100.0 / total_checks as f64)
+            },
+            mttr_minutes: average(&recovery_minutes).map(round2),
+            recovered_cnt: recovery_minutes.len(),
+            unrecovered_cnt: usize::from(failure_started_at.is_some()),
+            avg_indexing_cnt: average(
+                &indexing_cnts
+                    .iter()
+                    .map(|cnt| *cnt as f64)
+                    .collect::<Vec<f64>>(),
+            )
+            .map(round2),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct SlaReport {
+    pub cluster_name: String,
+    pub period_name: String,
+    pub period_start: String,
+    pub period_end: String,
+    pub generated_at: String,
+    pub indexes: Vec<IndexSlaReport>,
+}
+
+impl SlaReport {
+    pub fn new(
+        cluster_name: &str,
+        period_name: &str,
+        period_start: NaiveDateTime,
+        period_end: NaiveDateTime,
+        generated_at: NaiveDateTime,
+        indexes: Vec<IndexSlaReport>,
+    ) -> Self {
+        let fmt: &str = "%Y-%m-%d %H:%M:%S";
+
+        Self {
+            cluster_name: cluster_name.to_string(),
+            period_name: period_name.to_string(),
+            period_start: period_start.format(fmt).to_string(),
+            period_end: period_end.format(fmt).to_string(),
+            generated_at: generated_at.format(fmt).to_string(),
+            indexes,
+        }
+    }
+
+    #[doc = "리포트를 CSV 문자열로 변환해주는 함수"]
+    pub fn to_csv(&self) -> String {
+        let mut csv: String = String::from(
+            "index_name,index_type,period_start,period_end,total_checks,success_cnt,partial_error_cnt,full_error_cnt,unknown_cnt,success_rate,mttr_minutes,recovered_cnt,unrecovered_cnt,avg_indexing_cnt\n",
+        );
+
+        for index in &self.indexes {
+            let row: Vec<String> = vec![
+                csv_field(&index.index_name),
+                csv_field(&index.index_type),
+                csv_field(&self.period_start),
+                csv_field(&self.period_end),
+                index.total_checks.to_string(),
+                index.success_cnt.to_string(),
+                index.partial_error_cnt.to_string(),
+                index.full_error_cnt.to_string(),
+                index.unknown_cnt.to_string(),
+                index.success_rate.to_string(),
+                index
+                    .mttr_minutes
+                    .map(|v| v.to_string())
+                    .unwrap_or_default(),
+                index.recovered_cnt.to_string(),
+                index.unrecovered_cnt.to_string(),
+                index
+                    .avg_indexing_cnt
+                    .map(|v| v.to_string())
+                    .unwrap_or_default(),
+            ];
+
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+
+        csv
+    }
+}
+
+fn average(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.iter().sum::<f64>() / values.len() as f64)
+    }
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kst(datetime: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(datetime, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn schedule() -> IndexSchedules {
+        toml::from_str(
+            r#"
+            index_name = "product_a"
+            time = "0 0 * * * * *"
+            duration = 600
+            size = 100
+            indexing_type = "static index"
+            "#,
+        )
+        .unwrap()
+    }
+
+    fn record(outcome: &str, checked_at: &str, indexing_cnt_num: Option<usize>) -> CheckRecord {
+        CheckRecord::new(outcome.to_string(), kst(checked_at), indexing_cnt_num)
+    }
+
+    #[test]
+    fn range_before_weekly_and_monthly() {
+        let now: NaiveDateTime = kst("2025-03-05 09:30:00");
+
+        assert_eq!(
+            ReportPeriod::Weekly.range_before(now).unwrap(),
+            (kst("2025-02-26 00:00:00"), kst("2025-03-05 00:00:00"))
+        );
+        assert_eq!(
+            ReportPeriod::Monthly.range_before(now).unwrap(),
+            (kst("2025-02-01 00:00:00"), kst("2025-03-01 00:00:00"))
+        );
+        assert_eq!(
+            ReportPeriod::Monthly
+                .range_before(kst("2025-01-15 00:00:00"))
+                .unwrap(),
+            (kst("2024-12-01 00:00:00"), kst("2025-01-01 00:00:00"))
+        );
+    }
+
+    #[test]
+    fn from_records_counts_outcomes_and_mttr() {
+        let records: Vec<CheckRecord> = vec![
+            record("Success", "2025-01-01 00:00:00", Some(120)),
+            record("Full Error", "2025-01-01 01:00:00", None),
+            record("Partial Error", "2025-01-01 02:00:00", Some(40)),
+            record("Success", "2025-01-01 03:00:00", Some(110)),
+            record("Unknown", "2025-01-01 04:00:00", None),
+            record("Full Error", "2025-01-01 05:00:00", None),
+            record("Success", "2025-01-01 05:30:00", Some(100)),
+            record("Full Error", "2025-01-01 06:00:00", None),
+        ];
+
+        let report: IndexSlaReport = IndexSlaReport::from_records(&schedule(), &records);
+
+        assert_eq!(report.total_checks, 8);
+        assert_eq!(report.success_cnt, 3);
+        assert_eq!(report.partial_error_cnt, 1);
+        assert_eq!(report.full_error_cnt, 3);
+        assert_eq!(report.unknown_cnt, 1);
+        assert_eq!(report.success_rate, 37.5);
+        /* 01:00 ~ 03:00 (120분), 05:00 ~ 05:30 (30분) */
+        assert_eq!(report.recovered_cnt, 2);
+        assert_eq!(report.mttr_minutes, Some(75.0));
+        assert_eq!(report.unrecovered_cnt, 1);
+        assert_eq!(report.avg_indexing_cnt, Some(92.5));
+    }
+
+    #[test]
+    fn from_records_without_checks() {
+        let report: IndexSlaReport = IndexSlaReport::from_records(&schedule(), &[]);
+
+        assert_eq!(report.total_checks, 0);
+        assert_eq!(report.success_rate, 0.0);
+        assert_eq!(report.mttr_minutes, None);
+        assert_eq!(report.avg_indexing_cnt, None);
+    }
+
+    #[test]
+    fn sla_report_to_csv() {
+        let index: IndexSlaReport = IndexSlaReport::from_records(
+            &schedule(),
+            &[
+                record("Full Error", "2025-01-01 00:00:00", None),
+                record("Success", "2025-01-01 01:00:00", Some(100)),
+            ],
+        );
+        let report: SlaReport = SlaReport::new(
+            "main",
+            "custom",
+            kst("2025-01-01 00:00:00"),
+            kst("2025-01-02 00:00:00"),
+            kst("2025-01-02 09:00:00"),
+            vec![index],
+        );
+
+        let csv: String = report.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("index_name,index_type,period_start,"));
+        assert_eq!(
+            lines[1],
+            "product_a,static index,2025-01-01 00:00:00,2025-01-02 00:00:00,2,1,0,1,0,50,60,1,0,100"
+        );
+    }
+}
@@ -1,10 +1,38 @@
 use crate::common::*;
 
+use crate::model::secret_value::*;
+
 #[derive(Serialize, Deserialize, Debug, Getters)]
 #[getset(get = "pub")]
 pub struct SmtpConfig {
     pub smtp_name: String,
+    /* 미지정 시 smtp_security 에 맞는 기본 포트 사용 (tls: 465, starttls: 587, none: 25) */
+    #[serde(default)]
+    pub smtp_port: Option<u16>,
+    /* "tls" | "starttls" | "none" */
+    #[serde(default = "default_smtp_security")]
+    pub smtp_security: String,
+    /* 인증이 없는 SMTP 서버(로컬 테스트 서버 등)는 빈 값으로 둔다. */
+    #[serde(default)]
     pub credential_id: String,
-    pub credential_pw: String,
-    pub async_process_yn: bool,
+    /* "${ENV_VAR}" 로 환경변수 참조, 또는 credential_pw_file 로 파일에서 읽기 */
+    #[serde(default)]
+    pub credential_pw: SecretValue,
+    #[serde(default)]
+    pub credential_pw_file: Option<String>,
+    /* 발신자 주소 - 미지정 시 credential_id 사용 */
+    #[serde(default)]
+    pub sender_email: Option<String>,
+}
+
+fn default_smtp_security() -> String {
+    String::from("tls")
+}
+
+impl SmtpConfig {
+    #[doc = "secret 항목의 <항목>_file 을 읽어서 값을 채워주는 함수"]
+    pub fn resolve_secret_files(&mut self) -> Result<(), anyhow::Error> {
+        self.credential_pw
+            .resolve_file("credential_pw", self.credential_pw_file.as_deref())
+    }
 }
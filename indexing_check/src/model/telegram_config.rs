@@ -1,8 +1,44 @@
 use crate::common::*;
 
+use crate::model::secret_value::*;
+
 #[derive(Serialize, Deserialize, Debug, Getters)]
 #[getset(get = "pub")]
 pub struct TelegramConfig {
-    pub bot_token: String,
+    /* "${ENV_VAR}" 로 환경변수 참조, 또는 bot_token_file 로 파일에서 읽기 */
+    #[serde(default)]
+    pub bot_token: SecretValue,
+    #[serde(default)]
+    pub bot_token_file: Option<String>,
     pub chat_room_id: String,
+    /* 봇 명령어(/status, /ack ...) 수신 여부 */
+    #[serde(default)]
+    pub command_enabled: bool,
+    /* 명령어를 허용할 chat id 목록 - 비어있으면 chat_room_id 만 허용 */
+    #[serde(default)]
+    pub command_chat_ids: Vec<String>,
+    /* getUpdates long polling 대기 시간(초) */
+    #[serde(default = "default_command_poll_timeout")]
+    pub command_poll_timeout: u64,
+}
+
+fn default_command_poll_timeout() -> u64 {
+    30
+}
+
+impl TelegramConfig {
+    #[doc = "secret 항목의 <항목>_file 을 읽어서 값을 채워주는 함수"]
+    pub fn resolve_secret_files(&mut self) -> Result<(), anyhow::Error> {
+        self.bot_token
+            .resolve_file("bot_token", self.bot_token_file.as_deref())
+    }
+
+    #[doc = "해당 chat id 가 봇 명령어를 실행할 수 있는지 확인해주는 함수"]
+    pub fn is_command_allowed(&self, chat_id: &str) -> bool {
+        if self.command_chat_ids.is_empty() {
+            self.chat_room_id == chat_id
+        } else {
+            self.command_chat_ids.iter().any(|id| id == chat_id)
+        }
+    }
 }
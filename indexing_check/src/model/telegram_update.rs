@@ -0,0 +1,22 @@
+use crate::common::*;
+
+#[derive(Serialize, Deserialize, Debug, Getters, Clone)]
+#[getset(get = "pub")]
+pub struct TelegramChat {
+    pub id: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Getters, Clone)]
+#[getset(get = "pub")]
+pub struct TelegramMessage {
+    pub chat: TelegramChat,
+    pub date: i64,
+    pub text: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Getters, Clone)]
+#[getset(get = "pub")]
+pub struct TelegramUpdate {
+    pub update_id: i64,
+    pub message: Option<TelegramMessage>,
+}
@@ -1,7 +1,15 @@
 use crate::common::*;
 
 use crate::model::code_config::*;
+use crate::model::digest_config::*;
 use crate::model::elastic_server_config::*;
+use crate::model::email_config::*;
+use crate::model::history_config::*;
+use crate::model::index_management_config::*;
+use crate::model::leader_election_config::*;
+use crate::model::outbox_config::*;
+use crate::model::procedure_config::*;
+use crate::model::report_config::*;
 use crate::model::smtp_config::*;
 use crate::model::system_config::*;
 use crate::model::telegram_config::*;
@@ -10,89 +18,243 @@ use crate::utils_modules::io_utils::*;
 
 use crate::env_configuration::env_config::*;
 
-static SERVER_CONFIG: once_lazy<Arc<Config>> =
-    once_lazy::new(|| Arc::new(initialize_server_config()));
+use crate::errors::app_error::*;
 
-#[doc = "Function to initialize System configuration information instances"]
-pub fn initialize_server_config() -> Config {
+static SERVER_CONFIG: OnceCell<Config> = OnceCell::new();
+
+#[doc = "Function to initialize System configuration information instances - main() 에서 한번만 호출"]
+pub fn initialize_server_config() -> Result<(), AppError> {
     info!("initialize_server_config() START!");
 
-    let system_config: Config = Config::new();
-    system_config
+    let system_config: Config = Config::load()?;
+    SERVER_CONFIG
+        .set(system_config)
+        .map_err(|_| AppError::Config(String::from("system config is already initialized")))
+}
+
+#[doc = "초기화된 전역 설정 - initialize_server_config() 를 먼저 호출해야 한다."]
+fn server_config() -> &'static Config {
+    SERVER_CONFIG
+        .get()
+        .expect("[server_config()] initialize_server_config() must be called first")
 }
 
 #[doc = "Elasticsearch config 정보"]
 pub fn get_elasticsearch_config_info() -> Arc<ElasticServerConfig> {
-    let elastic_config: &Arc<ElasticServerConfig> = &SERVER_CONFIG.elasticsearch;
+    let elastic_config: &Arc<ElasticServerConfig> = &server_config().elasticsearch;
     Arc::clone(elastic_config)
 }
 
-#[doc = "SMTP config 정보"]
-#[allow(dead_code)]
-pub fn get_smtp_config_info() -> Arc<SmtpConfig> {
-    let smtp_config: &Arc<SmtpConfig> = &SERVER_CONFIG.smtp;
-    Arc::clone(smtp_config)
+#[doc = "모니터링 대상 Elasticsearch 클러스터 전체 config 정보 - 첫 번째는 [elasticsearch] (기본 클러스터)"]
+pub fn get_cluster_configs_info() -> Arc<Vec<Arc<ElasticServerConfig>>> {
+    let cluster_configs: &Arc<Vec<Arc<ElasticServerConfig>>> = &server_config().clusters;
+    Arc::clone(cluster_configs)
+}
+
+#[doc = "SMTP config 정보 - [smtp] 항목이 없으면 None"]
+pub fn get_smtp_config_info() -> Option<Arc<SmtpConfig>> {
+    server_config().smtp.as_ref().map(Arc::clone)
+}
+
+#[doc = "프로시저 기반 알림 채널(SMS, 알림톡 등) config 정보"]
+pub fn get_procedure_notifier_config_info() -> Arc<Vec<ProcedureNotifierConfig>> {
+    let procedure_notifier_config: &Arc<Vec<ProcedureNotifierConfig>> =
+        &server_config().procedure_notifier;
+    Arc::clone(procedure_notifier_config)
+}
+
+#[doc = "Email 발송 방식 config 정보"]
+pub fn get_email_config_info() -> Arc<EmailConfig> {
+    let email_config: &Arc<EmailConfig> = &server_config().email;
+    Arc::clone(email_config)
 }
 
 #[doc = "Telegram config 정보"]
 pub fn get_telegram_config_info() -> Arc<TelegramConfig> {
-    let telegram_config: &Arc<TelegramConfig> = &SERVER_CONFIG.telegram;
+    let telegram_config: &Arc<TelegramConfig> = &server_config().telegram;
     Arc::clone(telegram_config)
 }
 
 #[doc = "System config 정보"]
 pub fn get_system_config_info() -> Arc<SystemConfig> {
-    let system_config: &Arc<SystemConfig> = &SERVER_CONFIG.system;
+    let system_config: &Arc<SystemConfig> = &server_config().system;
     Arc::clone(system_config)
 }
 
+#[doc = "알림 발송 대기열(outbox) config 정보"]
+pub fn get_outbox_config_info() -> Arc<OutboxConfig> {
+    let outbox_config: &Arc<OutboxConfig> = &server_config().outbox;
+    Arc::clone(outbox_config)
+}
+
+#[doc = "일일 상태 요약(digest) config 정보"]
+pub fn get_digest_config_info() -> Arc<DigestConfig> {
+    let digest_config: &Arc<DigestConfig> = &server_config().digest;
+    Arc::clone(digest_config)
+}
+
+#[doc = "SLA 리포트 config 정보"]
+pub fn get_report_config_info() -> Arc<ReportConfig> {
+    let report_config: &Arc<ReportConfig> = &server_config().report;
+    Arc::clone(report_config)
+}
+
+#[doc = "색인 체크 결과 이력 config 정보"]
+pub fn get_history_config_info() -> Arc<HistoryConfig> {
+    let history_config: &Arc<HistoryConfig> = &server_config().history;
+    Arc::clone(history_config)
+}
+
+#[doc = "모니터링 프로그램 자체 인덱스(template, ILM, 보관 기간) 관리 config 정보"]
+pub fn get_index_management_config_info() -> Arc<IndexManagementConfig> {
+    let index_management_config: &Arc<IndexManagementConfig> = &server_config().index_management;
+    Arc::clone(index_management_config)
+}
+
+#[doc = "여러 인스턴스 사이의 리더 선출 config 정보"]
+pub fn get_leader_election_config_info() -> Arc<LeaderElectionConfig> {
+    let leader_election_config: &Arc<LeaderElectionConfig> = &server_config().leader_election;
+    Arc::clone(leader_election_config)
+}
+
 #[doc = "code 타입 config 정보"]
 pub fn get_code_config_info() -> Arc<CodeConfig> {
-    let code_config: &Arc<CodeConfig> = &SERVER_CONFIG.code_type;
+    let code_config: &Arc<CodeConfig> = &server_config().code_type;
     Arc::clone(code_config)
 }
 
 #[derive(Debug)]
 pub struct Config {
     pub elasticsearch: Arc<ElasticServerConfig>,
-    #[allow(dead_code)]
-    pub smtp: Arc<SmtpConfig>,
+    pub clusters: Arc<Vec<Arc<ElasticServerConfig>>>,
+    pub email: Arc<EmailConfig>,
+    pub smtp: Option<Arc<SmtpConfig>>,
+    pub procedure_notifier: Arc<Vec<ProcedureNotifierConfig>>,
     pub telegram: Arc<TelegramConfig>,
     pub system: Arc<SystemConfig>,
     pub code_type: Arc<CodeConfig>,
+    pub outbox: Arc<OutboxConfig>,
+    pub digest: Arc<DigestConfig>,
+    pub report: Arc<ReportConfig>,
+    pub history: Arc<HistoryConfig>,
+    pub index_management: Arc<IndexManagementConfig>,
+    pub leader_election: Arc<LeaderElectionConfig>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ConfigNotSafe {
     pub elasticsearch: ElasticServerConfig,
-    pub smtp: SmtpConfig,
+    /* 추가 클러스터 ([[clusters]]) - 모니터링 인덱스(알람, 이력 등)는 항상 [elasticsearch] 에 저장 */
+    #[serde(default)]
+    pub clusters: Vec<ElasticServerConfig>,
+    #[serde(default)]
+    pub email: EmailConfig,
+    #[serde(default)]
+    pub smtp: Option<SmtpConfig>,
+    #[serde(default)]
+    pub procedure_notifier: Vec<ProcedureNotifierConfig>,
     pub telegram: TelegramConfig,
     pub system: SystemConfig,
     pub code_type: CodeConfig,
+    #[serde(default)]
+    pub outbox: OutboxConfig,
+    #[serde(default)]
+    pub digest: DigestConfig,
+    #[serde(default)]
+    pub report: ReportConfig,
+    #[serde(default)]
+    pub history: HistoryConfig,
+    #[serde(default)]
+    pub index_management: IndexManagementConfig,
+    #[serde(default)]
+    pub leader_election: LeaderElectionConfig,
 }
 
-impl Config {
-    pub fn new() -> Self {
-        let system_config = match read_toml_from_file::<ConfigNotSafe>(&SYSTEM_CONFIG_PATH) {
-            Ok(system_config) => system_config,
-            Err(e) => {
-                error!(
-                    "[Error][main()] Failed to retrieve information 'system_config'. : {:?}",
-                    e
-                );
-                panic!(
-                    "[Error][main()] Failed to retrieve information 'system_config'. : {:?}",
+impl ConfigNotSafe {
+    #[doc = "비밀값 항목들의 `<항목>_file` 을 읽어서 채워주는 함수"]
+    /// # Returns
+    /// * Result<(), anyhow::Error> - 실패한 섹션 이름을 붙여서 반환
+    pub fn resolve_secret_files(&mut self) -> Result<(), anyhow::Error> {
+        self.elasticsearch
+            .resolve_secret_files()
+            .map_err(|e| anyhow!("[elasticsearch] {}", e))?;
+
+        for (position, cluster) in self.clusters.iter_mut().enumerate() {
+            cluster.resolve_secret_files().map_err(|e| {
+                anyhow!(
+                    "[[clusters]] #{} '{}' {}",
+                    position,
+                    cluster.elastic_cluster_name(),
                     e
-                );
+                )
+            })?;
+        }
+
+        if let Some(smtp) = self.smtp.as_mut() {
+            smtp.resolve_secret_files()
+                .map_err(|e| anyhow!("[smtp] {}", e))?;
+        }
+
+        self.telegram
+            .resolve_secret_files()
+            .map_err(|e| anyhow!("[telegram] {}", e))?;
+
+        Ok(())
+    }
+}
+
+impl Config {
+    #[doc = "system_config.toml 을 읽어서 설정 객체를 만들어주는 함수"]
+    pub fn load() -> Result<Self, AppError> {
+        let system_config_path: String = get_system_config_path()?;
+        let mut system_config: ConfigNotSafe =
+            read_toml_from_file::<ConfigNotSafe>(&system_config_path).map_err(|e| {
+                AppError::Config(format!(
+                    "Failed to read 'system_config' ({}): {}",
+                    system_config_path, e
+                ))
+            })?;
+
+        system_config.resolve_secret_files().map_err(|e| {
+            AppError::Config(format!(
+                "Failed to resolve secrets in 'system_config' ({}): {}",
+                system_config_path, e
+            ))
+        })?;
+
+        let elasticsearch: Arc<ElasticServerConfig> = Arc::new(system_config.elasticsearch);
+        let mut clusters: Vec<Arc<ElasticServerConfig>> = vec![Arc::clone(&elasticsearch)];
+
+        for cluster in system_config.clusters {
+            /* 알람, 체크 이력 등이 클러스터 이름으로 구분되므로 이름은 겹치면 안된다. */
+            if clusters
+                .iter()
+                .any(|known| known.elastic_cluster_name() == cluster.elastic_cluster_name())
+            {
+                return Err(AppError::Config(format!(
+                    "Duplicate elastic_cluster_name '{}' in 'system_config'",
+                    cluster.elastic_cluster_name()
+                )));
             }
-        };
 
-        Config {
-            elasticsearch: Arc::new(system_config.elasticsearch),
-            smtp: Arc::new(system_config.smtp),
+            clusters.push(Arc::new(cluster));
+        }
+
+        Ok(Config {
+            elasticsearch,
+            clusters: Arc::new(clusters),
+            email: Arc::new(system_config.email),
+            smtp: system_config.smtp.map(Arc::new),
+            procedure_notifier: Arc::new(system_config.procedure_notifier),
             telegram: Arc::new(system_config.telegram),
             system: Arc::new(system_config.system),
             code_type: Arc::new(system_config.code_type),
-        }
+            outbox: Arc::new(system_config.outbox),
+            digest: Arc::new(system_config.digest),
+            report: Arc::new(system_config.report),
+            history: Arc::new(system_config.history),
+            index_management: Arc::new(system_config.index_management),
+            leader_election: Arc::new(system_config.leader_election),
+        })
     }
 }
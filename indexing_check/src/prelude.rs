@@ -1,11 +1,12 @@
 pub use std::{
-    collections::HashMap,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     env, fs,
     io::{Read, Write},
     ops::Deref,
     path::Path,
     str::FromStr,
-    sync::Arc,
+    sync::{Arc, Mutex, MutexGuard, RwLock},
+    time::Instant,
 };
 
 pub use tokio::{
@@ -20,7 +21,7 @@ pub use async_trait::async_trait;
 pub use derive_new::new;
 pub use dotenv::dotenv;
 pub use getset::{Getters, Setters};
-pub use log::{error, info};
+pub use log::{error, info, warn};
 pub use serde::{de::DeserializeOwned, Deserialize, Serialize};
 pub use serde_json::{json, Value};
 pub use futures::{stream, StreamExt};
\ No newline at end of file
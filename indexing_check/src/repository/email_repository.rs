@@ -0,0 +1,72 @@
+use crate::common::*;
+
+use crate::errors::app_error::*;
+
+use crate::model::{email_config::*, procedure_config::*, total_config::*};
+
+use crate::repository::{
+    procedure_notifier_repository::*, smtp_repository::*, sqlserver_repository::*,
+};
+
+use crate::traits::repository_traits::email_repository_trait::*;
+
+#[doc = "[email] backend 설정에 맞는 이메일 발송 구현체를 만들어주는 함수"]
+/// # Returns
+/// * Result<Arc<dyn EmailRepository + Send + Sync>, AppError>
+///   - "imailer" : SQL Server iMailer 프로시저 ([email.imailer])
+///   - "smtp"    : lettre SMTP 클라이언트
+pub fn initialize_email_repo() -> Result<Arc<dyn EmailRepository + Send + Sync>, AppError> {
+    let email_config: Arc<EmailConfig> = get_email_config_info();
+
+    info!(
+        "initialize_email_repo() START! backend={}",
+        email_config.backend()
+    );
+
+    match email_config.backend().as_str() {
+        "imailer" => Ok(Arc::new(ProcedureNotifierRepositoryPub::new(
+            get_sqlserver_repo()?,
+            email_config.imailer().clone(),
+        ))),
+        "smtp" => {
+            let smtp_repo: SmtpRepositoryPub = initialize_smtp_client()
+                .map_err(|e| AppError::Config(format!("[initialize_email_repo()] {:#}", e)))?;
+            Ok(Arc::new(smtp_repo))
+        }
+        other => Err(AppError::Config(format!(
+            "[initialize_email_repo()] Unsupported email backend '{}' (use imailer/smtp)",
+            other
+        ))),
+    }
+}
+
+#[doc = "프로시저 알림 채널 설정과 발송 구현체"]
+pub type ProcedureNotifierRepo = (
+    ProcedureNotifierConfig,
+    Arc<dyn EmailRepository + Send + Sync>,
+);
+
+#[doc = "[[procedure_notifier]] 설정별로 프로시저 알림 채널을 만들어주는 함수"]
+/// # Returns
+/// * Result<Vec<ProcedureNotifierRepo>, AppError>
+pub fn initialize_procedure_notifier_repos() -> Result<Vec<ProcedureNotifierRepo>, AppError> {
+    let notifier_configs: Arc<Vec<ProcedureNotifierConfig>> = get_procedure_notifier_config_info();
+
+    notifier_configs
+        .iter()
+        .map(|notifier_config| {
+            info!(
+                "initialize_procedure_notifier_repos() channel={}",
+                notifier_config.name()
+            );
+
+            let repo: Arc<dyn EmailRepository + Send + Sync> =
+                Arc::new(ProcedureNotifierRepositoryPub::new(
+                    get_sqlserver_repo()?,
+                    notifier_config.procedure().clone(),
+                ));
+
+            Ok((notifier_config.clone(), repo))
+        })
+        .collect()
+}
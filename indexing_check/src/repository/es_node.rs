@@ -0,0 +1,245 @@
+use crate::common::*;
+
+use crate::model::es_node_status::*;
+
+use crate::repository::es_repository::*;
+
+/*
+    노드별 circuit breaker 상태
+    - Closed: 정상. 연속 실패가 기준치에 도달하면 Open
+    - Open: 차단. 대기 시간이 지나면 요청 하나를 시험삼아 보내면서 HalfOpen
+    - HalfOpen: 시험 요청 중. 성공하면 Closed, 실패하면 다시 Open
+      노드가 바빠서 거절(429)했거나 시험 요청이 대기 시간 안에 끝나지 않으면(취소된 요청 등) 결과 없이 다시 Open
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakerState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+impl BreakerState {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BreakerState::Closed => "healthy",
+            BreakerState::Open => "open",
+            BreakerState::HalfOpen => "half-open",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct NodeHealth {
+    state: BreakerState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    /* HalfOpen 이 된 시각 - 시험 요청이 끝나지 않은 채 남는 것을 막기 위해 */
+    probe_started_at: Option<Instant>,
+    last_error: Option<String>,
+}
+
+#[derive(Debug, Getters)]
+#[getset(get = "pub(crate)")]
+pub(crate) struct EsNode {
+    url: Url,
+    client: EsClient,
+    #[getset(skip)]
+    health: Mutex<NodeHealth>,
+}
+
+impl EsNode {
+    pub(crate) fn new(url: Url, client: EsClient) -> Self {
+        Self {
+            url,
+            client,
+            health: Mutex::new(NodeHealth {
+                state: BreakerState::Closed,
+                consecutive_failures: 0,
+                opened_at: None,
+                probe_started_at: None,
+                last_error: None,
+            }),
+        }
+    }
+
+    fn health(&self) -> MutexGuard<'_, NodeHealth> {
+        /* 상태값만 들어있으므로 poison 되어도 그대로 사용 */
+        self.health.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    #[doc = "현재 circuit breaker 상태"]
+    pub(crate) fn state(&self) -> BreakerState {
+        self.health().state
+    }
+
+    #[doc = "차단 대기 시간이 지난 노드면 HalfOpen 으로 바꾸고 true 를 반환 - 시험 요청은 한번에 하나만 보낸다."]
+    /// 대기 시간이 지나도록 결과가 기록되지 않은 시험 요청은 끝난 것으로 보고 다시 Open 으로 돌린다.
+    pub(crate) fn try_begin_probe(&self, open_duration: Duration) -> bool {
+        let mut health: MutexGuard<'_, NodeHealth> = self.health();
+
+        let probe_timed_out: bool = health
+            .probe_started_at
+            .is_none_or(|probe_started_at| probe_started_at.elapsed() >= open_duration);
+
+        if health.state == BreakerState::HalfOpen && probe_timed_out {
+            warn!(
+                "[EsNode] Probe to Elasticsearch node {} did not finish, blocking it again",
+                self.url
+            );
+            health.state = BreakerState::Open;
+            health.opened_at = Some(Instant::now());
+            health.probe_started_at = None;
+        }
+
+        let cooled_down: bool = health
+            .opened_at
+            .is_none_or(|opened_at| opened_at.elapsed() >= open_duration);
+
+        if health.state == BreakerState::Open && cooled_down {
+            health.state = BreakerState::HalfOpen;
+            health.probe_started_at = Some(Instant::now());
+            info!("[EsNode] Probing Elasticsearch node {}", self.url);
+            true
+        } else {
+            false
+        }
+    }
+
+    #[doc = "요청 성공을 기록 - 차단 중이던 노드는 다시 정상으로 돌린다."]
+    pub(crate) fn record_success(&self) {
+        let mut health: MutexGuard<'_, NodeHealth> = self.health();
+
+        if health.state != BreakerState::Closed {
+            info!("[EsNode] Elasticsearch node {} recovered", self.url);
+        }
+
+        health.state = BreakerState::Closed;
+        health.consecutive_failures = 0;
+        health.opened_at = None;
+        health.probe_started_at = None;
+    }
+
+    #[doc = "노드가 바빠서 거절(429)한 것을 기록 - 장애는 아니지만 시험 요청이었으면 결과를 알 수 없으므로 다시 차단한다."]
+    pub(crate) fn record_busy(&self) {
+        let mut health: MutexGuard<'_, NodeHealth> = self.health();
+
+        if health.state == BreakerState::HalfOpen {
+            health.state = BreakerState::Open;
+            health.opened_at = Some(Instant::now());
+            health.probe_started_at = None;
+        }
+    }
+
+    #[doc = "요청 실패를 기록 - 연속 실패가 기준치에 도달하거나 시험 요청이 실패하면 차단한다."]
+    pub(crate) fn record_failure(&self, err: &anyhow::Error, failure_threshold: u32) {
+        let mut health: MutexGuard<'_, NodeHealth> = self.health();
+
+        health.consecutive_failures = health.consecutive_failures.saturating_add(1);
+        health.last_error = Some(format!("{}", err));
+
+        let should_open: bool = match health.state {
+            BreakerState::Closed => health.consecutive_failures >= failure_threshold,
+            BreakerState::Open | BreakerState::HalfOpen => true,
+        };
+
+        if should_open {
+            if health.state != BreakerState::Open {
+                error!(
+                    "[EsNode] Elasticsearch node {} is unavailable after {} consecutive failure(s): {:?}",
+                    self.url, health.consecutive_failures, err
+                );
+            }
+
+            health.state = BreakerState::Open;
+            health.opened_at = Some(Instant::now());
+            health.probe_started_at = None;
+        }
+    }
+
+    #[doc = "진단용 노드 상태를 만들어주는 함수"]
+    pub(crate) fn status(&self, open_duration: Duration) -> EsNodeStatus {
+        let health: MutexGuard<'_, NodeHealth> = self.health();
+
+        let retry_in_sec: Option<u64> = match health.state {
+            BreakerState::Open => health
+                .opened_at
+                .map(|opened_at| open_duration.saturating_sub(opened_at.elapsed()).as_secs()),
+            _ => None,
+        };
+
+        EsNodeStatus::new(
+            self.url.to_string(),
+            health.state.name().to_string(),
+            health.consecutive_failures,
+            health.last_error.clone(),
+            retry_in_sec,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPEN_DURATION: Duration = Duration::from_millis(50);
+
+    fn open_node() -> EsNode {
+        let node: EsNode = EsNode::new(
+            Url::parse("http://127.0.0.1:9200").unwrap(),
+            EsClient::new(Elasticsearch::default()),
+        );
+        node.record_failure(&anyhow!("connection refused"), 1);
+        node
+    }
+
+    #[test]
+    fn busy_probe_returns_to_open() {
+        let node: EsNode = open_node();
+        std::thread::sleep(OPEN_DURATION);
+
+        assert!(node.try_begin_probe(OPEN_DURATION));
+        assert_eq!(node.state(), BreakerState::HalfOpen);
+
+        node.record_busy();
+        assert_eq!(node.state(), BreakerState::Open);
+        /* 새로 차단된 시각부터 다시 대기 */
+        assert!(!node.try_begin_probe(OPEN_DURATION));
+
+        std::thread::sleep(OPEN_DURATION);
+        assert!(node.try_begin_probe(OPEN_DURATION));
+    }
+
+    #[test]
+    fn unfinished_probe_returns_to_open_after_timeout() {
+        let node: EsNode = open_node();
+        std::thread::sleep(OPEN_DURATION);
+
+        assert!(node.try_begin_probe(OPEN_DURATION));
+        /* 시험 요청 중에는 다른 시험 요청을 보내지 않는다. */
+        assert!(!node.try_begin_probe(OPEN_DURATION));
+        assert_eq!(node.state(), BreakerState::HalfOpen);
+
+        /* 결과가 기록되지 않은 채 대기 시간이 지나면 다시 Open */
+        std::thread::sleep(OPEN_DURATION);
+        assert!(!node.try_begin_probe(OPEN_DURATION));
+        assert_eq!(node.state(), BreakerState::Open);
+
+        std::thread::sleep(OPEN_DURATION);
+        assert!(node.try_begin_probe(OPEN_DURATION));
+    }
+
+    #[test]
+    fn probe_result_closes_or_opens_breaker() {
+        let node: EsNode = open_node();
+        std::thread::sleep(OPEN_DURATION);
+
+        assert!(node.try_begin_probe(OPEN_DURATION));
+        node.record_failure(&anyhow!("connection refused"), 3);
+        assert_eq!(node.state(), BreakerState::Open);
+
+        std::thread::sleep(OPEN_DURATION);
+        assert!(node.try_begin_probe(OPEN_DURATION));
+        node.record_success();
+        assert_eq!(node.state(), BreakerState::Closed);
+    }
+}
@@ -1,35 +1,82 @@
 use crate::common::*;
 
-use crate::utils_modules::io_utils::*;
+use crate::errors::app_error::*;
 
+use crate::model::bulk_operation::*;
 use crate::model::elastic_server_config::*;
+use crate::model::es_node_status::*;
 use crate::model::total_config::*;
 
+use crate::repository::es_node::*;
+
 use crate::traits::repository_traits::es_repository_trait::*;
 
-static ELASTICSEARCH_CONN_SEMAPHORE_POOL: once_lazy<Vec<Arc<EsRepositoryPub>>> = once_lazy::new(
-    || {
-        let config: Arc<ElasticServerConfig> = get_elasticsearch_config_info();
-        let pool_cnt: i32 = *config.elastic_pool_cnt();
-        let es_host: &Vec<String> = config.elastic_host();
-        let es_id: String = config.elastic_id().clone().unwrap_or(String::from(""));
-        let es_pw: String = config.elastic_pw().clone().unwrap_or(String::from(""));
-
-        (0..pool_cnt)
-        .map(|_| {
-            Arc::new(
-                EsRepositoryPub::new(es_host.clone(), &es_id, &es_pw)
-                    .expect("[Error][ELASTICSEARCH_CONN_SEMA_POOL] Failed to create Elasticsearch client"),
-            )
+/*
+    노드별 상태(circuit breaker)를 공유해야 하므로 클라이언트는 클러스터마다 하나만 만들고,
+    동시 요청 수는 클러스터별 semaphore 로 제한 (한 클러스터가 느려도 다른 클러스터 요청은 막히지 않도록)
+*/
+static ELASTICSEARCH_CLUSTERS: OnceCell<HashMap<String, EsClusterPool>> = OnceCell::new();
+
+#[doc = "설정된 클러스터별 Elasticsearch 클라이언트를 만들어주는 함수 - main() 에서 한번만 호출"]
+pub fn initialize_elastic_clusters() -> Result<(), AppError> {
+    let cluster_pools: HashMap<String, EsClusterPool> = get_cluster_configs_info()
+        .iter()
+        .map(|config| {
+            let pool: EsClusterPool = EsClusterPool::new(Arc::clone(config)).map_err(|e| {
+                AppError::Config(format!(
+                    "Failed to create Elasticsearch client for '{}': {:#}",
+                    config.elastic_cluster_name(),
+                    e
+                ))
+            })?;
+
+            Ok((config.elastic_cluster_name().to_string(), pool))
         })
-        .collect()
-    },
-);
+        .collect::<Result<_, AppError>>()?;
 
-static SEMAPHORE: once_lazy<Arc<Semaphore>> = once_lazy::new(|| {
-    let config: Arc<ElasticServerConfig> = get_elasticsearch_config_info();
-    Arc::new(Semaphore::new(*config.elastic_pool_cnt() as usize))
-});
+    ELASTICSEARCH_CLUSTERS.set(cluster_pools).map_err(|_| {
+        AppError::Config(String::from(
+            "Elasticsearch clients are already initialized",
+        ))
+    })
+}
+
+#[doc = "클러스터 하나의 리포지토리와 동시 요청 제한(semaphore)"]
+#[derive(Debug)]
+struct EsClusterPool {
+    repo: Arc<EsRepositoryPub>,
+    semaphore: Arc<Semaphore>,
+}
+
+impl EsClusterPool {
+    fn new(config: Arc<ElasticServerConfig>) -> Result<Self, anyhow::Error> {
+        let semaphore: Arc<Semaphore> =
+            Arc::new(Semaphore::new(*config.elastic_pool_cnt() as usize));
+
+        Ok(Self {
+            repo: Arc::new(EsRepositoryPub::new(config)?),
+            semaphore,
+        })
+    }
+}
+
+#[doc = "이름으로 클러스터를 찾아주는 함수"]
+fn get_cluster_pool(cluster_name: &str) -> Result<&'static EsClusterPool, AppError> {
+    ELASTICSEARCH_CLUSTERS
+        .get()
+        .ok_or_else(|| {
+            AppError::Config(String::from(
+                "[get_cluster_pool] Elasticsearch clients are not initialized",
+            ))
+        })?
+        .get(cluster_name)
+        .ok_or_else(|| {
+            AppError::Config(format!(
+                "[get_cluster_pool] Unknown Elasticsearch cluster '{}'",
+                cluster_name
+            ))
+        })
+}
 
 #[derive(Debug)]
 pub struct ElasticConnGuard {
@@ -38,22 +85,24 @@ pub struct ElasticConnGuard {
 }
 
 impl ElasticConnGuard {
-    pub async fn new() -> Result<Self, anyhow::Error> {
+    pub async fn new(cluster_name: &str) -> Result<Self, anyhow::Error> {
+        let cluster_pool: &EsClusterPool = get_cluster_pool(cluster_name)?;
+
         info!(
-            "[ElasticConnGuard] Available permits: {}",
-            SEMAPHORE.available_permits()
+            "[ElasticConnGuard] {} available permits: {}",
+            cluster_name,
+            cluster_pool.semaphore.available_permits()
         );
-        let permit: OwnedSemaphorePermit = SEMAPHORE.clone().acquire_owned().await?;
+        let permit: OwnedSemaphorePermit = cluster_pool
+            .semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|e| AppError::Elasticsearch(format!("[ElasticConnGuard] {}", e)))?;
         info!("[ElasticConnGuard] Acquired semaphore");
 
-        /* 임의로 하나의 클라이언트를 가져옴 (랜덤 선택 가능) */
-        let client: Arc<EsRepositoryPub> = ELASTICSEARCH_CONN_SEMAPHORE_POOL
-            .choose(&mut rand::thread_rng())
-            .cloned()
-            .expect("[Error][EalsticConnGuard -> new] No clients available");
-
         Ok(Self {
-            client,
+            client: Arc::clone(&cluster_pool.repo),
             _permit: permit, /* Drop 시 자동 반환 */
         })
     }
@@ -73,14 +122,34 @@ impl Drop for ElasticConnGuard {
     }
 }
 
+#[doc = "기본 클러스터([elasticsearch]) 연결 - 알람, 이력, 발송 대기열 등 모니터링 인덱스용"]
 pub async fn get_elastic_guard_conn() -> Result<ElasticConnGuard, anyhow::Error> {
     info!("use elasticsearch connection");
-    ElasticConnGuard::new().await
+    let elastic_config: Arc<ElasticServerConfig> = get_elasticsearch_config_info();
+    ElasticConnGuard::new(elastic_config.elastic_cluster_name()).await
 }
 
-#[derive(Debug, Getters, Clone)]
+#[doc = "이름으로 지정한 클러스터 연결 - 색인 로그 / 색인 대상 인덱스가 있는 클러스터용"]
+pub async fn get_cluster_guard_conn(cluster_name: &str) -> Result<ElasticConnGuard, anyhow::Error> {
+    info!("use elasticsearch connection: {}", cluster_name);
+    ElasticConnGuard::new(cluster_name).await
+}
+
+#[doc = "semaphore 없이 클러스터별 Elasticsearch 리포지토리를 반환 - 요청이 밀려있어도 노드 상태를 확인할 수 있도록 진단용으로만 사용"]
+pub fn get_elastic_repos() -> Vec<Arc<EsRepositoryPub>> {
+    /* 설정 파일 순서대로 */
+    get_cluster_configs_info()
+        .iter()
+        .filter_map(|config| get_cluster_pool(config.elastic_cluster_name()).ok())
+        .map(|cluster_pool| Arc::clone(&cluster_pool.repo))
+        .collect()
+}
+
+#[derive(Debug)]
 pub struct EsRepositoryPub {
-    es_clients: Vec<EsClient>,
+    config: Arc<ElasticServerConfig>,
+    /* sniffing 으로 바뀔 수 있다. */
+    es_nodes: RwLock<Vec<Arc<EsNode>>>,
 }
 
 #[derive(Debug, Getters, Clone, new)]
@@ -89,53 +158,485 @@ pub(crate) struct EsClient {
 }
 
 impl EsRepositoryPub {
-    pub fn new(es_url_vec: Vec<String>, es_id: &str, es_pw: &str) -> Result<Self, anyhow::Error> {
-        let mut es_clients: Vec<EsClient> = Vec::new();
+    pub fn new(config: Arc<ElasticServerConfig>) -> Result<Self, anyhow::Error> {
+        let es_nodes: Vec<Arc<EsNode>> = config
+            .elastic_host()
+            .iter()
+            .map(|host| {
+                let es_url: Url = config.build_node_url(host)?;
+                Ok(Arc::new(build_es_node(&config, es_url)?))
+            })
+            .collect::<Result<_, anyhow::Error>>()?;
 
-        for url in es_url_vec {
-            let parse_url: String = format!("http://{}:{}@{}", es_id, encode(es_pw), url);
+        Ok(EsRepositoryPub {
+            config,
+            es_nodes: RwLock::new(es_nodes),
+        })
+    }
+
+    pub fn cluster_name(&self) -> &str {
+        self.config.elastic_cluster_name()
+    }
+
+    pub fn engine(&self) -> SearchEngine {
+        *self.config.elastic_engine()
+    }
+
+    #[doc = "클라이언트에 전용 API 가 없는 요청(OpenSearch 전용 API 등)을 경로로 직접 보내는 함수 - 재시도 / 노드 선택은 동일"]
+    /// # Arguments
+    /// * `method` - HTTP method
+    /// * `path` - 요청 경로 (ex. "/_plugins/_ism/policies/name")
+    /// * `query_string` - query string 파라미터
+    /// * `body` - 요청 본문 (JSON)
+    /// * `timeout` - 요청 timeout
+    ///
+    /// # Returns
+    /// * Result<Response, anyhow::Error>
+    async fn send_raw(
+        &self,
+        method: Method,
+        path: &str,
+        query_string: &[(&str, String)],
+        body: Option<&Value>,
+        timeout: Duration,
+    ) -> Result<Response, anyhow::Error> {
+        self.execute_on_any_node(Idempotency::Idempotent, |es_client| {
+            let body: Option<JsonBody<Value>> = body.cloned().map(JsonBody::new);
+            async move {
+                let response: Response = es_client
+                    .es_conn
+                    .send(
+                        method,
+                        path,
+                        HeaderMap::new(),
+                        Some(query_string),
+                        body,
+                        Some(timeout),
+                    )
+                    .await?;
+
+                Ok(response)
+            }
+        })
+        .await
+    }
+
+    #[doc = "OpenSearch ISM policy 를 만들거나 덮어쓰는 함수 - 이미 있으면 seq_no / primary_term 을 지정해야 수정된다."]
+    async fn put_ism_policy(
+        &self,
+        policy_name: &str,
+        policy: &Value,
+        timeout: Duration,
+    ) -> Result<Response, anyhow::Error> {
+        let path: String = format!("/_plugins/_ism/policies/{}", policy_name);
+
+        let current: Response = self
+            .send_raw(Method::Get, &path, &[], None, timeout)
+            .await?;
+
+        let query_string: Vec<(&str, String)> = if current.status_code().is_success() {
+            let current_body: Value = current.json::<Value>().await?;
+            vec![
+                ("if_seq_no", current_body["_seq_no"].to_string()),
+                ("if_primary_term", current_body["_primary_term"].to_string()),
+            ]
+        } else {
+            Vec::new()
+        };
+
+        self.send_raw(Method::Put, &path, &query_string, Some(policy), timeout)
+            .await
+    }
+
+    fn breaker_open_duration(&self) -> Duration {
+        Duration::from_secs(*self.config.elastic_breaker_open_sec())
+    }
+
+    fn current_nodes(&self) -> Vec<Arc<EsNode>> {
+        self.es_nodes
+            .read()
+            .map(|nodes| nodes.clone())
+            .unwrap_or_else(|e| e.into_inner().clone())
+    }
 
-            let es_url: Url = Url::parse(&parse_url)?;
-            let conn_pool: SingleNodeConnectionPool = SingleNodeConnectionPool::new(es_url);
-            let transport: EsTransport = TransportBuilder::new(conn_pool)
-                .timeout(Duration::new(5, 0))
-                .build()?;
+    #[doc = "요청을 보낼 노드 순서를 정해주는 함수 - 시험 요청(half-open) 노드, 정상 노드(랜덤), 차단된 노드 순"]
+    fn select_nodes(&self) -> Vec<Arc<EsNode>> {
+        let open_duration: Duration = self.breaker_open_duration();
 
-            let elastic_conn: Elasticsearch = Elasticsearch::new(transport);
-            let es_client: EsClient = EsClient::new(elastic_conn);
+        let mut probe_nodes: Vec<Arc<EsNode>> = Vec::new();
+        let mut healthy_nodes: Vec<Arc<EsNode>> = Vec::new();
+        let mut blocked_nodes: Vec<Arc<EsNode>> = Vec::new();
 
-            es_clients.push(es_client);
+        for node in self.current_nodes() {
+            if node.state() == BreakerState::Closed {
+                healthy_nodes.push(node);
+            } else if node.try_begin_probe(open_duration) {
+                probe_nodes.push(node);
+            } else {
+                blocked_nodes.push(node);
+            }
         }
 
-        Ok(EsRepositoryPub { es_clients })
+        let mut rng: StdRng = StdRng::from_entropy();
+        healthy_nodes.shuffle(&mut rng);
+        blocked_nodes.shuffle(&mut rng);
+
+        /* 모든 노드가 차단된 경우에도 요청은 시도한다. */
+        probe_nodes
+            .into_iter()
+            .chain(healthy_nodes)
+            .chain(blocked_nodes)
+            .collect()
     }
 
     #[doc = "Common logic: common node failure handling and node selection"]
-    async fn execute_on_any_node<F, Fut>(&self, operation: F) -> Result<Response, anyhow::Error>
+    /// 연결 실패 / 429 / 502 / 503 / 504 응답이면 backoff 후 다음 노드로 재시도한다.
+    /// 멱등이 아닌 요청은 연결 실패 / 429 / 503 일 때만 재시도한다. (timeout, 502, 504 는 이미 반영됐을 수 있음)
+    ///
+    /// # Arguments
+    /// * `idempotency` - 요청을 다시 보내도 결과가 같은지
+    /// * `operation` - 노드 클라이언트로 요청을 보내는 함수
+    ///
+    /// # Returns
+    /// * Result<Response, anyhow::Error>
+    async fn execute_on_any_node<F, Fut>(
+        &self,
+        idempotency: Idempotency,
+        operation: F,
+    ) -> Result<Response, anyhow::Error>
     where
         F: Fn(EsClient) -> Fut + Send + Sync,
         Fut: Future<Output = Result<Response, anyhow::Error>> + Send,
     {
+        let retry_config: &EsRetryConfig = self.config.elastic_retry();
+        let failure_threshold: u32 = *self.config.elastic_breaker_failure_threshold();
+        let max_attempts: u32 = (*retry_config.max_attempts()).max(1);
+
+        let es_nodes: Vec<Arc<EsNode>> = self.select_nodes();
+
+        if es_nodes.is_empty() {
+            return Err(anyhow!(AppError::Elasticsearch(format!(
+                "No Elasticsearch nodes available in '{}'",
+                self.cluster_name()
+            ))));
+        }
+
         let mut last_error: Option<anyhow::Error> = None;
+        let mut attempt_cnt: u32 = 0;
 
-        let mut rng: StdRng = StdRng::from_entropy();
-        let mut shuffled_clients = self.es_clients.clone();
-        shuffled_clients.shuffle(&mut rng);
+        for attempt in 0..max_attempts {
+            if attempt > 0 {
+                sleep(retry_config.backoff(attempt)).await;
+            }
+
+            let node: &Arc<EsNode> = &es_nodes[attempt as usize % es_nodes.len()];
+            attempt_cnt += 1;
+
+            let retryable: bool = match operation(node.client().clone()).await {
+                Ok(response) if is_retryable_status(response.status_code().as_u16()) => {
+                    let status_code: u16 = response.status_code().as_u16();
+                    let err: anyhow::Error =
+                        anyhow!("{} responded with status code {}", node.url(), status_code);
 
-        for es_client in shuffled_clients {
-            match operation(es_client).await {
-                Ok(response) => return Ok(response),
+                    /* 429 는 노드가 바쁜 것이므로 노드 장애로 보지 않는다. (시험 요청이었으면 다시 차단) */
+                    if status_code == 429 {
+                        node.record_busy();
+                    } else {
+                        node.record_failure(&err, failure_threshold);
+                    }
+
+                    info!(
+                        "[EsRepositoryPub->execute_on_any_node] attempt {}/{} failed: {}",
+                        attempt + 1,
+                        max_attempts,
+                        err
+                    );
+                    last_error = Some(err);
+                    idempotency.allows_retry_status(status_code)
+                }
+                Ok(response) => {
+                    node.record_success();
+                    return Ok(response);
+                }
                 Err(err) => {
+                    node.record_failure(&err, failure_threshold);
+
+                    info!(
+                        "[EsRepositoryPub->execute_on_any_node] attempt {}/{} failed on {}: {:?}",
+                        attempt + 1,
+                        max_attempts,
+                        node.url(),
+                        err
+                    );
+                    let retryable: bool = idempotency.allows_retry_error(&err);
                     last_error = Some(err);
+                    retryable
                 }
+            };
+
+            if !retryable {
+                break;
             }
         }
 
-        Err(anyhow::anyhow!(
-            "All Elasticsearch nodes failed. Last error: {:?}",
+        Err(anyhow!(AppError::Elasticsearch(format!(
+            "All Elasticsearch attempts failed on '{}' ({} attempt(s)). Last error: {:?}",
+            self.cluster_name(),
+            attempt_cnt,
             last_error
-        ))
+        ))))
+    }
+
+    #[doc = "노드별 연결 상태를 반환해주는 함수 (진단용)"]
+    pub fn node_states(&self) -> Vec<EsNodeStatus> {
+        let open_duration: Duration = self.breaker_open_duration();
+
+        self.current_nodes()
+            .iter()
+            .map(|node| {
+                let mut node_status: EsNodeStatus = node.status(open_duration);
+                node_status.cluster_name = self.cluster_name().to_string();
+                node_status
+            })
+            .collect()
+    }
+
+    #[doc = "클러스터의 노드 목록(_nodes/http)을 가져와서 요청 대상 노드를 갱신해주는 함수"]
+    /// # Returns
+    /// * Result<usize, anyhow::Error> - 갱신된 노드 개수
+    pub async fn sniff_nodes(&self) -> Result<usize, anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().admin();
+
+        let response: Response = self
+            .execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .nodes()
+                    .info(NodesInfoParts::Metric(&["http"]))
+                    .request_timeout(timeout)
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        if !response.status_code().is_success() {
+            let error_body: String = response.text().await?;
+            return Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_sniff_nodes()] response status is failed: {:?}",
+                error_body
+            ))));
+        }
+
+        let response_body: Value = response.json::<Value>().await?;
+
+        let sniffed_urls: Vec<Url> = response_body
+            .get("nodes")
+            .and_then(|nodes| nodes.as_object())
+            .map(|nodes| {
+                nodes
+                    .values()
+                    .filter_map(|node| node.pointer("/http/publish_address"))
+                    .filter_map(|address| address.as_str())
+                    .map(|address| self.config.build_node_url(&parse_publish_address(address)))
+                    .collect::<Result<Vec<Url>, anyhow::Error>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        if sniffed_urls.is_empty() {
+            return Err(anyhow!(
+                "[EsRepositoryPub->sniff_nodes] No HTTP nodes found in _nodes response"
+            ));
+        }
+
+        /* 이미 알고 있는 노드는 상태(circuit breaker)를 유지한다. */
+        let current_nodes: Vec<Arc<EsNode>> = self.current_nodes();
+        let mut sniffed_nodes: Vec<Arc<EsNode>> = Vec::new();
+
+        for url in sniffed_urls {
+            match current_nodes.iter().find(|node| *node.url() == url) {
+                Some(node) => sniffed_nodes.push(Arc::clone(node)),
+                None => {
+                    /* 지문 확인은 blocking I/O 이므로 별도 스레드에서 */
+                    let config: Arc<ElasticServerConfig> = Arc::clone(&self.config);
+                    let node: EsNode =
+                        tokio::task::spawn_blocking(move || build_es_node(&config, url)).await??;
+
+                    info!(
+                        "[EsRepositoryPub->sniff_nodes] Discovered node {}",
+                        node.url()
+                    );
+                    sniffed_nodes.push(Arc::new(node));
+                }
+            }
+        }
+
+        let node_cnt: usize = sniffed_nodes.len();
+
+        match self.es_nodes.write() {
+            Ok(mut nodes) => *nodes = sniffed_nodes,
+            Err(e) => *e.into_inner() = sniffed_nodes,
+        }
+
+        Ok(node_cnt)
+    }
+}
+
+#[doc = "노드 URL 하나에 대한 Elasticsearch 클라이언트를 만들어주는 함수 (인증 / 인증서 설정 포함)"]
+/// # Arguments
+/// * `config` - Elasticsearch 설정
+/// * `es_url` - 노드 URL
+///
+/// # Returns
+/// * Result<EsNode, anyhow::Error>
+fn build_es_node(config: &ElasticServerConfig, es_url: Url) -> Result<EsNode, anyhow::Error> {
+    /* 지문이 지정된 경우 서버 인증서를 직접 확인한 뒤 CA 검증은 생략한다. */
+    let cert_validation: Option<CertificateValidation> = match config.elastic_cert_fingerprint() {
+        Some(fingerprint) => {
+            verify_cert_fingerprint(&es_url, fingerprint)?;
+            Some(CertificateValidation::None)
+        }
+        None => config.load_ca_validation()?,
+    };
+
+    let conn_pool: SingleNodeConnectionPool = SingleNodeConnectionPool::new(es_url.clone());
+    let mut transport_builder: TransportBuilder = TransportBuilder::new(conn_pool);
+
+    if let Some(auth_header) = config.build_auth_header()? {
+        transport_builder = transport_builder.header(AUTHORIZATION, auth_header);
+    }
+
+    if let Some(client_cert) = config.load_client_certificate()? {
+        transport_builder = transport_builder.auth(client_cert.into());
+    }
+
+    if let Some(cert_validation) = cert_validation {
+        transport_builder = transport_builder.cert_validation(cert_validation);
     }
+
+    let transport: EsTransport = transport_builder.build()?;
+    let elastic_conn: Elasticsearch = Elasticsearch::new(transport);
+
+    Ok(EsNode::new(es_url, EsClient::new(elastic_conn)))
+}
+
+#[doc = "요청을 다시 보내도 결과가 같은지 - 재시도할 수 있는 실패가 달라진다."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Idempotency {
+    /* 조회 / 같은 값으로 덮어쓰기 - 연결 실패, 429, 502, 503, 504 모두 재시도 */
+    Idempotent,
+    /*
+        스크립트 갱신 / 자동 id 생성 / update_by_query - timeout, 502, 504 는 이미 반영됐을 수 있으므로
+        요청이 처리되지 않은 것이 확실한 연결 실패와 429, 503 만 재시도
+    */
+    NonIdempotent,
+}
+
+impl Idempotency {
+    fn allows_retry_status(&self, status_code: u16) -> bool {
+        match self {
+            Self::Idempotent => is_retryable_status(status_code),
+            Self::NonIdempotent => matches!(status_code, 429 | 503),
+        }
+    }
+
+    fn allows_retry_error(&self, err: &anyhow::Error) -> bool {
+        match self {
+            Self::Idempotent => true,
+            Self::NonIdempotent => is_connect_error(err),
+        }
+    }
+}
+
+#[doc = "다른 노드로 재시도할 응답 상태 코드인지 확인 - 429(rejected execution), 502, 503, 504"]
+fn is_retryable_status(status_code: u16) -> bool {
+    matches!(status_code, 429 | 502 | 503 | 504)
+}
+
+#[doc = "요청을 보내기 전에 연결 단계에서 실패한 에러인지 확인 (요청이 노드에 전달되지 않음)"]
+fn is_connect_error(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        cause
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(reqwest::Error::is_connect)
+    })
+}
+
+#[doc = "_nodes 응답의 publish_address 를 'host:port' 로 변환 - 'hostname/ip:port' 형식이면 hostname 을 사용"]
+fn parse_publish_address(publish_address: &str) -> String {
+    match publish_address.split_once('/') {
+        Some((hostname, ip_port)) => {
+            let port: &str = ip_port.rsplit(':').next().unwrap_or_default();
+            format!("{}:{}", hostname, port)
+        }
+        None => publish_address.to_string(),
+    }
+}
+
+#[doc = "서버 인증서의 SHA-256 지문이 설정값과 같은지 TLS 연결로 확인해주는 함수"]
+/// # Arguments
+/// * `es_url` - 확인할 Elasticsearch 노드 URL (https)
+/// * `fingerprint` - 기대하는 SHA-256 지문 (hex, ':' 구분자 허용)
+///
+/// # Returns
+/// * Result<(), anyhow::Error>
+fn verify_cert_fingerprint(es_url: &Url, fingerprint: &str) -> Result<(), anyhow::Error> {
+    if es_url.scheme() != "https" {
+        return Err(anyhow!(
+            "[verify_cert_fingerprint] elastic_cert_fingerprint requires https: {}",
+            es_url
+        ));
+    }
+
+    let host: &str = es_url
+        .host_str()
+        .ok_or_else(|| anyhow!("[verify_cert_fingerprint] Missing host: {}", es_url))?;
+    let port: u16 = es_url.port_or_known_default().unwrap_or(443);
+
+    let addr: std::net::SocketAddr = std::net::ToSocketAddrs::to_socket_addrs(&(host, port))?
+        .next()
+        .ok_or_else(|| anyhow!("[verify_cert_fingerprint] Cannot resolve {}:{}", host, port))?;
+    let stream: std::net::TcpStream =
+        std::net::TcpStream::connect_timeout(&addr, Duration::new(5, 0))?;
+    stream.set_read_timeout(Some(Duration::new(5, 0)))?;
+    stream.set_write_timeout(Some(Duration::new(5, 0)))?;
+
+    /* 자체 서명 인증서도 받을 수 있도록 검증 없이 연결한 뒤 지문만 비교 */
+    let connector: TlsConnector = TlsConnector::builder()
+        .danger_accept_invalid_certs(true)
+        .danger_accept_invalid_hostnames(true)
+        .build()?;
+    let tls_stream = connector.connect(host, stream).map_err(|e| {
+        anyhow!(
+            "[verify_cert_fingerprint] TLS handshake with {} failed: {}",
+            es_url,
+            e
+        )
+    })?;
+
+    let peer_cert_der: Vec<u8> = tls_stream
+        .peer_certificate()?
+        .ok_or_else(|| anyhow!("[verify_cert_fingerprint] {} sent no certificate", es_url))?
+        .to_der()?;
+
+    let actual: String = Sha256::digest(&peer_cert_der)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    let expected: String = fingerprint.replace(':', "").to_lowercase();
+
+    if actual != expected {
+        return Err(anyhow!(
+            "[verify_cert_fingerprint] Certificate fingerprint mismatch for {}: expected {}, got {}",
+            es_url,
+            expected,
+            actual
+        ));
+    }
+
+    Ok(())
 }
 
 #[async_trait]
@@ -146,12 +647,16 @@ impl EsRepository for EsRepositoryPub {
         es_query: &Value,
         index_name: &str,
     ) -> Result<Value, anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().search();
+
         let response = self
-            .execute_on_any_node(|es_client| async move {
+            .execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
                 let response = es_client
                     .es_conn
                     .search(SearchParts::Index(&[index_name]))
+                    .ignore_unavailable(true)
                     .body(es_query)
+                    .request_timeout(timeout)
                     .send()
                     .await?;
 
@@ -164,33 +669,576 @@ impl EsRepository for EsRepositoryPub {
             Ok(response_body)
         } else {
             let error_body: String = response.text().await?;
-            Err(anyhow!(
-                "[Elasticsearch Error][node_search_query()] response status is failed: {:?}",
+            Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_search_query()] response status is failed: {:?}",
                 error_body
-            ))
+            ))))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - count"]
+    async fn count(&self, es_query: &Value, index_name: &str) -> Result<u64, anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().search();
+
+        let response: Response = self
+            .execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .count(CountParts::Index(&[index_name]))
+                    .body(es_query)
+                    .request_timeout(timeout)
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            let response_body: Value = response.json::<Value>().await?;
+
+            response_body["count"].as_u64().ok_or_else(|| {
+                anyhow!(AppError::Elasticsearch(format!(
+                    "[node_count()] Missing 'count' field: {:?}",
+                    response_body
+                )))
+            })
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_count()] response status is failed: {:?}",
+                error_body
+            ))))
         }
     }
 
-    #[doc = "Function that EXECUTES elasticsearch queries - indexing struct"]
-    async fn post_query_struct<T: Serialize + Sync>(
+    #[doc = "Function that EXECUTES elasticsearch queries - open point in time"]
+    async fn open_point_in_time(
         &self,
-        param_struct: &T,
         index_name: &str,
-    ) -> Result<(), anyhow::Error> {
-        let struct_json: Value = convert_json_from_struct(param_struct)?;
-        self.post_query(&struct_json, index_name).await?;
+        keep_alive: &str,
+    ) -> Result<String, anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().search();
+
+        /* OpenSearch 는 PIT API 경로와 응답 필드(pit_id)가 다르다. */
+        let response: Response = match self.engine() {
+            SearchEngine::Elasticsearch => {
+                self.execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
+                    let response: Response = es_client
+                        .es_conn
+                        .open_point_in_time(OpenPointInTimeParts::Index(&[index_name]))
+                        .keep_alive(keep_alive)
+                        .ignore_unavailable(true)
+                        .request_timeout(timeout)
+                        .send()
+                        .await?;
 
-        Ok(())
+                    Ok(response)
+                })
+                .await?
+            }
+            SearchEngine::Opensearch => {
+                self.send_raw(
+                    Method::Post,
+                    &format!("/{}/_search/point_in_time", index_name),
+                    &[("keep_alive", keep_alive.to_string())],
+                    None,
+                    timeout,
+                )
+                .await?
+            }
+        };
+
+        if response.status_code().is_success() {
+            let response_body: Value = response.json::<Value>().await?;
+
+            response_body
+                .get("id")
+                .or(response_body.get("pit_id"))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    anyhow!(AppError::Elasticsearch(String::from(
+                        "[node_open_point_in_time()] Missing PIT id field"
+                    )))
+                })
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_open_point_in_time()] response status is failed: {:?}",
+                error_body
+            ))))
+        }
     }
 
-    #[doc = "Function that EXECUTES elasticsearch queries - indexing"]
-    async fn post_query(&self, document: &Value, index_name: &str) -> Result<(), anyhow::Error> {
-        let response = self
-            .execute_on_any_node(|es_client| async move {
-                let response = es_client
+    #[doc = "Function that EXECUTES elasticsearch queries - search with point in time (인덱스는 PIT 에 포함되어 있음)"]
+    async fn get_search_query_pit(&self, es_query: &Value) -> Result<Value, anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().search();
+
+        let response: Response = self
+            .execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .search(SearchParts::None)
+                    .body(es_query)
+                    .request_timeout(timeout)
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            let response_body: Value = response.json::<Value>().await?;
+            Ok(response_body)
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_search_query_pit()] response status is failed: {:?}",
+                error_body
+            ))))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - close point in time"]
+    async fn close_point_in_time(&self, pit_id: &str) -> Result<(), anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().search();
+
+        let response: Response = match self.engine() {
+            SearchEngine::Elasticsearch => {
+                let body: Value = json!({ "id": pit_id });
+                let body_ref: &Value = &body;
+
+                self.execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
+                    let response: Response = es_client
+                        .es_conn
+                        .close_point_in_time()
+                        .body(body_ref)
+                        .request_timeout(timeout)
+                        .send()
+                        .await?;
+
+                    Ok(response)
+                })
+                .await?
+            }
+            SearchEngine::Opensearch => {
+                self.send_raw(
+                    Method::Delete,
+                    "/_search/point_in_time",
+                    &[],
+                    Some(&json!({ "pit_id": [pit_id] })),
+                    timeout,
+                )
+                .await?
+            }
+        };
+
+        /* 이미 만료된 PIT 는 404 - 정리 목적이므로 성공으로 본다. */
+        if response.status_code().is_success() || response.status_code().as_u16() == 404 {
+            Ok(())
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_close_point_in_time()] response status is failed: {:?}",
+                error_body
+            ))))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - get document (없으면 None)"]
+    async fn get_document(
+        &self,
+        doc_id: &str,
+        index_name: &str,
+    ) -> Result<Option<Value>, anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().search();
+
+        let response: Response = self
+            .execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
+                let response: Response = es_client
                     .es_conn
-                    .index(IndexParts::Index(index_name))
+                    .get(GetParts::IndexId(index_name, doc_id))
+                    .request_timeout(timeout)
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        match response.status_code().as_u16() {
+            /* 인덱스가 없는 경우도 404 */
+            404 => Ok(None),
+            _ if response.status_code().is_success() => {
+                let response_body: Value = response.json::<Value>().await?;
+                Ok(Some(response_body))
+            }
+            _ => {
+                let error_body: String = response.text().await?;
+                Err(anyhow!(AppError::Elasticsearch(format!(
+                    "[node_get_document()] response status is failed: {:?}",
+                    error_body
+                ))))
+            }
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - conditional put (seq_no/primary_term 가 다르면 false)"]
+    async fn put_document_if(
+        &self,
+        doc_id: &str,
+        document: &Value,
+        index_name: &str,
+        if_seq_no_primary_term: Option<(i64, i64)>,
+    ) -> Result<bool, anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().write();
+
+        let response: Response = self
+            .execute_on_any_node(Idempotency::NonIdempotent, |es_client| async move {
+                let request = es_client
+                    .es_conn
+                    .index(IndexParts::IndexId(index_name, doc_id))
                     .body(document)
+                    .request_timeout(timeout);
+
+                /* 기존 문서가 없다고 본 경우에는 create - 그 사이 다른 인스턴스가 만들었으면 409 */
+                let request = match if_seq_no_primary_term {
+                    Some((seq_no, primary_term)) => {
+                        request.if_seq_no(seq_no).if_primary_term(primary_term)
+                    }
+                    None => request.op_type(OpType::Create),
+                };
+
+                let response: Response = request.send().await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        match response.status_code().as_u16() {
+            409 => Ok(false),
+            _ if response.status_code().is_success() => Ok(true),
+            _ => {
+                let error_body: String = response.text().await?;
+                Err(anyhow!(AppError::Elasticsearch(format!(
+                    "[node_put_document_if()] response status is failed: {:?}",
+                    error_body
+                ))))
+            }
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - scripted upsert (문서가 없으면 upsert_document 로 생성)"]
+    async fn upsert_document(
+        &self,
+        doc_id: &str,
+        script: &Value,
+        upsert_document: &Value,
+        index_name: &str,
+    ) -> Result<(), anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().write();
+        let body: Value = json!({
+            "script": script,
+            "upsert": upsert_document
+        });
+
+        let response: Response = self
+            .execute_on_any_node(Idempotency::NonIdempotent, |es_client| {
+                let body: &Value = &body;
+                async move {
+                    let response: Response = es_client
+                        .es_conn
+                        .update(UpdateParts::IndexId(index_name, doc_id))
+                        /* 같은 장애를 여러 인스턴스/작업이 동시에 갱신할 수 있다. */
+                        .retry_on_conflict(3)
+                        .body(body)
+                        .request_timeout(timeout)
+                        .send()
+                        .await?;
+
+                    Ok(response)
+                }
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            Ok(())
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_upsert_document()] response status is failed: {:?}",
+                error_body
+            ))))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - bulk (항목별 실패는 BulkResult 에 담아서 반환)"]
+    async fn bulk(
+        &self,
+        operations: &[BulkOperation],
+        index_name: &str,
+    ) -> Result<BulkResult, anyhow::Error> {
+        if operations.is_empty() {
+            return Ok(BulkResult::default());
+        }
+
+        let bulk_lines: Vec<Value> = operations
+            .iter()
+            .flat_map(|operation| operation.to_bulk_lines(index_name))
+            .collect();
+
+        let timeout: Duration = self.config.elastic_timeout().write();
+
+        /* 자동 id 로 만드는 문서가 있으면 다시 보냈을 때 중복 문서가 생긴다. */
+        let idempotency: Idempotency = if operations.iter().all(BulkOperation::is_idempotent) {
+            Idempotency::Idempotent
+        } else {
+            Idempotency::NonIdempotent
+        };
+
+        let response: Response = self
+            .execute_on_any_node(idempotency, |es_client| {
+                let body: Vec<JsonBody<Value>> =
+                    bulk_lines.iter().cloned().map(JsonBody::new).collect();
+                async move {
+                    let response: Response = es_client
+                        .es_conn
+                        .bulk(BulkParts::Index(index_name))
+                        .body(body)
+                        .request_timeout(timeout)
+                        .send()
+                        .await?;
+
+                    Ok(response)
+                }
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            let response_body: Value = response.json::<Value>().await?;
+            BulkResult::from_response(&response_body)
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_bulk()] response status is failed: {:?}",
+                error_body
+            ))))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - update by query"]
+    async fn update_by_query(
+        &self,
+        es_query: &Value,
+        index_name: &str,
+    ) -> Result<Value, anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().update_by_query();
+
+        let response: Response = self
+            .execute_on_any_node(Idempotency::NonIdempotent, |es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .update_by_query(UpdateByQueryParts::Index(&[index_name]))
+                    .conflicts(Conflicts::Proceed)
+                    .refresh(true)
+                    .body(es_query)
+                    .request_timeout(timeout)
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            let response_body: Value = response.json::<Value>().await?;
+            Ok(response_body)
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_update_by_query()] response status is failed: {:?}",
+                error_body
+            ))))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - delete by query"]
+    async fn delete_by_query(
+        &self,
+        es_query: &Value,
+        index_name: &str,
+    ) -> Result<Value, anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().update_by_query();
+
+        let response: Response = self
+            .execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .delete_by_query(DeleteByQueryParts::Index(&[index_name]))
+                    .conflicts(Conflicts::Proceed)
+                    .refresh(true)
+                    .body(es_query)
+                    .request_timeout(timeout)
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            let response_body: Value = response.json::<Value>().await?;
+            Ok(response_body)
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_delete_by_query()] response status is failed: {:?}",
+                error_body
+            ))))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - index exists"]
+    async fn index_exists(&self, index_name: &str) -> Result<bool, anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().admin();
+
+        let response: Response = self
+            .execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .indices()
+                    .exists(IndicesExistsParts::Index(&[index_name]))
+                    .request_timeout(timeout)
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(true),
+            404 => Ok(false),
+            status_code => Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_index_exists()] Unexpected status code: {}, Index: {}",
+                status_code, index_name
+            )))),
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - put index template"]
+    async fn put_index_template(
+        &self,
+        template_name: &str,
+        template: &Value,
+    ) -> Result<(), anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().admin();
+
+        let response: Response = self
+            .execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .indices()
+                    .put_index_template(IndicesPutIndexTemplateParts::Name(template_name))
+                    .body(template)
+                    .request_timeout(timeout)
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            Ok(())
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_put_index_template()] Failed to put template '{}': {:?}",
+                template_name, error_body
+            ))))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - put ILM policy"]
+    async fn put_lifecycle_policy(
+        &self,
+        policy_name: &str,
+        policy: &Value,
+    ) -> Result<(), anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().admin();
+
+        let response: Response = match self.engine() {
+            SearchEngine::Elasticsearch => {
+                self.execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
+                    let response: Response = es_client
+                        .es_conn
+                        .ilm()
+                        .put_lifecycle(IlmPutLifecycleParts::Policy(policy_name))
+                        .body(policy)
+                        .request_timeout(timeout)
+                        .send()
+                        .await?;
+
+                    Ok(response)
+                })
+                .await?
+            }
+            SearchEngine::Opensearch => self.put_ism_policy(policy_name, policy, timeout).await?,
+        };
+
+        if response.status_code().is_success() {
+            Ok(())
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_put_lifecycle_policy()] Failed to put policy '{}': {:?}",
+                policy_name, error_body
+            ))))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - data stream exists"]
+    async fn data_stream_exists(&self, data_stream_name: &str) -> Result<bool, anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().admin();
+
+        let response: Response = self
+            .execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .indices()
+                    .get_data_stream(IndicesGetDataStreamParts::Name(&[data_stream_name]))
+                    .request_timeout(timeout)
+                    .send()
+                    .await?;
+
+                Ok(response)
+            })
+            .await?;
+
+        match response.status_code().as_u16() {
+            200 => Ok(true),
+            404 => Ok(false),
+            status_code => Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_data_stream_exists()] Unexpected status code: {}, Data stream: {}",
+                status_code, data_stream_name
+            )))),
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - create data stream"]
+    async fn create_data_stream(&self, data_stream_name: &str) -> Result<(), anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().admin();
+
+        let response: Response = self
+            .execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .indices()
+                    .create_data_stream(IndicesCreateDataStreamParts::Name(data_stream_name))
+                    .request_timeout(timeout)
                     .send()
                     .await?;
 
@@ -199,27 +1247,61 @@ impl EsRepository for EsRepositoryPub {
             .await?;
 
         if response.status_code().is_success() {
+            info!("[{}] Data stream has been created", data_stream_name);
             Ok(())
         } else {
-            let error_message = format!("[Elasticsearch Error][node_post_query()] Failed to index document: Status Code: {}", response.status_code());
-            Err(anyhow!(error_message))
+            let error_body: String = response.text().await?;
+            Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_create_data_stream()] Failed to create data stream '{}': {:?}",
+                data_stream_name, error_body
+            ))))
         }
     }
 
-    #[doc = "Function that EXECUTES elasticsearch queries - delete"]
-    async fn delete_query(&self, doc_id: &str, index_name: &str) -> Result<(), anyhow::Error> {
+    #[doc = "Function that EXECUTES elasticsearch queries - get mapping (alias 면 실제 인덱스별로 반환)"]
+    async fn get_mapping(&self, index_name: &str) -> Result<Value, anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().admin();
+
         let response: Response = self
-            .execute_on_any_node(|es_client| async move {
+            .execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
                 let response: Response = es_client
                     .es_conn
-                    .delete(DeleteParts::IndexId(index_name, doc_id))
+                    .indices()
+                    .get_mapping(IndicesGetMappingParts::Index(&[index_name]))
+                    .request_timeout(timeout)
                     .send()
                     .await?;
 
-                info!(
-                    "[{}] document of [{}] Index has been erased",
-                    doc_id, index_name
-                );
+                Ok(response)
+            })
+            .await?;
+
+        if response.status_code().is_success() {
+            let response_body: Value = response.json::<Value>().await?;
+            Ok(response_body)
+        } else {
+            let error_body: String = response.text().await?;
+            Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_get_mapping()] Failed to get mapping of '{}': {:?}",
+                index_name, error_body
+            ))))
+        }
+    }
+
+    #[doc = "Function that EXECUTES elasticsearch queries - put mapping"]
+    async fn put_mapping(&self, index_name: &str, mapping: &Value) -> Result<(), anyhow::Error> {
+        let timeout: Duration = self.config.elastic_timeout().admin();
+
+        let response: Response = self
+            .execute_on_any_node(Idempotency::Idempotent, |es_client| async move {
+                let response: Response = es_client
+                    .es_conn
+                    .indices()
+                    .put_mapping(IndicesPutMappingParts::Index(&[index_name]))
+                    .body(mapping)
+                    .request_timeout(timeout)
+                    .send()
+                    .await?;
 
                 Ok(response)
             })
@@ -228,8 +1310,79 @@ impl EsRepository for EsRepositoryPub {
         if response.status_code().is_success() {
             Ok(())
         } else {
-            let error_message = format!("[Elasticsearch Error][node_delete_query()] Failed to delete document: Status Code: {}, Document ID: {}", response.status_code(), doc_id);
-            Err(anyhow!(error_message))
+            let error_body: String = response.text().await?;
+            Err(anyhow!(AppError::Elasticsearch(format!(
+                "[node_put_mapping()] Failed to update mapping of '{}': {:?}",
+                index_name, error_body
+            ))))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_publish_address_prefers_hostname() {
+        assert_eq!(
+            parse_publish_address("es-node-01/10.0.0.11:9200"),
+            "es-node-01:9200"
+        );
+    }
+
+    #[test]
+    fn parse_publish_address_without_hostname() {
+        assert_eq!(parse_publish_address("10.0.0.11:9200"), "10.0.0.11:9200");
+    }
+
+    #[test]
+    fn parse_publish_address_with_ipv6() {
+        assert_eq!(
+            parse_publish_address("es-node-01/[::1]:9200"),
+            "es-node-01:9200"
+        );
+        assert_eq!(parse_publish_address("[::1]:9200"), "[::1]:9200");
+    }
+
+    #[test]
+    fn non_idempotent_requests_retry_only_when_not_applied() {
+        for status_code in [429, 502, 503, 504] {
+            assert!(Idempotency::Idempotent.allows_retry_status(status_code));
+        }
+
+        assert!(Idempotency::NonIdempotent.allows_retry_status(429));
+        assert!(Idempotency::NonIdempotent.allows_retry_status(503));
+        assert!(!Idempotency::NonIdempotent.allows_retry_status(502));
+        assert!(!Idempotency::NonIdempotent.allows_retry_status(504));
+
+        let timeout_err: anyhow::Error = anyhow!("operation timed out");
+        assert!(Idempotency::Idempotent.allows_retry_error(&timeout_err));
+        assert!(!Idempotency::NonIdempotent.allows_retry_error(&timeout_err));
+    }
+
+    #[tokio::test]
+    async fn connect_error_is_retryable_for_non_idempotent_requests() {
+        /* 아무도 listen 하지 않는 포트 - 연결 단계에서 실패 */
+        let listener: std::net::TcpListener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr: std::net::SocketAddr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let err: anyhow::Error =
+            anyhow!(reqwest::get(format!("http://{}", addr)).await.unwrap_err());
+
+        assert!(is_connect_error(&err));
+        assert!(Idempotency::NonIdempotent.allows_retry_error(&err));
+    }
+
+    #[test]
+    fn is_retryable_status_only_for_overload_and_gateway_errors() {
+        for status_code in [429, 502, 503, 504] {
+            assert!(is_retryable_status(status_code));
+        }
+
+        for status_code in [200, 400, 404, 409, 500] {
+            assert!(!is_retryable_status(status_code));
         }
     }
 }
@@ -1,3 +1,7 @@
+pub mod email_repository;
+pub mod es_node;
 pub mod es_repository;
+pub mod procedure_notifier_repository;
+pub mod smtp_repository;
 pub mod sqlserver_repository;
 pub mod telegram_repository;
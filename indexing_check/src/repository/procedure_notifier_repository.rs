@@ -0,0 +1,39 @@
+use crate::common::*;
+
+use crate::model::{email_struct::*, procedure_config::*};
+
+use crate::repository::sqlserver_repository::*;
+
+use crate::traits::repository_traits::{email_repository_trait::*, sqlserver_repository_trait::*};
+
+#[derive(new)]
+pub struct ProcedureNotifierRepositoryPub {
+    sql_repo: Arc<SqlServerRepositoryPub>,
+    procedure: ProcedureConfig,
+}
+
+#[async_trait]
+impl EmailRepository for ProcedureNotifierRepositoryPub {
+    #[doc = "설정된 SQL Server 프로시저(iMailer, SMS, 알림톡 등)로 알림을 보내주는 함수"]
+    /// # Arguments
+    /// * `receiver` - 수신자 (이메일 주소, 전화번호 등)
+    /// * `email` - 제목/본문 정보
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    async fn send_email(&self, receiver: &str, email: &EmailStruct) -> Result<(), anyhow::Error> {
+        let param_sources: HashMap<&str, &str> = HashMap::from([
+            ("recipient", receiver),
+            ("subject", email.subject().as_str()),
+            ("html_content", email.html_form().as_str()),
+            ("text_content", email.text_form().as_str()),
+            ("summary", email.summary_form().as_str()),
+        ]);
+
+        self.sql_repo
+            .execute_procedure(&self.procedure, &param_sources)
+            .await?;
+
+        Ok(())
+    }
+}
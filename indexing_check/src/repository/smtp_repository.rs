@@ -0,0 +1,279 @@
+use crate::common::*;
+
+use crate::errors::app_error::*;
+
+use crate::model::{email_struct::*, smtp_config::*, total_config::*};
+
+use crate::traits::repository_traits::email_repository_trait::*;
+
+#[derive(Clone)]
+pub struct SmtpRepositoryPub {
+    mailer: AsyncSmtpTransport<Tokio1Executor>,
+    sender: Mailbox,
+}
+
+impl SmtpRepositoryPub {
+    #[doc = "SMTP config 정보로 메일 전송 클라이언트를 만들어주는 함수"]
+    pub fn new(smtp_config: &SmtpConfig) -> Result<Self, anyhow::Error> {
+        let host: &str = smtp_config.smtp_name();
+
+        let builder: AsyncSmtpTransportBuilder = match smtp_config.smtp_security().as_str() {
+            "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(host)?,
+            "starttls" => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)?,
+            "none" => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host),
+            other => {
+                return Err(anyhow!(
+                "[SmtpRepositoryPub->new] Unsupported smtp_security '{}' (use tls/starttls/none)",
+                other
+            ))
+            }
+        };
+
+        let builder: AsyncSmtpTransportBuilder = match smtp_config.smtp_port() {
+            Some(port) => builder.port(*port),
+            None => builder,
+        };
+
+        let builder: AsyncSmtpTransportBuilder = if smtp_config.credential_id().is_empty() {
+            builder
+        } else {
+            builder.credentials(SmtpCredentials::new(
+                smtp_config.credential_id().to_string(),
+                smtp_config.credential_pw().expose().to_string(),
+            ))
+        };
+
+        let sender_email: &str = smtp_config
+            .sender_email()
+            .as_deref()
+            .unwrap_or(smtp_config.credential_id().as_str());
+
+        let sender: Mailbox = sender_email.parse().map_err(|e| {
+            anyhow!(
+                "[SmtpRepositoryPub->new] Invalid sender address '{}': {}",
+                sender_email,
+                e
+            )
+        })?;
+
+        Ok(Self {
+            mailer: builder.build(),
+            sender,
+        })
+    }
+}
+
+#[doc = "[smtp] config 로 SMTP 클라이언트를 초기화해주는 함수"]
+pub fn initialize_smtp_client() -> Result<SmtpRepositoryPub, anyhow::Error> {
+    info!("initialize_smtp_client() START!");
+
+    let smtp_config: Arc<SmtpConfig> = get_smtp_config_info().ok_or_else(|| {
+        anyhow!("[initialize_smtp_client()] email backend is 'smtp' but [smtp] config is missing")
+    })?;
+
+    SmtpRepositoryPub::new(&smtp_config)
+}
+
+#[async_trait]
+impl EmailRepository for SmtpRepositoryPub {
+    #[doc = "SMTP 를 통해서 수신자에게 이메일(HTML + plaintext)을 보내주는 함수"]
+    /// # Arguments
+    /// * `receiver` - 수신자 이메일 주소
+    /// * `email` - 제목/본문 정보
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    async fn send_email(&self, receiver: &str, email: &EmailStruct) -> Result<(), anyhow::Error> {
+        let to: Mailbox = receiver.parse().map_err(|e| {
+            anyhow!(AppError::Notification(format!(
+                "[SmtpRepositoryPub->send_email] {:?} : Invalid receiver address '{}'",
+                e, receiver
+            )))
+        })?;
+
+        let message: Message = Message::builder()
+            .from(self.sender.clone())
+            .to(to)
+            .subject(email.subject())
+            .multipart(MultiPart::alternative_plain_html(
+                email.text_form().to_string(),
+                email.html_form().to_string(),
+            ))
+            .map_err(|e| {
+                anyhow!(AppError::Notification(format!(
+                    "[SmtpRepositoryPub->send_email] {:?} : Failed to build email to {}",
+                    e, receiver
+                )))
+            })?;
+
+        self.mailer.send(message).await.map_err(|e| {
+            anyhow!(AppError::Notification(format!(
+                "[SmtpRepositoryPub->send_email] {:?} : Failed to send email to {}",
+                e, receiver
+            )))
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    fn local_smtp_config(host: &str, port: u16) -> SmtpConfig {
+        toml::from_str(&format!(
+            r#"
+            smtp_name = "{}"
+            smtp_port = {}
+            smtp_security = "none"
+            sender_email = "indexing-check@example.com"
+            "#,
+            host, port
+        ))
+        .unwrap()
+    }
+
+    fn test_email() -> EmailStruct {
+        EmailStruct::new(
+            String::from("[Test] indexing check"),
+            String::from("<p>smtp stand-in html</p>"),
+            String::from("smtp stand-in text"),
+            String::from("smtp stand-in summary"),
+        )
+    }
+
+    /* 로컬 SMTP 대역(stand-in) - 메일 한 통을 받아서 DATA 본문을 돌려준다. */
+    async fn receive_one_mail(listener: TcpListener) -> String {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (reader, mut writer) = stream.into_split();
+        let mut reader: BufReader<_> = BufReader::new(reader);
+
+        writer
+            .write_all(b"220 localhost ESMTP stand-in\r\n")
+            .await
+            .unwrap();
+
+        let mut data: String = String::new();
+        let mut in_data: bool = false;
+        let mut line: String = String::new();
+
+        loop {
+            line.clear();
+
+            if reader.read_line(&mut line).await.unwrap() == 0 {
+                break;
+            }
+
+            if in_data {
+                if line == ".\r\n" {
+                    in_data = false;
+                    writer.write_all(b"250 OK queued\r\n").await.unwrap();
+                } else {
+                    data.push_str(&line);
+                }
+                continue;
+            }
+
+            let command: String = line.to_ascii_uppercase();
+
+            let reply: &[u8] = if command.starts_with("EHLO") || command.starts_with("HELO") {
+                b"250 localhost\r\n"
+            } else if command.starts_with("DATA") {
+                in_data = true;
+                b"354 End data with <CR><LF>.<CR><LF>\r\n"
+            } else if command.starts_with("QUIT") {
+                writer.write_all(b"221 Bye\r\n").await.unwrap();
+                break;
+            } else {
+                b"250 OK\r\n"
+            };
+
+            writer.write_all(reply).await.unwrap();
+        }
+
+        data
+    }
+
+    #[tokio::test]
+    async fn send_email_through_local_smtp_stand_in() {
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port: u16 = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(receive_one_mail(listener));
+
+        let smtp_repo: SmtpRepositoryPub =
+            SmtpRepositoryPub::new(&local_smtp_config("127.0.0.1", port)).unwrap();
+
+        smtp_repo
+            .send_email("receiver@example.com", &test_email())
+            .await
+            .unwrap();
+
+        let data: String = server.await.unwrap();
+
+        assert!(data.contains("From: indexing-check@example.com"));
+        assert!(data.contains("To: receiver@example.com"));
+        assert!(data.contains("Subject: [Test] indexing check"));
+        assert!(data.contains("smtp stand-in text"));
+        assert!(data.contains("<p>smtp stand-in html</p>"));
+    }
+
+    #[tokio::test]
+    async fn send_email_fails_when_smtp_server_is_down() {
+        /* 아무도 listen 하지 않는 포트 */
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port: u16 = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let smtp_repo: SmtpRepositoryPub =
+            SmtpRepositoryPub::new(&local_smtp_config("127.0.0.1", port)).unwrap();
+
+        let err: anyhow::Error = smtp_repo
+            .send_email("receiver@example.com", &test_email())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            AppError::find(&err),
+            Some(AppError::Notification(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn send_email_fails_with_invalid_receiver_address() {
+        let smtp_repo: SmtpRepositoryPub =
+            SmtpRepositoryPub::new(&local_smtp_config("127.0.0.1", 25)).unwrap();
+
+        let err: anyhow::Error = smtp_repo
+            .send_email("not-an-address", &test_email())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            AppError::find(&err),
+            Some(AppError::Notification(_))
+        ));
+    }
+
+    /* 로컬 mailpit / MailHog (SMTP 1025) 로 실제 발송 - cargo test -- --ignored */
+    #[tokio::test]
+    #[ignore]
+    async fn send_email_through_local_mailpit() {
+        let host: String = env::var("SMTP_TEST_HOST").unwrap_or_else(|_| String::from("127.0.0.1"));
+        let port: u16 = env::var("SMTP_TEST_PORT")
+            .ok()
+            .and_then(|port| port.parse().ok())
+            .unwrap_or(1025);
+
+        let smtp_repo: SmtpRepositoryPub =
+            SmtpRepositoryPub::new(&local_smtp_config(&host, port)).unwrap();
+
+        smtp_repo
+            .send_email("receiver@example.com", &test_email())
+            .await
+            .unwrap();
+    }
+}
@@ -2,15 +2,16 @@ use crate::common::*;
 
 use crate::env_configuration::env_config::*;
 
-use crate::model::rdb_config::*;
+use crate::errors::app_error::*;
+
+use crate::model::{procedure_config::*, rdb_config::*};
 
 use crate::utils_modules::io_utils::*;
 
 use crate::traits::repository_traits::sqlserver_repository_trait::*;
 
-#[doc = "전역 SQL Client 인스턴스 선언"]
-static SQL_REPO: once_lazy<Arc<SqlServerRepositoryPub>> =
-    once_lazy::new(initialize_sqlserver_client);
+#[doc = "전역 SQL Client 인스턴스 선언 - iMailer / 프로시저 알림 채널을 쓰는 경우에만 처음 사용할 때 초기화"]
+static SQL_REPO: OnceCell<Arc<SqlServerRepositoryPub>> = OnceCell::new();
 
 #[derive(Getters, new)]
 #[getset(get = "pub")]
@@ -19,17 +20,25 @@ pub struct SqlServerRepositoryPub {
 }
 
 #[doc = "SQL Server 커넥션 풀 초기화 - 애플리케이션 시작 시 1회만 호출"]
-fn initialize_sqlserver_client() -> Arc<SqlServerRepositoryPub> {
+fn initialize_sqlserver_client() -> Result<Arc<SqlServerRepositoryPub>, AppError> {
     info!("initialize_sqlserver_client() START!");
 
     /* TOML 로딩 */
-    let rdb_config: RdbConfig = read_toml_from_file::<RdbConfig>(&SQL_SERVER_INFO_PATH)
-        .unwrap_or_else(|_| {
-            let err_msg: &str =
-                "[ERROR][initialize_sqlserver_client()] Cannot read RdbConfig object.";
-            error!("{}", err_msg);
-            panic!("{}", err_msg)
-        });
+    let sql_server_info_path: String = get_sql_server_info_path()?;
+    let mut rdb_config: RdbConfig = read_toml_from_file::<RdbConfig>(&sql_server_info_path)
+        .map_err(|e| {
+            AppError::Config(format!(
+                "Cannot read RdbConfig object ({}): {}",
+                sql_server_info_path, e
+            ))
+        })?;
+
+    rdb_config.resolve_secret_files().map_err(|e| {
+        AppError::Config(format!(
+            "Cannot resolve RdbConfig secrets ({}): {}",
+            sql_server_info_path, e
+        ))
+    })?;
 
     let conn_str: String = format!(
         "Server={},{};Database={};User Id={};Password={};TrustServerCertificate=true;",
@@ -37,94 +46,160 @@ fn initialize_sqlserver_client() -> Arc<SqlServerRepositoryPub> {
         rdb_config.port(),
         rdb_config.db_schema(),
         rdb_config.user_id(),
-        rdb_config.user_pw(),
+        rdb_config.user_pw().expose(),
     );
 
     /* Connection Pool 생성 */
     let pool: deadpool_tiberius::deadpool::managed::Pool<Manager> =
-        match Manager::from_ado_string(&conn_str).and_then(|m| {
-            /* Sql Server Connection Pool 개수 제한 */
-            m.max_size(5)
-                .wait_timeout(std::time::Duration::from_secs(30))
-                .pre_recycle_sync(|_conn, _metrics| Ok(()))
-                .create_pool()
-        }) {
-            Ok(p) => p,
-            Err(e) => {
-                error!(
-                    "[ERROR][initialize_sqlserver_client] Failed to create pool: {:?}",
-                    e
-                );
-                panic!("{:?}", e);
-            }
-        };
-
-    Arc::new(SqlServerRepositoryPub::new(pool))
+        Manager::from_ado_string(&conn_str)
+            .and_then(|m| {
+                /* Sql Server Connection Pool 개수 제한 */
+                m.max_size(5)
+                    .wait_timeout(std::time::Duration::from_secs(30))
+                    .pre_recycle_sync(|_conn, _metrics| Ok(()))
+                    .create_pool()
+            })
+            .map_err(|e| AppError::SqlServer(format!("Failed to create pool: {:?}", e)))?;
+
+    Ok(Arc::new(SqlServerRepositoryPub::new(pool)))
 }
 
 #[doc = "sql server client 를 Thread-safe 하게 이용하는 함수."]
-pub fn get_sqlserver_repo() -> Arc<SqlServerRepositoryPub> {
-    Arc::clone(&SQL_REPO)
+pub fn get_sqlserver_repo() -> Result<Arc<SqlServerRepositoryPub>, AppError> {
+    SQL_REPO
+        .get_or_try_init(initialize_sqlserver_client)
+        .map(Arc::clone)
+}
+
+#[doc = "프로시저/파라미터 이름이 SQL 에 그대로 들어가므로 허용된 문자만 사용하는지 확인"]
+fn validate_sql_identifier(identifier: &str, allow_dot: bool) -> Result<(), anyhow::Error> {
+    let is_valid: bool = !identifier.is_empty()
+        && identifier.chars().all(|c| {
+            c.is_ascii_alphanumeric() || c == '_' || (allow_dot && matches!(c, '.' | '[' | ']'))
+        });
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "[validate_sql_identifier()] Invalid SQL identifier: '{}'",
+            identifier
+        ))
+    }
 }
 
 #[async_trait]
 impl SqlServerRepository for SqlServerRepositoryPub {
-    #[doc = "SQL Server 아이메일러 관련 프로시저 호출"]
-    async fn execute_imailer_procedure(
+    #[doc = "설정된 SQL Server 프로시저 호출 -> 리턴코드가 실패이면 에러 반환"]
+    /// # Arguments
+    /// * `procedure` - 프로시저 이름 / 파라미터 매핑 / 성공 리턴코드 정보
+    /// * `param_sources` - source 로 지정된 파라미터에 들어갈 값 (recipient, subject ...)
+    ///
+    /// # Returns
+    /// * Result<i32, anyhow::Error> - 프로시저 리턴코드
+    async fn execute_procedure(
         &self,
-        send_email: &str,
-        email_subject: &str,
-        email_content: &str,
-    ) -> Result<(), anyhow::Error> {
+        procedure: &ProcedureConfig,
+        param_sources: &HashMap<&str, &str>,
+    ) -> Result<i32, anyhow::Error> {
+        validate_sql_identifier(procedure.procedure_name(), true)?;
+
+        /* 파라미터 이름은 SQL 문에, 값은 바인딩 파라미터(@P1..)로 전달 */
+        let mut param_clauses: Vec<String> = Vec::new();
+        let mut param_values: Vec<String> = Vec::new();
+
+        for (idx, param) in procedure.params().iter().enumerate() {
+            validate_sql_identifier(param.name(), false)?;
+
+            let value: String = match (param.value(), param.source()) {
+                (Some(value), _) => value.clone(),
+                (None, Some(source)) => param_sources
+                    .get(source.as_str())
+                    .map(|v| v.to_string())
+                    .ok_or_else(|| {
+                    anyhow!(
+                        "[execute_procedure] Unknown source '{}' for parameter '@{}'",
+                        source,
+                        param.name()
+                    )
+                })?,
+                (None, None) => String::new(),
+            };
+
+            param_clauses.push(format!("@{} = @P{}", param.name(), idx + 1));
+            param_values.push(value);
+        }
+
+        let query: String = format!(
+            "DECLARE @return_value INT;\nEXEC @return_value = {} {};\nSELECT @return_value AS return_code;",
+            procedure.procedure_name(),
+            param_clauses.join(", ")
+        );
+
+        let query_params: Vec<&dyn tiberius::ToSql> = param_values
+            .iter()
+            .map(|value| value as &dyn tiberius::ToSql)
+            .collect();
+
         /* 풀에서 커넥션 가져오기 */
         let pool: &deadpool_tiberius::deadpool::managed::Pool<Manager> = self.pool();
-        let mut client: deadpool_tiberius::deadpool::managed::Object<Manager> = pool.get().await?;
+        let mut client: deadpool_tiberius::deadpool::managed::Object<Manager> =
+            pool.get().await.map_err(|e| {
+                AppError::SqlServer(format!(
+                    "[execute_procedure] Failed to get connection: {}",
+                    e
+                ))
+            })?;
 
         /* 프로시저 호출 */
         let results: Vec<Vec<tiberius::Row>> = client
-            .query(
-                r#"
-                DECLARE @return_value INT;
-                EXEC @return_value = NEWSLETTER.dbo.IM_DMAIL_INFO_INS_TEMPLATE_PROC
-                    @GUBUN      = @P1,
-                    @SENDNAME   = @P2,
-                    @SENDEMAIL  = @P3,
-                    @RECVNAME   = @P4,
-                    @RECVEMAIL  = @P5,
-                    @SUBJECT    = @P6,
-                    @CONTENT    = @P7,
-                    @QRY        = @P8;
-                SELECT @return_value AS return_code;
-                "#,
-                &[
-                    &"ALBA",
-                    &"알바천국",
-                    &"alba@alba.co.kr",
-                    &"",
-                    &send_email,
-                    &email_subject,
-                    &email_content,
-                    &"",
-                ],
-            )
-            .await?
+            .query(query, &query_params)
+            .await
+            .map_err(|e| {
+                AppError::SqlServer(format!(
+                    "[execute_procedure] {} failed: {}",
+                    procedure.procedure_name(),
+                    e
+                ))
+            })?
             .into_results()
-            .await?;
-
-        /* 결과 처리 */
-        if let Some(row) = results.first().and_then(|set| set.first()) {
-            let code: i32 = row.get("return_code").unwrap_or(0);
-            if code == 0 {
-                error!(
-                    "[execute_imailer_procedure] proc failure - return_code={}",
-                    code
-                );
-            }
+            .await
+            .map_err(|e| {
+                AppError::SqlServer(format!(
+                    "[execute_procedure] {} failed to read results: {}",
+                    procedure.procedure_name(),
+                    e
+                ))
+            })?;
+
+        /* 결과 처리 - 마지막 결과셋의 return_code 를 전달 성공/실패로 매핑 */
+        let row: &tiberius::Row = results
+            .iter()
+            .rev()
+            .find_map(|set| set.first())
+            .ok_or_else(|| {
+                anyhow!(
+                    "[execute_procedure] {} returned no return_code row",
+                    procedure.procedure_name()
+                )
+            })?;
+
+        let code: i32 = row.get::<i32, _>("return_code").ok_or_else(|| {
+            anyhow!(
+                "[execute_procedure] {} returned NULL return_code",
+                procedure.procedure_name()
+            )
+        })?;
+
+        if procedure.is_success_code(code) {
+            Ok(code)
         } else {
-            error!("[execute_imailer_procedure] no return row");
+            Err(anyhow!(AppError::Notification(format!(
+                "[execute_procedure] {} failed - return_code={}",
+                procedure.procedure_name(),
+                code
+            ))))
         }
-
-        Ok(())
     }
 }
 
@@ -1,9 +1,14 @@
 use crate::common::*;
 
-use crate::model::total_config::*;
+use crate::errors::app_error::*;
+
+use crate::model::{secret_value::*, telegram_update::*, total_config::*};
 
 use crate::traits::repository_traits::telegram_repository_trait::*;
 
+/* Telegram 메시지 한 건의 최대 길이(4096)보다 약간 작게 잘라서 보낸다. */
+pub const TELEGRAM_MSG_MAX_LEN: usize = 4000;
+
 #[doc = "전역 Telebot 인스턴스를 선언"]
 static TELEGRAM_REPO: once_lazy<Arc<TelebotRepositoryPub>> =
     once_lazy::new(initialize_tele_bot_client);
@@ -14,7 +19,7 @@ pub fn initialize_tele_bot_client() -> Arc<TelebotRepositoryPub> {
 
     let telegram_config: Arc<crate::model::telegram_config::TelegramConfig> =
         get_telegram_config_info();
-    let bot_token: &String = telegram_config.bot_token();
+    let bot_token: &SecretValue = telegram_config.bot_token();
     let chat_room_id: &String = telegram_config.chat_room_id();
 
     let tele_repo: TelebotRepositoryPub =
@@ -31,49 +36,12 @@ pub fn get_telegram_repo() -> Arc<TelebotRepositoryPub> {
 /* TelebotService는 비즈니스 로직을 담당하는 서비스 레이어로 분리 */
 #[derive(Clone, Debug, Deserialize, Serialize, new)]
 pub struct TelebotRepositoryPub {
-    pub bot_token: String,
+    pub bot_token: SecretValue,
     pub chat_room_id: String,
 }
 
 #[async_trait]
 impl TelebotRepository for TelebotRepositoryPub {
-    #[doc = "Telegram bot 이 메시지를 보내주는 기능 -> 3번 실패 시 에러발생"]
-    /// # Arguments
-    /// * `send_msg` - Telegram 을 통해서 보내줄 메시지
-    ///
-    /// # Returns
-    /// * Result<(), anyhow::Error>
-    async fn bot_send(&self, send_msg: &str) -> Result<(), anyhow::Error> {
-        let url = format!("https://api.telegram.org/bot{}/sendMessage", self.bot_token);
-
-        let body = serde_json::json!({
-            "chat_id": self.chat_room_id,
-            "text": send_msg
-        });
-
-        let client: Client = Client::new();
-
-        /* 최대 3번의 시도를 수행 */
-        for try_cnt in 0..3 {
-            match self.try_send(&client, &url, &body).await {
-                Ok(_) => {
-                    info!("Successfully sent Telegram message");
-                    return Ok(());
-                }
-                Err(err) => {
-                    error!(
-                       "[Timeout Error][bot_send()] Attempt {} failed: {}. Retrying in 10 seconds.",
-                       try_cnt + 1,
-                       err
-                   );
-                    sleep(Duration::from_secs(10)).await;
-                }
-            }
-        }
-
-        Err(anyhow!("[Timeout Error][bot_send()] Failed to send message after 3 attempts to the Telegram bot."))
-    }
-
     #[doc = "메시지를 직접 보내주는 함수"]
     /// # Arguments
     /// * `client` - Telegram 메시지 통신을 위한 클라이언트
@@ -93,7 +61,14 @@ impl TelebotRepository for TelebotRepositoryPub {
             .header("Content-Type", "application/json")
             .body(body.to_string())
             .send()
-            .await?;
+            .await
+            .map_err(|e| {
+                /* 에러 메시지의 URL 에 bot token 이 들어있으므로 제거 */
+                AppError::Notification(format!(
+                    "[try_send()] Telegram request failed: {}",
+                    e.without_url()
+                ))
+            })?;
 
         if res.status().is_success() {
             Ok(())
@@ -102,7 +77,102 @@ impl TelebotRepository for TelebotRepositoryPub {
                 .text()
                 .await
                 .unwrap_or_else(|_| "Failed to retrieve error message".to_string());
-            Err(anyhow!("HTTP request failed with status: {:?}", err_text))
+            Err(anyhow!(AppError::Notification(format!(
+                "HTTP request failed with status: {:?}",
+                err_text
+            ))))
         }
     }
+
+    #[doc = "특정 chat 으로 메시지를 한번 보내주는 함수 - 재시도는 발송 대기열(outbox)에서 처리"]
+    /// # Arguments
+    /// * `chat_id` - 메시지를 받을 chat id
+    /// * `send_msg` - Telegram 을 통해서 보내줄 메시지
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    async fn send_to_chat(&self, chat_id: &str, send_msg: &str) -> Result<(), anyhow::Error> {
+        let url: String = format!(
+            "https://api.telegram.org/bot{}/sendMessage",
+            self.bot_token.expose()
+        );
+
+        let body: Value = serde_json::json!({
+            "chat_id": chat_id,
+            "text": send_msg
+        });
+
+        let client: Client = Client::new();
+        self.try_send(&client, &url, &body).await
+    }
+
+    #[doc = "getUpdates long polling 으로 봇에게 들어온 메시지를 가져오는 함수"]
+    /// # Arguments
+    /// * `offset` - 이미 처리한 update_id 다음 값
+    /// * `timeout_sec` - long polling 대기 시간(초)
+    ///
+    /// # Returns
+    /// * Result<Vec<TelegramUpdate>, anyhow::Error>
+    async fn get_updates(
+        &self,
+        offset: i64,
+        timeout_sec: u64,
+    ) -> Result<Vec<TelegramUpdate>, anyhow::Error> {
+        let url: String = format!(
+            "https://api.telegram.org/bot{}/getUpdates",
+            self.bot_token.expose()
+        );
+
+        let body: Value = serde_json::json!({
+            "offset": offset,
+            "timeout": timeout_sec,
+            "allowed_updates": ["message"]
+        });
+
+        /* long polling 시간보다 클라이언트 타임아웃을 길게 잡아준다. */
+        let client: Client = Client::builder()
+            .timeout(Duration::from_secs(timeout_sec + 10))
+            .build()
+            .map_err(|e| {
+                AppError::Notification(format!(
+                    "[get_updates()] Failed to build Telegram client: {}",
+                    e.without_url()
+                ))
+            })?;
+
+        let res = client.post(&url).json(&body).send().await.map_err(|e| {
+            /* 에러 메시지의 URL 에 bot token 이 들어있으므로 제거 */
+            AppError::Notification(format!(
+                "[get_updates()] Telegram request failed: {}",
+                e.without_url()
+            ))
+        })?;
+
+        if !res.status().is_success() {
+            let err_text: String = res
+                .text()
+                .await
+                .unwrap_or_else(|_| "Failed to retrieve error message".to_string());
+            return Err(anyhow!(AppError::Notification(format!(
+                "[get_updates()] HTTP request failed with status: {:?}",
+                err_text
+            ))));
+        }
+
+        /* 응답 본문을 읽다가 난 에러에도 URL(bot token) 이 들어있으므로 제거 */
+        let res_body: Value = res.json::<Value>().await.map_err(|e| {
+            AppError::Notification(format!(
+                "[get_updates()] Failed to read Telegram response: {}",
+                e.without_url()
+            ))
+        })?;
+        let result: Value = res_body
+            .get("result")
+            .cloned()
+            .ok_or_else(|| anyhow!("[get_updates()] Missing 'result' field"))?;
+
+        let updates: Vec<TelegramUpdate> = serde_json::from_value(result)?;
+
+        Ok(updates)
+    }
 }
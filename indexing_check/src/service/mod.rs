@@ -1,2 +1,4 @@
 pub mod notification_service;
-pub mod query_service;
\ No newline at end of file
+pub mod query_service;
+pub mod status_service;
+pub mod template_service;
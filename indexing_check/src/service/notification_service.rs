@@ -1,43 +1,77 @@
 use crate::common::*;
 
 use crate::model::{
-    elastic_server_config::*, error_alarm_info::*, error_alarm_info_format::*,
-    receiver_email_config::*, system_config::*, total_config::*,
+    digest_config::*, digest_template_context::*, elastic_server_config::*, email_struct::*,
+    error_alarm_info::*, error_alarm_info_format::*, notification_outbox::*, outbox_config::*,
+    procedure_config::*, receiver_email_config::*, sla_report::*, system_config::*,
+    telegram_config::*, total_config::*,
 };
 
-use crate::traits::repository_traits::sqlserver_repository_trait::SqlServerRepository;
-use crate::traits::repository_traits::telegram_repository_trait::*;
-use crate::traits::service_traits::notification_service_trait::*;
+use crate::traits::repository_traits::{email_repository_trait::*, telegram_repository_trait::*};
+use crate::traits::service_traits::{notification_service_trait::*, template_service_trait::*};
 
-use crate::repository::{sqlserver_repository::*, telegram_repository::*};
+use crate::repository::{email_repository::*, telegram_repository::*};
 
-use crate::utils_modules::io_utils::*;
+use crate::service::template_service::*;
+
+use crate::utils_modules::{io_utils::*, message_utils::*, time_utils::*};
 
 use crate::env_configuration::env_config::*;
 
-#[derive(Debug, Getters)]
+use crate::errors::app_error::*;
+
+#[derive(Getters)]
 #[getset(get = "pub")]
 pub struct NotificationServicePub {
     receiver_email_list: ReceiverEmailConfig,
+    template_service: TemplateServicePub,
+    email_repo: Arc<dyn EmailRepository + Send + Sync>,
+    procedure_notifiers: Vec<(
+        ProcedureNotifierConfig,
+        Arc<dyn EmailRepository + Send + Sync>,
+    )>,
 }
 
 impl NotificationServicePub {
     #[doc = "NotificationServicePub 구조체의 생성자"]
-    pub fn new() -> Self {
+    /// # Returns
+    /// * Result<Self, AppError> - 수신자 / 템플릿 / 발송 채널 설정을 읽지 못하면 에러
+    pub fn new() -> Result<Self, AppError> {
+        let email_receiver_path: String = get_email_receiver_path()?;
         let receiver_email_list: ReceiverEmailConfig =
-            read_toml_from_file::<ReceiverEmailConfig>(&EMAIL_RECEIVER_PATH)
-                .unwrap_or_else(|e| {
-                    let err_msg: &str = "[ERROR][NotificationServicePub->new] Failed to retrieve information 'receiver_email_list'.";
-                    error!("{} : {:?}", err_msg, e);
-                    panic!("{} : {:?}", err_msg, e)
-                });
-
-        NotificationServicePub {
+            read_toml_from_file::<ReceiverEmailConfig>(&email_receiver_path).map_err(|e| {
+                AppError::Config(format!(
+                    "[NotificationServicePub->new] Failed to retrieve information 'receiver_email_list' ({}): {}",
+                    email_receiver_path, e
+                ))
+            })?;
+
+        /* 이메일 템플릿은 시작 시 한번만 읽고 검증한다. */
+        let template_service: TemplateServicePub = TemplateServicePub::new().map_err(|e| {
+            AppError::Config(format!(
+                "[NotificationServicePub->new] Failed to load email templates: {:#}",
+                e
+            ))
+        })?;
+
+        /* [email] backend 설정에 따라 SMTP / iMailer 중 하나를 사용 */
+        let email_repo: Arc<dyn EmailRepository + Send + Sync> = initialize_email_repo()?;
+
+        /* SMS, 알림톡 등 프로시저 기반 추가 알림 채널 */
+        let procedure_notifiers: Vec<(
+            ProcedureNotifierConfig,
+            Arc<dyn EmailRepository + Send + Sync>,
+        )> = initialize_procedure_notifier_repos()?;
+
+        Ok(NotificationServicePub {
             receiver_email_list,
-        }
+            template_service,
+            email_repo,
+            procedure_notifiers,
+        })
     }
 
-    #[doc = "색인 실패별 로그들을 완전실패/부분실패로 나눠주는 함수"]
+    #[doc = "색인 실패별 로그들을 완전실패/부분실패/DR 정합성 오류로 나눠주는 함수"]
     /// # Arguments
     /// * `error_alaram_infos` - 실패한 색인 정보
     ///
@@ -54,6 +88,16 @@ impl NotificationServicePub {
             error_alaram_info.index_name()
         ));
 
+        /* 클러스터 이름이 없는 이전 버전 알람은 기본 클러스터 */
+        let cluster_name: String = if error_alaram_info.cluster_name().is_empty() {
+            get_elasticsearch_config_info()
+                .elastic_cluster_name()
+                .to_string()
+        } else {
+            error_alaram_info.cluster_name().to_string()
+        };
+        send_msg.push_str(&format!("   - cluster: {}\n", cluster_name));
+
         send_msg.push_str(&format!(
             "   - indexing type: {}\n",
             error_alaram_info.index_type()
@@ -61,6 +105,10 @@ impl NotificationServicePub {
 
         let key_name: String = if error_alaram_info.error_type() == "Full Error" {
             String::from("Full Error")
+        } else if error_alaram_info.error_type() == "Consistency Error" {
+            /* DR 클러스터 정합성 - 사유에 두 클러스터의 문서 수가 들어있다. */
+            send_msg.push_str(&format!("   - reason: {}\n", error_alaram_info.reason()));
+            String::from("Consistency Error")
         } else {
             send_msg.push_str(&format!(
                 "   - index cnt (declare cnt): {} ({})\n",
@@ -82,24 +130,28 @@ impl NotificationServicePub {
         Ok(())
     }
 
-    #[doc = "색인이 실패했을 때, Telegram bot 을 통해서 알람을 보내주는 함수"]
+    #[doc = "색인이 실패했을 때, Telegram bot 으로 보낼 알림들을 만들어주는 함수"]
     /// # Arguments
     /// * `error_alaram_infos` - 실패한 색인 정보들
+    /// * `created_at` - 알림 생성 시각 (UTC)
     ///
     /// # Returns
-    /// * Result<(), anyhow::Error>
-    async fn send_indexing_failed_msg(
+    /// * Result<Vec<NotificationOutbox>, anyhow::Error>
+    fn build_telegram_notifications(
         &self,
         error_alaram_infos: &[ErrorAlarmInfoFormat],
-    ) -> Result<(), anyhow::Error> {
-        let tele_repo: Arc<TelebotRepositoryPub> = get_telegram_repo();
-
+        created_at: NaiveDateTime,
+    ) -> Result<Vec<NotificationOutbox>, anyhow::Error> {
+        let telegram_config: Arc<TelegramConfig> = get_telegram_config_info();
         let system_config: Arc<SystemConfig> = get_system_config_info();
+        let outbox_config: Arc<OutboxConfig> = get_outbox_config_info();
         let msg_chunk_size: usize = *system_config.message_chunk_size();
 
-        let mut err_alram_map: HashMap<String, Vec<String>> = HashMap::new();
+        let mut notifications: Vec<NotificationOutbox> = Vec::new();
 
         for chunk in error_alaram_infos.chunks(msg_chunk_size) {
+            let mut err_alram_map: HashMap<String, Vec<String>> = HashMap::new();
+
             for item in chunk {
                 self.get_error_clasification(item.error_alarm_info(), &mut err_alram_map)?;
             }
@@ -117,98 +169,327 @@ impl NotificationServicePub {
                 }
             }
 
-            /* Send Message */
-            tele_repo.bot_send(&msg_format).await?;
-
-            err_alram_map = HashMap::new(); /* Clear HashMap */
+            let message: EmailStruct = EmailStruct::new(
+                String::from("[Elasticsearch Indexing Error!]"),
+                String::new(),
+                msg_format.clone(),
+                msg_format,
+            );
+
+            notifications.push(NotificationOutbox::new(
+                OUTBOX_CHANNEL_TELEGRAM,
+                telegram_config.chat_room_id(),
+                &message,
+                chunk
+                    .iter()
+                    .map(|alarm| alarm.doc_id().to_string())
+                    .collect(),
+                created_at,
+                *outbox_config.expire_sec(),
+            ));
         }
 
-        Ok(())
+        Ok(notifications)
     }
 
-    #[doc = "색인이 실패했을 때, mail 을 통해서 알람을 보내주는 함수"]
+    #[doc = "색인이 실패했을 때, mail 및 프로시저 기반 채널(SMS, 알림톡 등)로 보낼 알림들을 만들어주는 함수"]
     /// # Arguments
     /// * `error_alaram_infos` - 실패한 색인 정보들
+    /// * `created_at` - 알림 생성 시각 (UTC)
     ///
     /// # Returns
-    /// * Result<(), anyhow::Error>
-    async fn send_mail_to_receivers(
+    /// * Result<Vec<NotificationOutbox>, anyhow::Error>
+    fn build_mail_notifications(
         &self,
         error_alarm_infos: &[ErrorAlarmInfoFormat],
-    ) -> Result<(), anyhow::Error> {
+        created_at: NaiveDateTime,
+    ) -> Result<Vec<NotificationOutbox>, anyhow::Error> {
         let elastic_config: Arc<ElasticServerConfig> = get_elasticsearch_config_info();
-
-        /* receiver email list */
-        let receiver_email_list: &Vec<ReceiverEmail> = &self.receiver_email_list().emails;
-
-        let email_subject: String = String::from("[Elasticsearch] Indexing ERROR Alarm");
-        let mut inner_template: String = String::from("");
-        let html_template: String = fs::read_to_string(Path::new(HTML_TEMPLATE_PATH.as_str()))?;
-
-        for err_info in error_alarm_infos {
-            let err_info_tag: String = err_info.error_alarm_info().convert_email_struct()?;
-            inner_template.push_str(&err_info_tag);
+        let outbox_config: Arc<OutboxConfig> = get_outbox_config_info();
+
+        let email: EmailStruct = self
+            .template_service
+            .render_alarm_email(elastic_config.elastic_cluster_name(), error_alarm_infos)?;
+
+        let alarm_doc_ids: Vec<String> = error_alarm_infos
+            .iter()
+            .map(|alarm| alarm.doc_id().to_string())
+            .collect();
+
+        let mut notifications: Vec<NotificationOutbox> = Vec::new();
+
+        /* 1. Email (SMTP / iMailer) - 수신자별로 따로 재시도 */
+        for receiver in &self.receiver_email_list().emails {
+            notifications.push(NotificationOutbox::new(
+                OUTBOX_CHANNEL_EMAIL,
+                receiver.email_id(),
+                &email,
+                alarm_doc_ids.clone(),
+                created_at,
+                *outbox_config.expire_sec(),
+            ));
         }
 
-        let html_content: String = html_template
-            .replace("{cluster_name}", elastic_config.elastic_cluster_name())
-            .replace("{index_list}", &inner_template);
-
-        let sql_conn: Arc<SqlServerRepositoryPub> = get_sqlserver_repo();
-
-        for receiver in receiver_email_list {
-            match sql_conn
-                .execute_imailer_procedure(receiver.email_id(), &email_subject, &html_content)
-                .await
-            {
-                Ok(_) => {
-                    info!("Successfully sent mail to {}", receiver.email_id());
-                }
-                Err(e) => {
-                    error!("[ERROR][NotificationServicePub->send_mail_to_receivers] Failed sent mail to {} : {:?}", receiver.email_id(), e);
-                }
+        /* 2. 프로시저 기반 채널 (SMS, 알림톡 등) */
+        for (notifier_config, _) in &self.procedure_notifiers {
+            let channel: String = format!(
+                "{}{}",
+                OUTBOX_CHANNEL_PROCEDURE_PREFIX,
+                notifier_config.name()
+            );
+
+            for receiver in notifier_config.receivers() {
+                notifications.push(NotificationOutbox::new(
+                    &channel,
+                    receiver,
+                    &email,
+                    alarm_doc_ids.clone(),
+                    created_at,
+                    *outbox_config.expire_sec(),
+                ));
             }
         }
 
-        Ok(())
+        Ok(notifications)
     }
 }
 
 #[async_trait]
 impl NotificationService for NotificationServicePub {
-    #[doc = "지정된 수신자 모두에게 이메일을 보내주는 함수"]
+    #[doc = "알람 정보들을 채널/수신자별 발송 대기 알림으로 만들어주는 함수"]
     /// # Arguments
     /// * `error_alarm_infos` - Index error informations
+    /// * `created_at` - 알림 생성 시각 (UTC)
     ///
     /// # Returns
-    /// * Result<(), anyhow::Error>
-    async fn send_message_to_receivers(
+    /// * Result<Vec<NotificationOutbox>, anyhow::Error>
+    fn build_notifications(
         &self,
         error_alarm_infos: &[ErrorAlarmInfoFormat],
-    ) -> Result<(), anyhow::Error> {
-        /* Telegram 이나 Imailer 가 통신되지 않을 경우를 고려한다. */
-        /* 1. Telegram 알람 전송 */
-        let telegram = async {
-            if let Err(e) = self.send_indexing_failed_msg(error_alarm_infos).await {
-                error!(
-                    "[ERROR][NotificationServicePub->send_message_to_receivers][telegram]{:?}",
-                    e
+        created_at: NaiveDateTime,
+    ) -> Result<Vec<NotificationOutbox>, anyhow::Error> {
+        let mut notifications: Vec<NotificationOutbox> =
+            self.build_telegram_notifications(error_alarm_infos, created_at)?;
+        notifications.extend(self.build_mail_notifications(error_alarm_infos, created_at)?);
+
+        Ok(notifications)
+    }
+
+    #[doc = "모니터링 중인 인덱스들의 상태 요약(digest) 을 발송 대기 알림으로 만들어주는 함수"]
+    /// # Arguments
+    /// * `items` - 인덱스별 상태
+    /// * `generated_at` - 요약 생성 시각 (한국시간)
+    /// * `created_at` - 알림 생성 시각 (UTC)
+    ///
+    /// # Returns
+    /// * Result<Vec<NotificationOutbox>, anyhow::Error>
+    fn build_digest_notifications(
+        &self,
+        items: Vec<DigestTemplateItem>,
+        generated_at: &str,
+        created_at: NaiveDateTime,
+    ) -> Result<Vec<NotificationOutbox>, anyhow::Error> {
+        let elastic_config: Arc<ElasticServerConfig> = get_elasticsearch_config_info();
+        let outbox_config: Arc<OutboxConfig> = get_outbox_config_info();
+        let digest_config: Arc<DigestConfig> = get_digest_config_info();
+
+        let digest: EmailStruct = self.template_service.render_digest_email(
+            elastic_config.elastic_cluster_name(),
+            generated_at,
+            items,
+        )?;
+
+        let mut notifications: Vec<NotificationOutbox> = Vec::new();
+
+        /* 1. Telegram - 메시지 길이 제한에 맞게 나눠서 발송 */
+        if *digest_config.send_telegram() {
+            let telegram_config: Arc<TelegramConfig> = get_telegram_config_info();
+
+            for chunk in split_message(digest.text_form(), TELEGRAM_MSG_MAX_LEN) {
+                let message: EmailStruct = EmailStruct::new(
+                    digest.subject().to_string(),
+                    String::new(),
+                    chunk.clone(),
+                    chunk,
                 );
+
+                notifications.push(NotificationOutbox::new(
+                    OUTBOX_CHANNEL_TELEGRAM,
+                    telegram_config.chat_room_id(),
+                    &message,
+                    Vec::new(),
+                    created_at,
+                    *outbox_config.expire_sec(),
+                ));
             }
-        };
-        
-        /* 2. Imailer 알람 전송 */
-        let mail = async {
-            if let Err(e) = self.send_mail_to_receivers(error_alarm_infos).await {
-                error!(
-                    "[ERROR][NotificationServicePub->send_message_to_receivers][imailer]{:?}",
-                    e
-                );
+        }
+
+        /* 2. Email (SMTP / iMailer) */
+        if *digest_config.send_email() {
+            for receiver in &self.receiver_email_list().emails {
+                notifications.push(NotificationOutbox::new(
+                    OUTBOX_CHANNEL_EMAIL,
+                    receiver.email_id(),
+                    &digest,
+                    Vec::new(),
+                    created_at,
+                    *outbox_config.expire_sec(),
+                ));
             }
-        };
+        }
+
+        Ok(notifications)
+    }
+
+    #[doc = "SLA 리포트를 이메일 수신자별 발송 대기 알림으로 만들어주는 함수"]
+    /// # Arguments
+    /// * `sla_report` - 기간별 SLA 리포트
+    /// * `created_at` - 알림 생성 시각 (UTC)
+    ///
+    /// # Returns
+    /// * Result<Vec<NotificationOutbox>, anyhow::Error>
+    fn build_report_notifications(
+        &self,
+        sla_report: &SlaReport,
+        created_at: NaiveDateTime,
+    ) -> Result<Vec<NotificationOutbox>, anyhow::Error> {
+        let outbox_config: Arc<OutboxConfig> = get_outbox_config_info();
+
+        let report: EmailStruct = self.template_service.render_sla_report_email(sla_report)?;
+
+        let notifications: Vec<NotificationOutbox> = self
+            .receiver_email_list()
+            .emails
+            .iter()
+            .map(|receiver| {
+                NotificationOutbox::new(
+                    OUTBOX_CHANNEL_EMAIL,
+                    receiver.email_id(),
+                    &report,
+                    Vec::new(),
+                    created_at,
+                    *outbox_config.expire_sec(),
+                )
+            })
+            .collect();
+
+        Ok(notifications)
+    }
+
+    #[doc = "모든 채널 / 수신자로 보낼 테스트 알림을 만들어주는 함수 (test-notify 명령어)"]
+    /// # Arguments
+    /// * `created_at` - 알림 생성 시각 (UTC)
+    ///
+    /// # Returns
+    /// * Result<Vec<NotificationOutbox>, anyhow::Error>
+    fn build_test_notifications(
+        &self,
+        created_at: NaiveDateTime,
+    ) -> Result<Vec<NotificationOutbox>, anyhow::Error> {
+        let elastic_config: Arc<ElasticServerConfig> = get_elasticsearch_config_info();
+        let telegram_config: Arc<TelegramConfig> = get_telegram_config_info();
+        let outbox_config: Arc<OutboxConfig> = get_outbox_config_info();
+
+        let subject: String = String::from("[Indexing Check] Test notification");
+        let text_form: String = format!(
+            "{}\ncluster: {}\nsent at: {} (KST)",
+            subject,
+            elastic_config.elastic_cluster_name(),
+            get_current_kor_naive_datetime_str()?
+        );
+        let html_form: String = format!("<pre>{}</pre>", text_form);
+        let message: EmailStruct = EmailStruct::new(subject.clone(), html_form, text_form, subject);
+
+        let mut notifications: Vec<NotificationOutbox> = vec![NotificationOutbox::new(
+            OUTBOX_CHANNEL_TELEGRAM,
+            telegram_config.chat_room_id(),
+            &message,
+            Vec::new(),
+            created_at,
+            *outbox_config.expire_sec(),
+        )];
+
+        for receiver in &self.receiver_email_list().emails {
+            notifications.push(NotificationOutbox::new(
+                OUTBOX_CHANNEL_EMAIL,
+                receiver.email_id(),
+                &message,
+                Vec::new(),
+                created_at,
+                *outbox_config.expire_sec(),
+            ));
+        }
+
+        for (notifier_config, _) in &self.procedure_notifiers {
+            let channel: String = format!(
+                "{}{}",
+                OUTBOX_CHANNEL_PROCEDURE_PREFIX,
+                notifier_config.name()
+            );
+
+            for receiver in notifier_config.receivers() {
+                notifications.push(NotificationOutbox::new(
+                    &channel,
+                    receiver,
+                    &message,
+                    Vec::new(),
+                    created_at,
+                    *outbox_config.expire_sec(),
+                ));
+            }
+        }
+
+        Ok(notifications)
+    }
+
+    #[doc = "발송 대기 알림 한 건을 해당 채널로 보내주는 함수 - 재시도는 호출하는 쪽에서 관리"]
+    /// # Arguments
+    /// * `notification` - 발송할 알림
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    async fn deliver_notification(
+        &self,
+        notification: &NotificationOutbox,
+    ) -> Result<(), anyhow::Error> {
+        let channel: &str = notification.channel();
+        let recipient: &str = notification.recipient();
+
+        if channel == OUTBOX_CHANNEL_TELEGRAM {
+            let tele_repo: Arc<TelebotRepositoryPub> = get_telegram_repo();
+            tele_repo
+                .send_to_chat(recipient, notification.text_form())
+                .await?;
+        } else if channel == OUTBOX_CHANNEL_EMAIL {
+            self.email_repo
+                .send_email(recipient, &notification.to_email_struct())
+                .await?;
+        } else if let Some(notifier_name) = channel.strip_prefix(OUTBOX_CHANNEL_PROCEDURE_PREFIX) {
+            let (_, notifier_repo) = self
+                .procedure_notifiers
+                .iter()
+                .find(|(notifier_config, _)| notifier_config.name() == notifier_name)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "[NotificationServicePub->deliver_notification] Unknown procedure notifier: {}",
+                        notifier_name
+                    )
+                })?;
+
+            notifier_repo
+                .send_email(recipient, &notification.to_email_struct())
+                .await?;
+        } else {
+            return Err(anyhow!(
+                "[NotificationServicePub->deliver_notification] Unknown channel: {}",
+                channel
+            ));
+        }
 
-        /* 병렬 실행 */
-        let ((), ()) = tokio::join!(telegram, mail);
+        info!(
+            "Successfully sent [{}] notification to {}",
+            channel, recipient
+        );
 
         Ok(())
     }
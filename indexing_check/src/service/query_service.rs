@@ -4,16 +4,24 @@ use crate::traits::{
     repository_traits::es_repository_trait::*, service_traits::query_service_trait::*,
 };
 
+use crate::errors::app_error::*;
+
 use crate::repository::es_repository::*;
 
+use crate::utils_modules::io_utils::*;
 use crate::utils_modules::time_utils::*;
 use crate::utils_modules::traits::*;
 
 use crate::model::{
-    error_alarm_info::*, error_alarm_info_format::*, vector_index_log::*,
-    vector_index_log_format::*,
+    alarm_snooze::*, bulk_operation::*, check_history::*, error_alarm_info::*,
+    error_alarm_info_format::*, es_node_status::*, index_template::*, leader_lease::*,
+    notification_outbox::*, vector_index_log::*, vector_index_log_format::*,
 };
 
+/* point in time 유지 시간 / 페이지 크기 - 한 페이지를 처리하는 동안만 유지되면 된다. */
+const PIT_KEEP_ALIVE: &str = "1m";
+const SEARCH_PAGE_SIZE: usize = 500;
+
 #[derive(Debug, new)]
 pub struct QueryServicePub {}
 
@@ -59,10 +67,10 @@ impl QueryServicePub {
                 })?;
 
                 let source: S = serde_json::from_value(src_val.clone()).map_err(|e| {
-                    anyhow!(
+                    AppError::Parse(format!(
                         "[QueryServicePub->get_query_result_vec] Failed to deserialize source: {}",
                         e
-                    )
+                    ))
                 })?;
 
                 /* 3) 트레이트 메서드로 T 생성 */
@@ -109,20 +117,113 @@ impl QueryServicePub {
             .ok_or_else(|| anyhow!("[QueryServicePub->get_query_result] Missing '_source'"))?;
 
         let source: S = serde_json::from_value(src_val.clone()).map_err(|e| {
-            anyhow!(
+            AppError::Parse(format!(
                 "[QueryServicePub->get_query_result] Failed to deserialize source: {}",
                 e
-            )
+            ))
         })?;
 
         Ok(T::from_search_hit(id, source))
     }
+
+    #[doc = "point in time + search_after 로 조건에 맞는 문서를 모두 조회해주는 함수 (size 제한 없음)"]
+    /// # Arguments
+    /// * `index_name` - 조회할 인덱스 이름
+    /// * `query` - query 절
+    /// * `sort` - 정렬 조건 (페이지 사이 순서가 유지되도록 _shard_doc(OpenSearch 는 _id) 이 마지막에 추가된다.)
+    ///
+    /// # Returns
+    /// * Result<Vec<T>, anyhow::Error>
+    async fn search_all_pages<T, S>(
+        &self,
+        index_name: &str,
+        query: Value,
+        sort: Value,
+    ) -> Result<Vec<T>, anyhow::Error>
+    where
+        S: DeserializeOwned,
+        T: FromSearchHit<S> + Send,
+    {
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+        let mut pit_id: String = es_client
+            .open_point_in_time(index_name, PIT_KEEP_ALIVE)
+            .await?;
+
+        let result: Result<Vec<T>, anyhow::Error> = self
+            .search_pit_pages::<T, S>(&es_client, &mut pit_id, query, sort)
+            .await;
+
+        /* 조회 성공 여부와 관계없이 PIT 는 닫는다. (실패해도 keep_alive 후 만료) */
+        if let Err(e) = es_client.close_point_in_time(&pit_id).await {
+            error!("[QueryServicePub->search_all_pages] {:?}", e);
+        }
+
+        result
+    }
+
+    #[doc = "열린 PIT 에서 마지막 페이지까지 search_after 로 조회하는 함수"]
+    async fn search_pit_pages<T, S>(
+        &self,
+        es_client: &ElasticConnGuard,
+        pit_id: &mut String,
+        query: Value,
+        sort: Value,
+    ) -> Result<Vec<T>, anyhow::Error>
+    where
+        S: DeserializeOwned,
+        T: FromSearchHit<S> + Send,
+    {
+        let mut sort: Vec<Value> = sort.as_array().cloned().unwrap_or_default();
+        sort.push(es_client.engine().pit_tiebreaker_sort());
+
+        let mut results: Vec<T> = Vec::new();
+        let mut search_after: Option<Value> = None;
+
+        loop {
+            let mut page_query: Value = json!({
+                "size": SEARCH_PAGE_SIZE,
+                "query": query,
+                "sort": sort,
+                "pit": { "id": pit_id, "keep_alive": PIT_KEEP_ALIVE },
+                "track_total_hits": false
+            });
+
+            if let Some(after) = search_after.take() {
+                page_query["search_after"] = after;
+            }
+
+            let response_body: Value = es_client.get_search_query_pit(&page_query).await?;
+
+            /* 응답마다 PIT id 가 바뀔 수 있으므로 항상 최신 id 를 사용 */
+            if let Some(new_pit_id) = response_body["pit_id"].as_str() {
+                *pit_id = new_pit_id.to_string();
+            }
+
+            let page_len: usize = response_body["hits"]["hits"]
+                .as_array()
+                .map_or(0, |hits| hits.len());
+
+            search_after = response_body["hits"]["hits"]
+                .as_array()
+                .and_then(|hits| hits.last())
+                .map(|hit| hit["sort"].clone());
+
+            results.extend(self.get_query_result_vec::<T, S>(&response_body)?);
+
+            if page_len < SEARCH_PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(results)
+    }
 }
 
 #[async_trait]
 impl QueryService for QueryServicePub {
     #[doc = "색인 동작 로그를 가져오는 함수"]
     /// # Arguments
+    /// * `cluster_name` - 색인 로그가 있는 클러스터 이름
     /// * `query_index` - 쿼리의 대상이 되는 Elasticsearch 인덱스 이름
     /// * `index_name`  - 색인될 인덱스의 이름
     /// * `index_type`  - 정적색인인지 동적색인인지 구분하는 타입
@@ -133,6 +234,7 @@ impl QueryService for QueryServicePub {
     /// * Result<Vec<VectorIndexLog>, anyhow::Error>
     async fn get_indexing_movement_log(
         &self,
+        cluster_name: &str,
         query_index: &str,
         index_name: &str,
         index_type: &str,
@@ -141,7 +243,7 @@ impl QueryService for QueryServicePub {
     ) -> Result<VectorIndexLogFormat, anyhow::Error> {
         let start_dt_str: String = get_str_from_naive_datetime(start_dt, "%Y-%m-%dT%H:%M:%SZ")?;
         let end_dt_str: String = get_str_from_naive_datetime(end_dt, "%Y-%m-%dT%H:%M:%SZ")?;
-        
+
         let query: Value = json!({
             "size": 1,                       /* 최신 한 건만 */
             "track_total_hits": false,       /* 총건수 집계 불필요 - 성능상 좋음 */
@@ -163,7 +265,7 @@ impl QueryService for QueryServicePub {
             ]
         });
 
-        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+        let es_client: ElasticConnGuard = get_cluster_guard_conn(cluster_name).await?;
         let response_body: Value = es_client.get_search_query(&query, query_index).await?;
 
         let result: VectorIndexLogFormat =
@@ -172,27 +274,240 @@ impl QueryService for QueryServicePub {
         Ok(result)
     }
 
-    #[doc = "색인 실패 정보를 모니터링 Elasitcsearch 인덱스에 색인해주는 함수"]
+    #[doc = "색인 실패 정보를 장애(incident) 단위로 모니터링 인덱스에 반영해주는 함수"]
+    /// 같은 fingerprint 의 진행중인(open / acknowledged / dispatched) 장애가 있으면 last_seen / occurrence_count 만 갱신하고,
+    /// 없으면(해결된 장애만 있으면) 새 장애 문서를 만든다. 해결된 장애 문서는 SLA / 이력 집계를 위해 그대로 둔다.
+    ///
     /// # Arguments
     /// * `index_name`  - 에러메시지 정보가 들어있는 인덱스 이름
+    /// * `error_alaram_info` - fingerprint 가 채워진 에러 알람 정보
     ///
     /// # Returns
     /// * Result<(), anyhow::Error>
-    async fn post_indexing_error_info(
+    async fn upsert_incident(
         &self,
         index_name: &str,
-        error_alaram_info: ErrorAlarmInfo,
+        error_alaram_info: &ErrorAlarmInfo,
     ) -> Result<(), anyhow::Error> {
+        if error_alaram_info.fingerprint().is_empty() {
+            return Err(anyhow!(
+                "[QueryServicePub->upsert_incident] fingerprint is empty: {}",
+                error_alaram_info.index_name()
+            ));
+        }
+
         let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
 
-        es_client
-            .post_query_struct(&error_alaram_info, index_name)
+        /* 이전 버전 문서(id 가 fingerprint)도 진행중이면 그대로 이어서 갱신한다. */
+        let open_incident_query: Value = json!({
+            "size": 1,
+            "_source": false,
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "term": { "fingerprint.keyword": error_alaram_info.fingerprint() } },
+                        { "terms": { "alarm_state.keyword": [
+                            ALARM_STATE_OPEN,
+                            ALARM_STATE_ACKNOWLEDGED,
+                            ALARM_STATE_DISPATCHED
+                        ] } }
+                    ]
+                }
+            },
+            "sort": [
+                { "first_seen": { "order": "desc", "unmapped_type": "date" } }
+            ]
+        });
+        let response_body: Value = es_client
+            .get_search_query(&open_incident_query, index_name)
             .await?;
 
-        Ok(())
+        let doc_id: String = match response_body["hits"]["hits"][0]["_id"].as_str() {
+            Some(doc_id) => doc_id.to_string(),
+            None => incident_doc_id(
+                error_alaram_info.fingerprint(),
+                error_alaram_info.first_seen(),
+            ),
+        };
+
+        /*
+            확인(ack) / 발송(dispatched) 상태는 그대로 둔다 - 같은 장애로 다시 알람이 나가지 않도록.
+            조회한 뒤 그 사이에 해결(resolved)됐으면 해결된 장애 문서는 건드리지 않는다.
+        */
+        let script: Value = json!({
+            "lang": "painless",
+            "source": "
+                if (ctx._source.alarm_state == params.resolved) {
+                    ctx.op = 'noop';
+                    return;
+                }
+                if (ctx._source.first_seen == null) {
+                    ctx._source.first_seen = ctx._source['@timestamp'];
+                }
+                ctx._source.occurrence_count = (ctx._source.occurrence_count == null ? 1 : ctx._source.occurrence_count) + 1;
+                ctx._source.last_seen = params.seen;
+                ctx._source['@timestamp'] = params.seen;
+                ctx._source.reason = params.reason;
+                ctx._source.indexing_cnt_num = params.indexing_cnt_num;
+                ctx._source.declare_index_size = params.declare_index_size;
+                ctx._source.duration = params.duration;
+                ctx._source.index_type = params.index_type;
+            ",
+            "params": {
+                "seen": error_alaram_info.timestamp(),
+                "reason": error_alaram_info.reason(),
+                "indexing_cnt_num": error_alaram_info.indexing_cnt_num(),
+                "declare_index_size": error_alaram_info.declare_index_size(),
+                "duration": error_alaram_info.duration(),
+                "index_type": error_alaram_info.index_type(),
+                "resolved": ALARM_STATE_RESOLVED
+            }
+        });
+        let upsert_document: Value = convert_json_from_struct(error_alaram_info)?;
+
+        es_client
+            .upsert_document(&doc_id, &script, &upsert_document, index_name)
+            .await
     }
 
-    #[doc = "색인 에러 정보들을 반환해주는 함수"]
+    #[doc = "체크가 성공한 인덱스의 진행중인 장애들을 해결(resolved) 처리해주는 함수"]
+    /// # Arguments
+    /// * `err_monitor_index` - 에러메시지 정보가 들어있는 인덱스 이름
+    /// * `cluster_name` - 장애가 발생한 클러스터 이름
+    /// * `index_name` - 체크가 성공한 색인 대상 인덱스 이름
+    /// * `error_types` - 해결 처리할 장애의 에러 종류
+    /// * `resolved_at_kst` - 해결 시각 (한국시간 문자열)
+    ///
+    /// # Returns
+    /// * Result<u64, anyhow::Error> - 해결 처리된 장애 개수
+    async fn resolve_incidents(
+        &self,
+        err_monitor_index: &str,
+        cluster_name: &str,
+        index_name: &str,
+        error_types: &[&str],
+        resolved_at_kst: &str,
+    ) -> Result<u64, anyhow::Error> {
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+
+        /* cluster_name 이 없는 이전 버전 알람 문서도 같은 장애로 본다. */
+        let query: Value = json!({
+            "script": {
+                "lang": "painless",
+                "source": "ctx._source.alarm_state = params.state; ctx._source.resolved_at = params.resolved_at",
+                "params": { "state": ALARM_STATE_RESOLVED, "resolved_at": resolved_at_kst }
+            },
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "term": { "index_name.keyword": index_name } },
+                        { "terms": { "error_type.keyword": error_types } },
+                        {
+                            "bool": {
+                                "should": [
+                                    { "term": { "cluster_name.keyword": cluster_name } },
+                                    { "bool": { "must_not": { "exists": { "field": "cluster_name" } } } }
+                                ],
+                                "minimum_should_match": 1
+                            }
+                        }
+                    ],
+                    "must_not": [
+                        { "term": { "alarm_state.keyword": ALARM_STATE_RESOLVED } }
+                    ]
+                }
+            }
+        });
+
+        let response_body: Value = es_client.update_by_query(&query, err_monitor_index).await?;
+
+        let updated: u64 = response_body
+            .get("updated")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0);
+
+        Ok(updated)
+    }
+
+    #[doc = "클러스터의 인덱스 문서 수를 반환해주는 함수"]
+    /// # Arguments
+    /// * `cluster_name` - 조회할 클러스터 이름
+    /// * `index_name` - 문서 수를 셀 인덱스 이름
+    ///
+    /// # Returns
+    /// * Result<u64, anyhow::Error>
+    async fn get_doc_count(
+        &self,
+        cluster_name: &str,
+        index_name: &str,
+    ) -> Result<u64, anyhow::Error> {
+        let query: Value = json!({ "query": { "match_all": {} } });
+
+        let es_client: ElasticConnGuard = get_cluster_guard_conn(cluster_name).await?;
+        es_client.count(&query, index_name).await
+    }
+
+    #[doc = "클러스터의 인덱스에서 필드 값(term)별 문서 수를 반환해주는 함수 - 값 순서대로 size 개까지"]
+    /// # Arguments
+    /// * `cluster_name` - 조회할 클러스터 이름
+    /// * `index_name` - 집계할 인덱스 이름
+    /// * `field` - terms aggregation 대상 필드 (keyword)
+    /// * `size` - 집계할 최대 값 개수
+    ///
+    /// # Returns
+    /// * Result<BTreeMap<String, u64>, anyhow::Error>
+    async fn get_term_counts(
+        &self,
+        cluster_name: &str,
+        index_name: &str,
+        field: &str,
+        size: usize,
+    ) -> Result<BTreeMap<String, u64>, anyhow::Error> {
+        /* 두 클러스터가 같은 값들을 집계하도록 문서 수가 아닌 값 순서로 정렬 */
+        let query: Value = json!({
+            "size": 0,
+            "track_total_hits": false,
+            "aggs": {
+                "term_counts": {
+                    "terms": {
+                        "field": field,
+                        "size": size,
+                        "order": { "_key": "asc" }
+                    }
+                }
+            }
+        });
+
+        let es_client: ElasticConnGuard = get_cluster_guard_conn(cluster_name).await?;
+        let response_body: Value = es_client.get_search_query(&query, index_name).await?;
+
+        let buckets: &Vec<Value> = response_body["aggregations"]["term_counts"]["buckets"]
+            .as_array()
+            .ok_or_else(|| {
+                anyhow!(
+                    "[QueryServicePub->get_term_counts] Missing buckets in aggregation response: {}",
+                    index_name
+                )
+            })?;
+
+        let term_counts: BTreeMap<String, u64> = buckets
+            .iter()
+            .map(|bucket| {
+                /* 숫자 / 날짜 필드는 key_as_string 이 있으면 그 값을 사용 */
+                let term: String = match bucket.get("key_as_string").or(bucket.get("key")) {
+                    Some(Value::String(key)) => key.to_string(),
+                    Some(key) => key.to_string(),
+                    None => String::new(),
+                };
+
+                (term, bucket["doc_count"].as_u64().unwrap_or(0))
+            })
+            .collect();
+
+        Ok(term_counts)
+    }
+
+    #[doc = "열려있는(open) 색인 에러 정보들을 반환해주는 함수"]
     /// # Arguments
     /// * `index_name`  - 에러메시지 정보가 들어있는 인덱스 이름
     ///
@@ -202,36 +517,662 @@ impl QueryService for QueryServicePub {
         &self,
         index_name: &str,
     ) -> Result<Vec<ErrorAlarmInfoFormat>, anyhow::Error> {
+        /*
+            확인(ack) 되었거나 이미 발송된 증분색인 알람, 해결된 장애는 제외
+            (alarm_state 가 없는 이전 버전 알람은 열린 알람으로 본다.)
+        */
+        let query: Value = json!({
+            "bool": {
+                "must_not": [
+                    { "terms": { "alarm_state.keyword": [ALARM_STATE_ACKNOWLEDGED, ALARM_STATE_DISPATCHED, ALARM_STATE_RESOLVED] } }
+                ]
+            }
+        });
+        let sort: Value = json!([{ "@timestamp": { "order": "asc" } }]);
+
+        /* 알람이 많이 쌓여도 누락되지 않도록 전부 조회 (오래된 알람부터) */
+        let err_alram_infos: Vec<ErrorAlarmInfoFormat> = self
+            .search_all_pages::<ErrorAlarmInfoFormat, ErrorAlarmInfo>(index_name, query, sort)
+            .await?;
+
+        Ok(err_alram_infos)
+    }
+
+    #[doc = "기간과 겹치는 장애(incident)들을 상태와 관계없이 처음 발견된 순서로 반환해주는 함수"]
+    /// 장애 문서의 @timestamp 는 마지막 발견 시각으로 덮어써지므로 first_seen / last_seen 으로 찾는다.
+    /// (first_seen / last_seen 이 없는 이전 버전 문서는 @timestamp 로 찾는다.)
+    ///
+    /// # Arguments
+    /// * `index_name` - 에러메시지 정보가 들어있는 인덱스 이름
+    /// * `start_dt` - 조회 시작 시각 (한국시간, 포함)
+    /// * `end_dt` - 조회 종료 시각 (한국시간, 미포함)
+    ///
+    /// # Returns
+    /// * Result<Vec<ErrorAlarmInfo>, anyhow::Error>
+    async fn get_error_alarm_history(
+        &self,
+        index_name: &str,
+        start_dt: NaiveDateTime,
+        end_dt: NaiveDateTime,
+    ) -> Result<Vec<ErrorAlarmInfo>, anyhow::Error> {
+        /* 알람 문서의 시각은 한국시간으로 기록되어 있다. */
+        let start_dt_str: String = get_str_from_naive_datetime(start_dt, "%Y-%m-%dT%H:%M:%SZ")?;
+        let end_dt_str: String = get_str_from_naive_datetime(end_dt, "%Y-%m-%dT%H:%M:%SZ")?;
+
+        let query: Value = json!({
+            "bool": {
+                "should": [
+                    {
+                        "bool": {
+                            "filter": [
+                                { "range": { "first_seen": { "lt": end_dt_str } } },
+                                { "range": { "last_seen": { "gte": start_dt_str } } }
+                            ]
+                        }
+                    },
+                    {
+                        "bool": {
+                            "must_not": [
+                                { "exists": { "field": "last_seen" } }
+                            ],
+                            "filter": [
+                                { "range": { "@timestamp": { "gte": start_dt_str, "lt": end_dt_str } } }
+                            ]
+                        }
+                    }
+                ],
+                "minimum_should_match": 1
+            }
+        });
+        let sort: Value = json!([{ "@timestamp": { "order": "asc" } }]);
+
+        let mut err_alarm_infos: Vec<ErrorAlarmInfo> = self
+            .search_all_pages::<ErrorAlarmInfoFormat, ErrorAlarmInfo>(index_name, query, sort)
+            .await?
+            .into_iter()
+            .map(|alarm| alarm.error_alarm_info)
+            .collect();
+        err_alarm_infos
+            .sort_by(|a, b| a.first_seen_or_timestamp().cmp(b.first_seen_or_timestamp()));
+
+        Ok(err_alarm_infos)
+    }
+
+    #[doc = "발송이 끝난 알람 중 증분색인(dynamic index) 알람을 dispatched 상태로 바꿔주는 함수"]
+    /// # Arguments
+    /// * `err_monitor_index` - 에러메시지 정보가 들어있는 인덱스 이름
+    /// * `doc_ids` - 알림이 발송된 알람 문서의 id 목록 (정해진 인덱스 알람은 그대로 둔다)
+    ///
+    /// # Returns
+    /// * Result<u64, anyhow::Error> - dispatched 로 바뀐 알람 개수
+    async fn dispatch_dynamic_alarms(
+        &self,
+        err_monitor_index: &str,
+        doc_ids: &[String],
+    ) -> Result<u64, anyhow::Error> {
         let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
 
         let query: Value = json!({
+            "script": {
+                "lang": "painless",
+                "source": "ctx._source.alarm_state = params.state",
+                "params": { "state": ALARM_STATE_DISPATCHED }
+            },
             "query": {
-                "match_all": {}
+                "bool": {
+                    "filter": [
+                        { "ids": { "values": doc_ids } },
+                        { "term": { "index_type.keyword": "dynamic index" } },
+                        { "term": { "alarm_state.keyword": ALARM_STATE_OPEN } }
+                    ]
+                }
+            }
+        });
+
+        let response_body: Value = es_client.update_by_query(&query, err_monitor_index).await?;
+
+        let updated: u64 = response_body
+            .get("updated")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0);
+
+        Ok(updated)
+    }
+
+    #[doc = "특정 인덱스의 열린 알람을 확인(ack) 처리해주는 함수"]
+    /// # Arguments
+    /// * `err_monitor_index` - 에러메시지 정보가 들어있는 인덱스 이름
+    /// * `index_name` - 알람을 확인 처리할 색인 대상 인덱스 이름
+    ///
+    /// # Returns
+    /// * Result<u64, anyhow::Error> - 확인 처리된 알람 개수
+    async fn acknowledge_alarms_by_index(
+        &self,
+        err_monitor_index: &str,
+        index_name: &str,
+    ) -> Result<u64, anyhow::Error> {
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+
+        let query: Value = json!({
+            "script": {
+                "lang": "painless",
+                "source": "ctx._source.alarm_state = params.state",
+                "params": { "state": ALARM_STATE_ACKNOWLEDGED }
             },
-            "size": 1000
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "term": { "index_name.keyword": index_name } }
+                    ],
+                    "must_not": [
+                        { "terms": { "alarm_state.keyword": [ALARM_STATE_ACKNOWLEDGED, ALARM_STATE_DISPATCHED, ALARM_STATE_RESOLVED] } }
+                    ]
+                }
+            }
         });
 
-        let response_body: Value = es_client.get_search_query(&query, index_name).await?;
-        let err_alram_infos: Vec<ErrorAlarmInfoFormat> =
-            self.get_query_result_vec::<ErrorAlarmInfoFormat, ErrorAlarmInfo>(&response_body)?;
+        let response_body: Value = es_client.update_by_query(&query, err_monitor_index).await?;
 
-        Ok(err_alram_infos)
+        let updated: u64 = response_body
+            .get("updated")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0);
+
+        Ok(updated)
     }
 
-    #[doc = "특정 인덱스의 특정 문서를 삭제해주는 함수"]
+    #[doc = "발송 대기 알림들을 outbox 인덱스에 bulk 로 저장해주는 함수"]
     /// # Arguments
-    /// * `index_name` - 삭제 대상이 되는 인덱스 이름
-    /// * `doc_id` - 삭제할 문서의 id
+    /// * `outbox_index` - 발송 대기열 인덱스 이름
+    /// * `notifications` - 발송 대기 알림 목록
     ///
     /// # Returns
-    /// * Result<Vec<ErrorAlarmInfo>, anyhow::Error>
-    async fn delete_index_by_doc(
+    /// * Result<BulkResult, anyhow::Error> - 알림별 처리 결과
+    async fn post_notification_outboxes(
+        &self,
+        outbox_index: &str,
+        notifications: &[NotificationOutbox],
+    ) -> Result<BulkResult, anyhow::Error> {
+        let operations: Vec<BulkOperation> = notifications
+            .iter()
+            .map(BulkOperation::index_from_struct)
+            .collect::<Result<_, _>>()?;
+
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+        es_client.bulk(&operations, outbox_index).await
+    }
+
+    #[doc = "지금 발송(재시도)해야 하는 알림들을 오래된 순으로 가져오는 함수"]
+    /// # Arguments
+    /// * `outbox_index` - 발송 대기열 인덱스 이름
+    /// * `now_utc` - 현재 시각 (UTC)
+    /// * `size` - 한번에 가져올 최대 개수
+    ///
+    /// # Returns
+    /// * Result<Vec<NotificationOutboxFormat>, anyhow::Error>
+    async fn get_due_outbox_entries(
+        &self,
+        outbox_index: &str,
+        now_utc: NaiveDateTime,
+        size: usize,
+    ) -> Result<Vec<NotificationOutboxFormat>, anyhow::Error> {
+        let now_utc_str: String = get_str_from_naive_datetime(now_utc, OUTBOX_TIME_FORMAT)?;
+
+        let query: Value = json!({
+            "size": size,
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "term": { "status.keyword": OUTBOX_STATUS_PENDING } },
+                        { "range": { "next_attempt_at": { "lte": now_utc_str } } }
+                    ]
+                }
+            },
+            "sort": [
+                { "next_attempt_at": { "order": "asc" } }
+            ]
+        });
+
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+        let response_body: Value = es_client.get_search_query(&query, outbox_index).await?;
+
+        self.get_query_result_vec::<NotificationOutboxFormat, NotificationOutbox>(&response_body)
+    }
+
+    #[doc = "아직 발송되지 않은 알림이 다루고 있는 알람 문서 id 들을 가져오는 함수"]
+    /// # Arguments
+    /// * `outbox_index` - 발송 대기열 인덱스 이름
+    ///
+    /// # Returns
+    /// * Result<HashSet<String>, anyhow::Error>
+    async fn get_pending_outbox_alarm_ids(
+        &self,
+        outbox_index: &str,
+    ) -> Result<HashSet<String>, anyhow::Error> {
+        let query: Value = json!({
+            "size": 0,
+            "query": {
+                "term": { "status.keyword": OUTBOX_STATUS_PENDING }
+            },
+            "aggs": {
+                "alarm_doc_ids": {
+                    "terms": { "field": "alarm_doc_ids.keyword", "size": 10000 }
+                }
+            }
+        });
+
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+        let response_body: Value = es_client.get_search_query(&query, outbox_index).await?;
+
+        /* outbox 인덱스가 아직 없으면 aggregations 가 비어있다. */
+        let alarm_doc_ids: HashSet<String> = response_body
+            .pointer("/aggregations/alarm_doc_ids/buckets")
+            .and_then(|buckets| buckets.as_array())
+            .map(|buckets| {
+                buckets
+                    .iter()
+                    .filter_map(|bucket| bucket.get("key").and_then(|key| key.as_str()))
+                    .map(|key| key.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(alarm_doc_ids)
+    }
+
+    #[doc = "발송을 시도한 알림들의 결과를 bulk 로 한번에 outbox 에 반영해주는 함수"]
+    /// # Arguments
+    /// * `outbox_index` - 발송 대기열 인덱스 이름
+    /// * `settlements` - (문서 id, 발송 결과) 목록 - 발송 완료는 삭제, 실패는 재시도 정보 갱신
+    ///
+    /// # Returns
+    /// * Result<BulkResult, anyhow::Error> - 문서별 처리 결과
+    async fn settle_outbox_entries(
+        &self,
+        outbox_index: &str,
+        settlements: &[(String, OutboxSettlement)],
+    ) -> Result<BulkResult, anyhow::Error> {
+        let operations: Vec<BulkOperation> = settlements
+            .iter()
+            .map(|(doc_id, settlement)| match settlement {
+                OutboxSettlement::Delivered => BulkOperation::Delete {
+                    doc_id: doc_id.clone(),
+                },
+                OutboxSettlement::Failed(notification) => BulkOperation::update(
+                    doc_id,
+                    json!({
+                        "status": notification.status(),
+                        "attempts": notification.attempts(),
+                        "next_attempt_at": notification.next_attempt_at(),
+                        "last_error": notification.last_error()
+                    }),
+                ),
+            })
+            .collect();
+
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+        es_client.bulk(&operations, outbox_index).await
+    }
+
+    #[doc = "composable index template 을 설치(덮어쓰기) 해주는 함수"]
+    /// # Arguments
+    /// * `template_name` - index template 이름
+    /// * `template` - index template 본문
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    async fn install_index_template(
+        &self,
+        template_name: &str,
+        template: &Value,
+    ) -> Result<(), anyhow::Error> {
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+        es_client.put_index_template(template_name, template).await
+    }
+
+    #[doc = "ILM policy 를 설치(덮어쓰기) 해주는 함수"]
+    /// # Arguments
+    /// * `policy_name` - ILM policy 이름
+    /// * `policy` - ILM policy 본문
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    async fn install_lifecycle_policy(
+        &self,
+        policy_name: &str,
+        policy: &Value,
+    ) -> Result<(), anyhow::Error> {
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+        es_client.put_lifecycle_policy(policy_name, policy).await
+    }
+
+    #[doc = "이미 만들어진 인덱스가 있으면 mapping 을 갱신해주는 함수 (없으면 template 이 적용되도록 그대로 둔다)"]
+    /// # Arguments
+    /// * `index_name` - 대상 인덱스 이름
+    /// * `mapping` - 인덱스 mapping
+    ///
+    /// # Returns
+    /// * Result<bool, anyhow::Error> - 인덱스가 이미 있었는지 여부
+    async fn update_index_mapping(
         &self,
         index_name: &str,
-        doc_id: &str,
+        mapping: &Value,
+    ) -> Result<bool, anyhow::Error> {
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+
+        if !es_client.index_exists(index_name).await? {
+            return Ok(false);
+        }
+
+        /*
+            기존 필드의 타입은 바꿀 수 없다. (ex. dynamic mapping 으로 text 가 된 @timestamp)
+            그대로 두면 range / sort 쿼리가 계속 실패하므로 옮기는 방법과 함께 실패로 알린다.
+        */
+        let mapping_conflicts: Vec<String> =
+            find_mapping_conflicts(mapping, &es_client.get_mapping(index_name).await?);
+
+        if !mapping_conflicts.is_empty() {
+            return Err(anyhow!(AppError::Elasticsearch(format!(
+                "[QueryServicePub->update_index_mapping] '{}' has field types that cannot be changed ({}). \
+                Reindex it into a backup index, delete '{}', run --bootstrap again and reindex the backup back \
+                (see README 'mapping 충돌')",
+                index_name,
+                mapping_conflicts.join(", "),
+                index_name
+            ))));
+        }
+
+        /* 새로 추가된 필드만 반영된다. */
+        es_client.put_mapping(index_name, mapping).await?;
+
+        Ok(true)
+    }
+
+    #[doc = "data stream 이 없으면 만들어주는 함수"]
+    /// # Arguments
+    /// * `data_stream_name` - data stream 이름 (index template 이 먼저 설치되어 있어야 한다.)
+    /// * `mapping` - 같은 이름의 일반 인덱스가 이미 있을 때 갱신할 mapping
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    async fn ensure_data_stream(
+        &self,
+        data_stream_name: &str,
+        mapping: &Value,
     ) -> Result<(), anyhow::Error> {
         let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
-        es_client.delete_query(doc_id, index_name).await?;
-        Ok(())
+
+        if es_client.data_stream_exists(data_stream_name).await? {
+            return Ok(());
+        }
+
+        if es_client.index_exists(data_stream_name).await? {
+            /* 이전 버전이 만든 일반 인덱스 - rollover/보관 기간은 적용되지 않는다. */
+            warn!(
+                "[QueryServicePub->ensure_data_stream] '{}' is a regular index, not a data stream. Reindex it to apply the lifecycle policy.",
+                data_stream_name
+            );
+            return es_client.put_mapping(data_stream_name, mapping).await;
+        }
+
+        es_client.create_data_stream(data_stream_name).await
+    }
+
+    #[doc = "보관 기간이 지난 확인(ack)/발송 완료 알람을 삭제해주는 함수"]
+    /// # Arguments
+    /// * `err_monitor_index` - 알람 인덱스 이름
+    /// * `before_kst` - 이 시각(한국시간) 이전에 발생한 알람만 삭제
+    ///
+    /// # Returns
+    /// * Result<u64, anyhow::Error> - 삭제된 문서 수
+    async fn delete_closed_alarms(
+        &self,
+        err_monitor_index: &str,
+        before_kst: NaiveDateTime,
+    ) -> Result<u64, anyhow::Error> {
+        /* 알람 문서의 @timestamp 는 한국시간 문자열로 저장되어 있다. */
+        let query: Value = json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "terms": { "alarm_state.keyword": [ALARM_STATE_ACKNOWLEDGED, ALARM_STATE_DISPATCHED, ALARM_STATE_RESOLVED] } },
+                        { "range": { "@timestamp": { "lt": before_kst.format("%Y-%m-%dT%H:%M:%SZ").to_string() } } }
+                    ]
+                }
+            }
+        });
+
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+        let response: Value = es_client.delete_by_query(&query, err_monitor_index).await?;
+
+        Ok(response["deleted"].as_u64().unwrap_or(0))
+    }
+
+    #[doc = "보관 기간이 지난 발송 포기(expired) 알림을 삭제해주는 함수"]
+    /// # Arguments
+    /// * `outbox_index` - 발송 대기열 인덱스 이름
+    /// * `before_utc` - 이 시각(UTC) 이전에 적재된 알림만 삭제
+    ///
+    /// # Returns
+    /// * Result<u64, anyhow::Error> - 삭제된 문서 수
+    async fn delete_expired_outbox(
+        &self,
+        outbox_index: &str,
+        before_utc: NaiveDateTime,
+    ) -> Result<u64, anyhow::Error> {
+        let query: Value = json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        { "term": { "status.keyword": OUTBOX_STATUS_EXPIRED } },
+                        { "range": { "@timestamp": { "lt": before_utc.format(OUTBOX_TIME_FORMAT).to_string() } } }
+                    ]
+                }
+            }
+        });
+
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+        let response: Value = es_client.delete_by_query(&query, outbox_index).await?;
+
+        Ok(response["deleted"].as_u64().unwrap_or(0))
+    }
+
+    #[doc = "색인 체크 결과들을 이력 data stream 에 bulk 로 저장해주는 함수"]
+    /// # Arguments
+    /// * `history_index` - 체크 이력 인덱스 이름
+    /// * `check_histories` - 체크 결과 이력 목록
+    ///
+    /// # Returns
+    /// * Result<BulkResult, anyhow::Error> - 이력별 처리 결과
+    async fn post_check_histories(
+        &self,
+        history_index: &str,
+        check_histories: &[CheckHistory],
+    ) -> Result<BulkResult, anyhow::Error> {
+        let operations: Vec<BulkOperation> = check_histories
+            .iter()
+            .map(BulkOperation::create_from_struct)
+            .collect::<Result<_, _>>()?;
+
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+        es_client.bulk(&operations, history_index).await
+    }
+
+    #[doc = "기간 내 특정 인덱스의 체크 이력을 시간순으로 가져오는 함수"]
+    /// # Arguments
+    /// * `history_index` - 체크 이력 인덱스 이름
+    /// * `index_name` - 색인 대상 인덱스 이름
+    /// * `start_dt` - 조회 시작 시각 (UTC, 포함)
+    /// * `end_dt` - 조회 종료 시각 (UTC, 미포함)
+    ///
+    /// # Returns
+    /// * Result<Vec<CheckHistory>, anyhow::Error>
+    async fn get_check_history(
+        &self,
+        history_index: &str,
+        index_name: &str,
+        start_dt: NaiveDateTime,
+        end_dt: NaiveDateTime,
+    ) -> Result<Vec<CheckHistory>, anyhow::Error> {
+        let start_dt_str: String =
+            get_str_from_naive_datetime(start_dt, CHECK_HISTORY_TIME_FORMAT)?;
+        let end_dt_str: String = get_str_from_naive_datetime(end_dt, CHECK_HISTORY_TIME_FORMAT)?;
+
+        let query: Value = json!({
+            "bool": {
+                "filter": [
+                    { "term": { "index_name": index_name } },
+                    { "range": { "@timestamp": {
+                        "gte": start_dt_str,
+                        "lt": end_dt_str
+                    }}}
+                ]
+            }
+        });
+        let sort: Value = json!([{ "@timestamp": { "order": "asc" } }]);
+
+        /* 한 달치 분 단위 스케쥴은 수만 건이 되므로 페이지를 나눠서 전부 조회 */
+        let check_histories: Vec<CheckHistory> = self
+            .search_all_pages::<CheckHistoryFormat, CheckHistory>(history_index, query, sort)
+            .await?
+            .into_iter()
+            .map(|history| history.check_history)
+            .collect();
+
+        Ok(check_histories)
+    }
+
+    #[doc = "리더 임대(lease) 문서를 seq_no / primary_term 과 함께 가져오는 함수"]
+    /// # Arguments
+    /// * `lease_index` - 임대 문서 인덱스 이름
+    /// * `lease_id` - 임대 문서 id
+    ///
+    /// # Returns
+    /// * Result<Option<LeaderLeaseFormat>, anyhow::Error> - 문서가 없으면 None
+    async fn get_leader_lease(
+        &self,
+        lease_index: &str,
+        lease_id: &str,
+    ) -> Result<Option<LeaderLeaseFormat>, anyhow::Error> {
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+
+        let response_body: Value = match es_client.get_document(lease_id, lease_index).await? {
+            Some(response_body) => response_body,
+            None => return Ok(None),
+        };
+
+        if !response_body["found"].as_bool().unwrap_or(false) {
+            return Ok(None);
+        }
+
+        let leader_lease: LeaderLease = serde_json::from_value(response_body["_source"].clone())?;
+        let seq_no: i64 = response_body["_seq_no"].as_i64().ok_or_else(|| {
+            anyhow!("[QueryServicePub->get_leader_lease] Missing '_seq_no' field")
+        })?;
+        let primary_term: i64 = response_body["_primary_term"].as_i64().ok_or_else(|| {
+            anyhow!("[QueryServicePub->get_leader_lease] Missing '_primary_term' field")
+        })?;
+
+        Ok(Some(LeaderLeaseFormat::new(
+            leader_lease,
+            seq_no,
+            primary_term,
+        )))
+    }
+
+    #[doc = "조회한 뒤로 다른 인스턴스가 바꾸지 않았을 때만 리더 임대 문서를 저장해주는 함수"]
+    /// # Arguments
+    /// * `lease_index` - 임대 문서 인덱스 이름
+    /// * `lease_id` - 임대 문서 id
+    /// * `leader_lease` - 저장할 임대 문서
+    /// * `current` - 조회했던 임대 문서 (없었으면 None - 새로 만든다.)
+    ///
+    /// # Returns
+    /// * Result<bool, anyhow::Error> - 다른 인스턴스와 충돌해서 저장하지 못했으면 false
+    async fn put_leader_lease(
+        &self,
+        lease_index: &str,
+        lease_id: &str,
+        leader_lease: &LeaderLease,
+        current: Option<&LeaderLeaseFormat>,
+    ) -> Result<bool, anyhow::Error> {
+        let document: Value = convert_json_from_struct(leader_lease)?;
+        let if_seq_no_primary_term: Option<(i64, i64)> =
+            current.map(|lease| (*lease.seq_no(), *lease.primary_term()));
+
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+        es_client
+            .put_document_if(lease_id, &document, lease_index, if_seq_no_primary_term)
+            .await
+    }
+
+    #[doc = "인덱스의 알람 일시중지 문서를 저장해주는 함수 - 같은 인덱스의 이전 일시중지는 덮어쓴다."]
+    /// # Arguments
+    /// * `lease_index` - 임대 문서 인덱스 이름
+    /// * `alarm_snooze` - 저장할 일시중지 문서
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    async fn put_alarm_snooze(
+        &self,
+        lease_index: &str,
+        alarm_snooze: &AlarmSnooze,
+    ) -> Result<(), anyhow::Error> {
+        let operation: BulkOperation = BulkOperation::Index {
+            doc_id: Some(alarm_snooze.doc_id()),
+            document: convert_json_from_struct(alarm_snooze)?,
+        };
+
+        let es_client: ElasticConnGuard = get_elastic_guard_conn().await?;
+        es_client
+            .bulk(&[operation], lease_index)
+            .await?
+            .ensure_success()
+    }
+
+    #[doc = "아직 만료되지 않은 알람 일시중지 문서들을 가져오는 함수"]
+    /// # Arguments
+    /// * `lease_index` - 임대 문서 인덱스 이름
+    /// * `now_utc` - 현재 시각 (UTC)
+    ///
+    /// # Returns
+    /// * Result<Vec<AlarmSnooze>, anyhow::Error>
+    async fn get_active_alarm_snoozes(
+        &self,
+        lease_index: &str,
+        now_utc: NaiveDateTime,
+    ) -> Result<Vec<AlarmSnooze>, anyhow::Error> {
+        let now_utc_str: String = get_str_from_naive_datetime(now_utc, LEADER_LEASE_TIME_FORMAT)?;
+
+        let query: Value = json!({
+            "bool": {
+                "filter": [
+                    { "range": { "snoozed_until": { "gt": now_utc_str } } }
+                ]
+            }
+        });
+        let sort: Value = json!([{ "snoozed_until": { "order": "asc" } }]);
+
+        self.search_all_pages::<AlarmSnooze, AlarmSnooze>(lease_index, query, sort)
+            .await
+    }
+
+    #[doc = "클러스터에서 노드 목록을 다시 가져와서(sniffing) 요청 대상 노드를 갱신해주는 함수"]
+    /// # Arguments
+    /// * `cluster_name` - 노드 목록을 갱신할 클러스터 이름
+    ///
+    /// # Returns
+    /// * Result<usize, anyhow::Error> - 갱신된 노드 개수
+    async fn refresh_es_nodes(&self, cluster_name: &str) -> Result<usize, anyhow::Error> {
+        let es_client: ElasticConnGuard = get_cluster_guard_conn(cluster_name).await?;
+        es_client.sniff_nodes().await
+    }
+
+    #[doc = "모든 클러스터의 Elasticsearch 노드별 연결 상태(circuit breaker)를 반환해주는 함수"]
+    fn get_es_node_states(&self) -> Vec<EsNodeStatus> {
+        get_elastic_repos()
+            .iter()
+            .flat_map(|es_repo| es_repo.node_states())
+            .collect()
     }
 }
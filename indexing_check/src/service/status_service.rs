@@ -0,0 +1,86 @@
+use crate::common::*;
+
+use crate::model::index_check_status::*;
+
+use crate::traits::service_traits::status_service_trait::*;
+
+#[derive(Debug, Default)]
+pub struct StatusServicePub {
+    /* 인덱스별 마지막 색인 체크 결과 */
+    check_status_map: RwLock<HashMap<String, IndexCheckStatus>>,
+    /* 인덱스별 알람 일시중지 만료 시각 (한국시간) - 임대 인덱스에 저장된 값을 alarm_task 가 주기적으로 다시 읽어서 채운다. */
+    snooze_map: RwLock<HashMap<String, NaiveDateTime>>,
+    /* 리더 임대 만료 시각 (UTC) - 리더 선출을 쓰지 않으면 항상 리더 */
+    leader_until: RwLock<Option<NaiveDateTime>>,
+    leader_election_enabled: bool,
+}
+
+impl StatusServicePub {
+    #[doc = "리더 선출을 사용하는 경우에는 임대를 얻기 전까지 리더가 아니다."]
+    pub fn new(leader_election_enabled: bool) -> Self {
+        Self {
+            leader_election_enabled,
+            ..Self::default()
+        }
+    }
+}
+
+impl StatusService for StatusServicePub {
+    #[doc = "인덱스의 마지막 색인 체크 결과를 저장해주는 함수"]
+    fn record_check_status(&self, check_status: IndexCheckStatus) {
+        match self.check_status_map.write() {
+            Ok(mut map) => {
+                map.insert(check_status.index_name().to_string(), check_status);
+            }
+            Err(e) => error!("[StatusServicePub->record_check_status] {:?}", e),
+        }
+    }
+
+    #[doc = "인덱스의 마지막 색인 체크 결과를 반환해주는 함수"]
+    fn get_check_status(&self, index_name: &str) -> Option<IndexCheckStatus> {
+        self.check_status_map
+            .read()
+            .ok()
+            .and_then(|map| map.get(index_name).cloned())
+    }
+
+    #[doc = "특정 인덱스의 알람을 until 시각까지 보내지 않도록 설정해주는 함수"]
+    fn snooze_index(&self, index_name: &str, until: NaiveDateTime) {
+        match self.snooze_map.write() {
+            Ok(mut map) => {
+                map.insert(index_name.to_string(), until);
+            }
+            Err(e) => error!("[StatusServicePub->snooze_index] {:?}", e),
+        }
+    }
+
+    #[doc = "알람 일시중지 중이라면 만료 시각을 반환해주는 함수"]
+    fn get_snoozed_until(&self, index_name: &str, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        self.snooze_map
+            .read()
+            .ok()
+            .and_then(|map| map.get(index_name).copied())
+            .filter(|until| *until > now)
+    }
+
+    #[doc = "리더 임대 만료 시각을 저장해주는 함수 (None: 리더 아님)"]
+    fn set_leader_until(&self, until: Option<NaiveDateTime>) {
+        match self.leader_until.write() {
+            Ok(mut leader_until) => *leader_until = until,
+            Err(e) => error!("[StatusServicePub->set_leader_until] {:?}", e),
+        }
+    }
+
+    #[doc = "지금 이 인스턴스가 체크/알림을 실행해야 하는지 여부"]
+    fn is_leader(&self, now: NaiveDateTime) -> bool {
+        if !self.leader_election_enabled {
+            return true;
+        }
+
+        self.leader_until
+            .read()
+            .ok()
+            .and_then(|leader_until| *leader_until)
+            .is_some_and(|until| until > now)
+    }
+}
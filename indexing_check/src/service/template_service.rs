@@ -0,0 +1,378 @@
+use crate::common::*;
+
+use crate::model::{
+    alarm_template_context::*, check_record::*, digest_template_context::*, email_struct::*,
+    error_alarm_info::*, error_alarm_info_format::*, index_check_status::*,
+    index_schedules_config::*, sla_report::*,
+};
+
+use crate::traits::service_traits::template_service_trait::*;
+
+use crate::env_configuration::env_config::*;
+
+/* '.html' 로 끝나는 템플릿은 Tera 가 자동으로 HTML escape 처리 */
+const ALARM_HTML_TEMPLATE: &str = "alarm_email.html";
+const ALARM_TEXT_TEMPLATE: &str = "alarm_email.txt";
+const DIGEST_HTML_TEMPLATE: &str = "digest_email.html";
+const DIGEST_TEXT_TEMPLATE: &str = "digest_email.txt";
+const REPORT_HTML_TEMPLATE: &str = "report_email.html";
+const REPORT_TEXT_TEMPLATE: &str = "report_email.txt";
+
+/* Tera 로 바꾸기 전 HTML 템플릿의 치환 문자열 - 그대로 두면 메일에 글자 그대로 나간다. */
+const LEGACY_PLACEHOLDERS: [&str; 2] = ["{cluster_name}", "{index_list}"];
+
+/* 경로가 지정되지 않았을 때 사용하는 기본 템플릿 */
+const DEFAULT_ALARM_TEXT_TEMPLATE: &str = include_str!("../../html/view.txt");
+const DEFAULT_DIGEST_HTML_TEMPLATE: &str = include_str!("../../html/digest.html");
+const DEFAULT_DIGEST_TEXT_TEMPLATE: &str = include_str!("../../html/digest.txt");
+const DEFAULT_REPORT_HTML_TEMPLATE: &str = include_str!("../../html/report.html");
+const DEFAULT_REPORT_TEXT_TEMPLATE: &str = include_str!("../../html/report.txt");
+
+#[derive(Debug)]
+pub struct TemplateServicePub {
+    tera: Tera,
+}
+
+impl TemplateServicePub {
+    #[doc = "템플릿 파일들을 읽어서 파싱/검증한 뒤 TemplateServicePub 을 만들어주는 함수"]
+    pub fn new() -> Result<Self, anyhow::Error> {
+        let html_template_path: String = get_html_template_path()?;
+        let html_template: String =
+            fs::read_to_string(Path::new(&html_template_path)).map_err(|e| {
+                anyhow!(
+                    "[TemplateServicePub->new] Failed to read '{}': {}",
+                    html_template_path,
+                    e
+                )
+            })?;
+
+        let legacy_placeholders: Vec<&str> = find_legacy_placeholders(&html_template);
+
+        if !legacy_placeholders.is_empty() {
+            return Err(anyhow!(
+                "[TemplateServicePub->new] '{}' uses the old placeholders {:?}. Replace '{{cluster_name}}' with '{{{{ cluster_name }}}}' and '{{index_list}}' with a '{{% for alarm in alarms %}}' loop (see html/view.html)",
+                html_template_path,
+                legacy_placeholders
+            ));
+        }
+
+        let text_template: String = read_template_or_default(
+            get_text_template_path().as_deref(),
+            DEFAULT_ALARM_TEXT_TEMPLATE,
+        )?;
+        let digest_html_template: String = read_template_or_default(
+            get_digest_html_template_path().as_deref(),
+            DEFAULT_DIGEST_HTML_TEMPLATE,
+        )?;
+        let digest_text_template: String = read_template_or_default(
+            get_digest_text_template_path().as_deref(),
+            DEFAULT_DIGEST_TEXT_TEMPLATE,
+        )?;
+
+        let report_html_template: String = read_template_or_default(
+            get_report_html_template_path().as_deref(),
+            DEFAULT_REPORT_HTML_TEMPLATE,
+        )?;
+        let report_text_template: String = read_template_or_default(
+            get_report_text_template_path().as_deref(),
+            DEFAULT_REPORT_TEXT_TEMPLATE,
+        )?;
+
+        let mut tera: Tera = Tera::default();
+
+        for (template_name, template) in [
+            (ALARM_HTML_TEMPLATE, &html_template),
+            (ALARM_TEXT_TEMPLATE, &text_template),
+            (DIGEST_HTML_TEMPLATE, &digest_html_template),
+            (DIGEST_TEXT_TEMPLATE, &digest_text_template),
+            (REPORT_HTML_TEMPLATE, &report_html_template),
+            (REPORT_TEXT_TEMPLATE, &report_text_template),
+        ] {
+            tera.add_raw_template(template_name, template)
+                .map_err(|e| {
+                    anyhow!(
+                        "[TemplateServicePub->new] Invalid template '{}': {:?}",
+                        template_name,
+                        e
+                    )
+                })?;
+        }
+
+        let template_service: TemplateServicePub = TemplateServicePub { tera };
+        template_service.validate()?;
+
+        Ok(template_service)
+    }
+
+    #[doc = "샘플 알람으로 렌더링을 해보고 템플릿에 정의되지 않은 변수가 없는지 확인"]
+    fn validate(&self) -> Result<(), anyhow::Error> {
+        let mut sample_info: ErrorAlarmInfo = ErrorAlarmInfo::new(
+            String::from("2025-01-01T00:00:00Z"),
+            String::from("Full Error"),
+            String::from("sample_index"),
+            String::from("static index"),
+            0,
+            1000,
+        );
+        sample_info
+            .set_incident("sample-cluster")
+            .set_reason(String::from("sample reason"))
+            .set_duration(900)
+            /* 반복 발생한 장애 표시 부분도 렌더링되도록 */
+            .set_occurrence_count(2);
+
+        let sample_alarm: ErrorAlarmInfoFormat =
+            ErrorAlarmInfoFormat::new(String::from("sample"), sample_info);
+
+        self.render_alarm_email("sample-cluster", &[sample_alarm])
+            .map_err(|e| anyhow!("[TemplateServicePub->validate] {:?}", e))?;
+
+        let sample_schedule: IndexSchedules = IndexSchedules {
+            index_name: String::from("sample_index"),
+            time: String::from("0 0 9 * * * *"),
+            duration: 900,
+            size: 1000,
+            indexing_type: String::from("static index"),
+            log_cluster: None,
+            target_cluster: None,
+            consistency: None,
+        };
+        let sample_status: IndexCheckStatus = IndexCheckStatus::new(
+            String::from("sample_index"),
+            String::from("Success"),
+            String::from("2025-01-01T00:00:00Z"),
+            1000,
+            1000,
+        );
+        let sample_items: Vec<DigestTemplateItem> = vec![
+            DigestTemplateItem::new(&sample_schedule, Some(&sample_status), 1, None),
+            DigestTemplateItem::new(&sample_schedule, None, 0, None),
+        ];
+
+        self.render_digest_email("sample-cluster", "2025-01-01 09:00:00", sample_items)
+            .map_err(|e| anyhow!("[TemplateServicePub->validate] {:?}", e))?;
+
+        let sample_records: Vec<CheckRecord> = vec![
+            CheckRecord::new(String::from("Full Error"), NaiveDateTime::default(), None),
+            CheckRecord::new(
+                String::from("Success"),
+                NaiveDateTime::default() + chrono::Duration::hours(1),
+                Some(1000),
+            ),
+        ];
+        let sample_report: SlaReport = SlaReport::new(
+            "sample-cluster",
+            "weekly",
+            NaiveDateTime::default(),
+            NaiveDateTime::default(),
+            NaiveDateTime::default(),
+            vec![
+                IndexSlaReport::from_records(&sample_schedule, &sample_records),
+                IndexSlaReport::from_records(&sample_schedule, &[]),
+            ],
+        );
+
+        self.render_sla_report_email(&sample_report)
+            .map(|_| ())
+            .map_err(|e| anyhow!("[TemplateServicePub->validate] {:?}", e))
+    }
+
+    #[doc = "템플릿 하나를 렌더링해주는 함수"]
+    fn render(&self, template_name: &str, context: &Context) -> Result<String, anyhow::Error> {
+        self.tera.render(template_name, context).map_err(|e| {
+            /* Tera 에러는 원인이 source 체인에 들어있어서 함께 출력 */
+            let mut err_msg: String = e.to_string();
+            let mut source: Option<&dyn std::error::Error> = std::error::Error::source(&e);
+
+            while let Some(cause) = source {
+                err_msg.push_str(&format!(" -> {}", cause));
+                source = cause.source();
+            }
+
+            anyhow!("[TemplateServicePub->render] {}", err_msg)
+        })
+    }
+}
+
+impl TemplateService for TemplateServicePub {
+    #[doc = "색인 실패 알람 정보를 이메일(HTML + plaintext) 로 렌더링해주는 함수"]
+    /// # Arguments
+    /// * `cluster_name` - Elasticsearch 클러스터 이름
+    /// * `error_alarm_infos` - 실패한 색인 정보들
+    ///
+    /// # Returns
+    /// * Result<EmailStruct, anyhow::Error>
+    fn render_alarm_email(
+        &self,
+        cluster_name: &str,
+        error_alarm_infos: &[ErrorAlarmInfoFormat],
+    ) -> Result<EmailStruct, anyhow::Error> {
+        let alarm_context: AlarmEmailContext =
+            AlarmEmailContext::new(cluster_name, error_alarm_infos);
+        let context: Context = Context::from_serialize(&alarm_context)?;
+
+        let html_form: String = self.render(ALARM_HTML_TEMPLATE, &context)?;
+        let text_form: String = self.render(ALARM_TEXT_TEMPLATE, &context)?;
+
+        let summary_form: String = format!(
+            "[{}] Indexing ERROR: {}",
+            cluster_name,
+            error_alarm_infos
+                .iter()
+                .map(|alarm| {
+                    let info: &ErrorAlarmInfo = alarm.error_alarm_info();
+                    format!("{}({})", info.index_name(), info.error_type())
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+
+        Ok(EmailStruct::new(
+            String::from("[Elasticsearch] Indexing ERROR Alarm"),
+            html_form,
+            text_form,
+            summary_form,
+        ))
+    }
+
+    #[doc = "모니터링 중인 모든 인덱스의 상태 요약(digest) 을 이메일(HTML + plaintext) 로 렌더링해주는 함수"]
+    /// # Arguments
+    /// * `cluster_name` - Elasticsearch 클러스터 이름
+    /// * `generated_at` - 요약 생성 시각 (한국시간)
+    /// * `items` - 인덱스별 상태
+    ///
+    /// # Returns
+    /// * Result<EmailStruct, anyhow::Error>
+    fn render_digest_email(
+        &self,
+        cluster_name: &str,
+        generated_at: &str,
+        items: Vec<DigestTemplateItem>,
+    ) -> Result<EmailStruct, anyhow::Error> {
+        let digest_context: DigestEmailContext =
+            DigestEmailContext::new(cluster_name, generated_at, items);
+        let context: Context = Context::from_serialize(&digest_context)?;
+
+        let html_form: String = self.render(DIGEST_HTML_TEMPLATE, &context)?;
+        let text_form: String = self.render(DIGEST_TEXT_TEMPLATE, &context)?;
+
+        let summary_form: String = format!(
+            "[{}] Indexing status: {} ok, {} failed, {} unknown, {} open alarm(s)",
+            cluster_name,
+            digest_context.success_cnt(),
+            digest_context.failure_cnt(),
+            digest_context.unknown_cnt(),
+            digest_context.open_alarm_cnt()
+        );
+
+        Ok(EmailStruct::new(
+            String::from("[Elasticsearch] Indexing Daily Status"),
+            html_form,
+            text_form,
+            summary_form,
+        ))
+    }
+
+    #[doc = "인덱스별 SLA 리포트를 이메일(HTML + plaintext) 로 렌더링해주는 함수"]
+    /// # Arguments
+    /// * `sla_report` - 기간별 SLA 리포트
+    ///
+    /// # Returns
+    /// * Result<EmailStruct, anyhow::Error>
+    fn render_sla_report_email(
+        &self,
+        sla_report: &SlaReport,
+    ) -> Result<EmailStruct, anyhow::Error> {
+        let context: Context = Context::from_serialize(sla_report)?;
+
+        let html_form: String = self.render(REPORT_HTML_TEMPLATE, &context)?;
+        let text_form: String = self.render(REPORT_TEXT_TEMPLATE, &context)?;
+
+        let summary_form: String = format!(
+            "[{}] Indexing SLA report ({}) {} ~ {}",
+            sla_report.cluster_name(),
+            sla_report.period_name(),
+            sla_report.period_start(),
+            sla_report.period_end()
+        );
+
+        Ok(EmailStruct::new(
+            format!(
+                "[Elasticsearch] Indexing SLA Report ({})",
+                sla_report.period_name()
+            ),
+            html_form,
+            text_form,
+            summary_form,
+        ))
+    }
+}
+
+#[doc = "템플릿 경로가 지정되어 있으면 파일을 읽고, 아니면 내장 템플릿을 사용"]
+fn read_template_or_default(
+    path: Option<&str>,
+    default_template: &str,
+) -> Result<String, anyhow::Error> {
+    match path {
+        Some(path) => fs::read_to_string(Path::new(path))
+            .map_err(|e| anyhow!("[TemplateServicePub->new] Failed to read '{}': {}", path, e)),
+        None => Ok(default_template.to_string()),
+    }
+}
+
+#[doc = "Tera 로 바꾸기 전의 치환 문자열('{cluster_name}', '{index_list}')이 남아있는지 찾아주는 함수"]
+fn find_legacy_placeholders(template: &str) -> Vec<&'static str> {
+    LEGACY_PLACEHOLDERS
+        .iter()
+        .copied()
+        .filter(|placeholder| {
+            template.match_indices(placeholder).any(|(pos, _)| {
+                /* '{{cluster_name}}' 처럼 Tera 표현식의 일부인 경우는 제외 */
+                !template[..pos].ends_with('{')
+                    && !template[pos + placeholder.len()..].starts_with('}')
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEFAULT_ALARM_HTML_TEMPLATE: &str = include_str!("../../html/view.html");
+
+    #[doc = "템플릿에서 출력하는 '{{ ... }}' 표현식들"]
+    fn output_expressions(template: &str) -> HashSet<&str> {
+        template
+            .split("{{")
+            .skip(1)
+            .filter_map(|part| part.split_once("}}"))
+            .map(|(expression, _)| expression.trim())
+            .collect()
+    }
+
+    #[test]
+    fn find_legacy_placeholders_in_old_template() {
+        assert_eq!(
+            find_legacy_placeholders("<h1>[{cluster_name}]</h1><table>{index_list}</table>"),
+            vec!["{cluster_name}", "{index_list}"]
+        );
+        assert!(find_legacy_placeholders("<h1>[{{ cluster_name }}]</h1>").is_empty());
+        assert!(find_legacy_placeholders("<h1>[{{cluster_name}}]</h1>").is_empty());
+        assert!(find_legacy_placeholders(DEFAULT_ALARM_HTML_TEMPLATE).is_empty());
+    }
+
+    #[test]
+    fn default_alarm_text_template_shows_every_html_value() {
+        /* 기본 알람 템플릿은 HTML / plaintext 두 개 - 한쪽에만 값이 추가되지 않도록 */
+        let html_outputs: HashSet<&str> = output_expressions(DEFAULT_ALARM_HTML_TEMPLATE);
+        let text_outputs: HashSet<&str> = output_expressions(DEFAULT_ALARM_TEXT_TEMPLATE);
+
+        let missing: Vec<&&str> = html_outputs.difference(&text_outputs).collect();
+        assert!(
+            missing.is_empty(),
+            "html/view.txt does not show {:?}",
+            missing
+        );
+    }
+}
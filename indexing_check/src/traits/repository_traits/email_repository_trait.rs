@@ -0,0 +1,8 @@
+use crate::common::*;
+
+use crate::model::email_struct::*;
+
+#[async_trait]
+pub trait EmailRepository {
+    async fn send_email(&self, receiver: &str, email: &EmailStruct) -> Result<(), anyhow::Error>;
+}
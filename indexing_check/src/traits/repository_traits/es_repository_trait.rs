@@ -1,5 +1,7 @@
 use crate::common::*;
 
+use crate::model::bulk_operation::*;
+
 #[async_trait]
 pub trait EsRepository {
     async fn get_search_query(
@@ -7,11 +9,61 @@ pub trait EsRepository {
         es_query: &Value,
         index_name: &str,
     ) -> Result<Value, anyhow::Error>;
-    async fn post_query(&self, document: &Value, index_name: &str) -> Result<(), anyhow::Error>;
-    async fn post_query_struct<T: Serialize + Sync>(
+    async fn count(&self, es_query: &Value, index_name: &str) -> Result<u64, anyhow::Error>;
+    async fn open_point_in_time(
+        &self,
+        index_name: &str,
+        keep_alive: &str,
+    ) -> Result<String, anyhow::Error>;
+    async fn get_search_query_pit(&self, es_query: &Value) -> Result<Value, anyhow::Error>;
+    async fn close_point_in_time(&self, pit_id: &str) -> Result<(), anyhow::Error>;
+    async fn get_document(
         &self,
-        param_struct: &T,
+        doc_id: &str,
         index_name: &str,
+    ) -> Result<Option<Value>, anyhow::Error>;
+    async fn put_document_if(
+        &self,
+        doc_id: &str,
+        document: &Value,
+        index_name: &str,
+        if_seq_no_primary_term: Option<(i64, i64)>,
+    ) -> Result<bool, anyhow::Error>;
+    async fn upsert_document(
+        &self,
+        doc_id: &str,
+        script: &Value,
+        upsert_document: &Value,
+        index_name: &str,
+    ) -> Result<(), anyhow::Error>;
+    async fn bulk(
+        &self,
+        operations: &[BulkOperation],
+        index_name: &str,
+    ) -> Result<BulkResult, anyhow::Error>;
+    async fn update_by_query(
+        &self,
+        es_query: &Value,
+        index_name: &str,
+    ) -> Result<Value, anyhow::Error>;
+    async fn delete_by_query(
+        &self,
+        es_query: &Value,
+        index_name: &str,
+    ) -> Result<Value, anyhow::Error>;
+    async fn index_exists(&self, index_name: &str) -> Result<bool, anyhow::Error>;
+    async fn put_index_template(
+        &self,
+        template_name: &str,
+        template: &Value,
+    ) -> Result<(), anyhow::Error>;
+    async fn put_lifecycle_policy(
+        &self,
+        policy_name: &str,
+        policy: &Value,
     ) -> Result<(), anyhow::Error>;
-    async fn delete_query(&self, doc_id: &str, index_name: &str) -> Result<(), anyhow::Error>;
+    async fn data_stream_exists(&self, data_stream_name: &str) -> Result<bool, anyhow::Error>;
+    async fn create_data_stream(&self, data_stream_name: &str) -> Result<(), anyhow::Error>;
+    async fn get_mapping(&self, index_name: &str) -> Result<Value, anyhow::Error>;
+    async fn put_mapping(&self, index_name: &str, mapping: &Value) -> Result<(), anyhow::Error>;
 }
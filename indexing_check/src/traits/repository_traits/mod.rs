@@ -1,3 +1,4 @@
+pub mod email_repository_trait;
 pub mod es_repository_trait;
 pub mod sqlserver_repository_trait;
 pub mod telegram_repository_trait;
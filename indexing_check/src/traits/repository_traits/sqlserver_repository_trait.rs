@@ -1,12 +1,13 @@
 use crate::common::*;
 
+use crate::model::procedure_config::*;
+
 #[async_trait]
 pub trait SqlServerRepository {
-    #[doc = "SQL Server 아이메일러 관련 프로시저 호출"]
-    async fn execute_imailer_procedure(
+    #[doc = "설정된 SQL Server 프로시저 호출 -> 리턴코드 반환"]
+    async fn execute_procedure(
         &self,
-        send_email: &str,
-        email_subject: &str,
-        email_content: &str,
-    ) -> Result<(), anyhow::Error>;
+        procedure: &ProcedureConfig,
+        param_sources: &HashMap<&str, &str>,
+    ) -> Result<i32, anyhow::Error>;
 }
@@ -1,12 +1,19 @@
 use crate::common::*;
 
+use crate::model::telegram_update::*;
+
 #[async_trait]
 pub trait TelebotRepository {
-    async fn bot_send(&self, send_msg: &str) -> Result<(), anyhow::Error>;
     async fn try_send(
         &self,
         client: &reqwest::Client,
         url: &str,
         body: &Value,
     ) -> Result<(), anyhow::Error>;
+    async fn send_to_chat(&self, chat_id: &str, send_msg: &str) -> Result<(), anyhow::Error>;
+    async fn get_updates(
+        &self,
+        offset: i64,
+        timeout_sec: u64,
+    ) -> Result<Vec<TelegramUpdate>, anyhow::Error>;
 }
@@ -1,4 +1,4 @@
 pub mod notification_service_trait;
 pub mod query_service_trait;
-//pub mod smtp_service_trait;
-//pub mod telegram_service_trait;
+pub mod status_service_trait;
+pub mod template_service_trait;
@@ -1,11 +1,33 @@
 use crate::common::*;
 
-use crate::model::error_alarm_info_format::*;
+use crate::model::{
+    digest_template_context::*, error_alarm_info_format::*, notification_outbox::*, sla_report::*,
+};
 
 #[async_trait]
 pub trait NotificationService {
-    async fn send_message_to_receivers(
+    fn build_notifications(
         &self,
         error_alarm_infos: &[ErrorAlarmInfoFormat],
+        created_at: NaiveDateTime,
+    ) -> Result<Vec<NotificationOutbox>, anyhow::Error>;
+    fn build_digest_notifications(
+        &self,
+        items: Vec<DigestTemplateItem>,
+        generated_at: &str,
+        created_at: NaiveDateTime,
+    ) -> Result<Vec<NotificationOutbox>, anyhow::Error>;
+    fn build_report_notifications(
+        &self,
+        sla_report: &SlaReport,
+        created_at: NaiveDateTime,
+    ) -> Result<Vec<NotificationOutbox>, anyhow::Error>;
+    fn build_test_notifications(
+        &self,
+        created_at: NaiveDateTime,
+    ) -> Result<Vec<NotificationOutbox>, anyhow::Error>;
+    async fn deliver_notification(
+        &self,
+        notification: &NotificationOutbox,
     ) -> Result<(), anyhow::Error>;
 }
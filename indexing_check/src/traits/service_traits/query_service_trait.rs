@@ -1,32 +1,153 @@
 use crate::common::*;
 
 
+use crate::model::alarm_snooze::*;
+use crate::model::bulk_operation::*;
+use crate::model::check_history::*;
 use crate::model::error_alarm_info::*;
 use crate::model::error_alarm_info_format::*;
+use crate::model::es_node_status::*;
+use crate::model::leader_lease::*;
+use crate::model::notification_outbox::*;
 use crate::model::vector_index_log_format::*;
 
 #[async_trait]
 pub trait QueryService {
     async fn get_indexing_movement_log(
         &self,
+        cluster_name: &str,
         query_index: &str,
         index_name: &str,
         index_type: &str,
         start_dt: NaiveDateTime,
         end_dt: NaiveDateTime,
     ) -> Result<VectorIndexLogFormat, anyhow::Error>;
-    async fn post_indexing_error_info(
+    async fn upsert_incident(
         &self,
         index_name: &str,
-        error_alaram_info: ErrorAlarmInfo,
+        error_alaram_info: &ErrorAlarmInfo,
     ) -> Result<(), anyhow::Error>;
+    async fn resolve_incidents(
+        &self,
+        err_monitor_index: &str,
+        cluster_name: &str,
+        index_name: &str,
+        error_types: &[&str],
+        resolved_at_kst: &str,
+    ) -> Result<u64, anyhow::Error>;
+    async fn get_doc_count(&self, cluster_name: &str, index_name: &str)
+        -> Result<u64, anyhow::Error>;
+    async fn get_term_counts(
+        &self,
+        cluster_name: &str,
+        index_name: &str,
+        field: &str,
+        size: usize,
+    ) -> Result<BTreeMap<String, u64>, anyhow::Error>;
     async fn get_error_alarm_infos(
         &self,
         index_name: &str,
     ) -> Result<Vec<ErrorAlarmInfoFormat>, anyhow::Error>;
-    async fn delete_index_by_doc(
+    async fn get_error_alarm_history(
+        &self,
+        index_name: &str,
+        start_dt: NaiveDateTime,
+        end_dt: NaiveDateTime,
+    ) -> Result<Vec<ErrorAlarmInfo>, anyhow::Error>;
+    async fn dispatch_dynamic_alarms(
+        &self,
+        err_monitor_index: &str,
+        doc_ids: &[String],
+    ) -> Result<u64, anyhow::Error>;
+    async fn acknowledge_alarms_by_index(
+        &self,
+        err_monitor_index: &str,
+        index_name: &str,
+    ) -> Result<u64, anyhow::Error>;
+    async fn post_notification_outboxes(
+        &self,
+        outbox_index: &str,
+        notifications: &[NotificationOutbox],
+    ) -> Result<BulkResult, anyhow::Error>;
+    async fn get_due_outbox_entries(
+        &self,
+        outbox_index: &str,
+        now_utc: NaiveDateTime,
+        size: usize,
+    ) -> Result<Vec<NotificationOutboxFormat>, anyhow::Error>;
+    async fn get_pending_outbox_alarm_ids(
+        &self,
+        outbox_index: &str,
+    ) -> Result<HashSet<String>, anyhow::Error>;
+    async fn settle_outbox_entries(
+        &self,
+        outbox_index: &str,
+        settlements: &[(String, OutboxSettlement)],
+    ) -> Result<BulkResult, anyhow::Error>;
+    async fn install_index_template(
+        &self,
+        template_name: &str,
+        template: &Value,
+    ) -> Result<(), anyhow::Error>;
+    async fn install_lifecycle_policy(
+        &self,
+        policy_name: &str,
+        policy: &Value,
+    ) -> Result<(), anyhow::Error>;
+    async fn update_index_mapping(
+        &self,
+        index_name: &str,
+        mapping: &Value,
+    ) -> Result<bool, anyhow::Error>;
+    async fn ensure_data_stream(
+        &self,
+        data_stream_name: &str,
+        mapping: &Value,
+    ) -> Result<(), anyhow::Error>;
+    async fn delete_closed_alarms(
+        &self,
+        err_monitor_index: &str,
+        before_kst: NaiveDateTime,
+    ) -> Result<u64, anyhow::Error>;
+    async fn delete_expired_outbox(
         &self,
+        outbox_index: &str,
+        before_utc: NaiveDateTime,
+    ) -> Result<u64, anyhow::Error>;
+    async fn post_check_histories(
+        &self,
+        history_index: &str,
+        check_histories: &[CheckHistory],
+    ) -> Result<BulkResult, anyhow::Error>;
+    async fn get_check_history(
+        &self,
+        history_index: &str,
         index_name: &str,
-        doc_id: &str,
+        start_dt: NaiveDateTime,
+        end_dt: NaiveDateTime,
+    ) -> Result<Vec<CheckHistory>, anyhow::Error>;
+    async fn get_leader_lease(
+        &self,
+        lease_index: &str,
+        lease_id: &str,
+    ) -> Result<Option<LeaderLeaseFormat>, anyhow::Error>;
+    async fn put_leader_lease(
+        &self,
+        lease_index: &str,
+        lease_id: &str,
+        leader_lease: &LeaderLease,
+        current: Option<&LeaderLeaseFormat>,
+    ) -> Result<bool, anyhow::Error>;
+    async fn put_alarm_snooze(
+        &self,
+        lease_index: &str,
+        alarm_snooze: &AlarmSnooze,
     ) -> Result<(), anyhow::Error>;
+    async fn get_active_alarm_snoozes(
+        &self,
+        lease_index: &str,
+        now_utc: NaiveDateTime,
+    ) -> Result<Vec<AlarmSnooze>, anyhow::Error>;
+    async fn refresh_es_nodes(&self, cluster_name: &str) -> Result<usize, anyhow::Error>;
+    fn get_es_node_states(&self) -> Vec<EsNodeStatus>;
 }
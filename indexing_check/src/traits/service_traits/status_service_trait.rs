@@ -0,0 +1,12 @@
+use crate::common::*;
+
+use crate::model::index_check_status::*;
+
+pub trait StatusService {
+    fn record_check_status(&self, check_status: IndexCheckStatus);
+    fn get_check_status(&self, index_name: &str) -> Option<IndexCheckStatus>;
+    fn snooze_index(&self, index_name: &str, until: NaiveDateTime);
+    fn get_snoozed_until(&self, index_name: &str, now: NaiveDateTime) -> Option<NaiveDateTime>;
+    fn set_leader_until(&self, until: Option<NaiveDateTime>);
+    fn is_leader(&self, now: NaiveDateTime) -> bool;
+}
@@ -0,0 +1,19 @@
+use crate::model::{
+    digest_template_context::*, email_struct::*, error_alarm_info_format::*, sla_report::*,
+};
+
+pub trait TemplateService {
+    fn render_alarm_email(
+        &self,
+        cluster_name: &str,
+        error_alarm_infos: &[ErrorAlarmInfoFormat],
+    ) -> Result<EmailStruct, anyhow::Error>;
+    fn render_digest_email(
+        &self,
+        cluster_name: &str,
+        generated_at: &str,
+        items: Vec<DigestTemplateItem>,
+    ) -> Result<EmailStruct, anyhow::Error>;
+    fn render_sla_report_email(&self, sla_report: &SlaReport)
+        -> Result<EmailStruct, anyhow::Error>;
+}
@@ -27,3 +27,50 @@ pub fn convert_json_from_struct<T: Serialize>(input_struct: &T) -> Result<Value,
         )
     })
 }
+
+#[doc = "CSV 필드 변환 - 쉼표/따옴표/줄바꿈이 들어있는 값은 따옴표로 감싸준다."]
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[doc = "명령어 결과를 파일로 저장하거나, 경로가 없으면 표준출력으로 보여주는 함수"]
+/// # Arguments
+/// * `output_path` - 저장할 파일 경로 (None 이면 표준출력)
+/// * `content` - 저장할 내용
+///
+/// # Returns
+/// * Result<(), anyhow::Error>
+pub fn write_or_print(output_path: Option<&str>, content: &str) -> Result<(), anyhow::Error> {
+    match output_path {
+        Some(output_path) => {
+            fs::write(output_path, content).map_err(|e| {
+                anyhow!(
+                    "[Error][write_or_print()] Failed to write '{}': {}",
+                    output_path,
+                    e
+                )
+            })?;
+            info!("Output written to {}", output_path);
+        }
+        None => print!("{}", content),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("product_a"), "product_a");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("line1\nline2"), "\"line1\nline2\"");
+    }
+}
@@ -1,13 +1,15 @@
 use crate::common::*;
 
+use crate::errors::app_error::*;
+
 #[doc = "Function responsible for logging"]
-pub fn set_global_logger() {
+pub fn set_global_logger() -> Result<(), AppError> {
     let log_directory = "logs"; /* Directory to store log files */
     let file_prefix = ""; /* Prefixes for log files */
 
     // Logger setting
     Logger::try_with_str("info")
-        .unwrap()
+        .map_err(|e| AppError::Config(format!("Invalid log specification: {}", e)))?
         .log_to_file(
             FileSpec::default()
                 .directory(log_directory)
@@ -20,7 +22,9 @@ pub fn set_global_logger() {
         )
         .format_for_files(custom_format)
         .start()
-        .unwrap_or_else(|e| panic!("Logger initialization failed: {}", e));
+        .map_err(|e| AppError::Config(format!("Logger initialization failed: {}", e)))?;
+
+    Ok(())
 }
 
 #[doc = "Custom Log Format Function"]
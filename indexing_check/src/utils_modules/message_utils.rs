@@ -0,0 +1,26 @@
+#[doc = "메시지 길이 제한에 맞게 줄 단위로 나눠주는 함수"]
+/// # Arguments
+/// * `msg` - 나눌 메시지
+/// * `max_len` - 한 조각의 최대 길이
+///
+/// # Returns
+/// * Vec<String>
+pub fn split_message(msg: &str, max_len: usize) -> Vec<String> {
+    let mut chunks: Vec<String> = Vec::new();
+    let mut current: String = String::new();
+
+    for line in msg.lines() {
+        if !current.is_empty() && current.len() + line.len() + 1 > max_len {
+            chunks.push(std::mem::take(&mut current));
+        }
+
+        current.push_str(line);
+        current.push('\n');
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
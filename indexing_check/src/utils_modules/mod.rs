@@ -1,4 +1,5 @@
 pub mod io_utils;
 pub mod logger_utils;
+pub mod message_utils;
 pub mod time_utils;
 pub mod traits;
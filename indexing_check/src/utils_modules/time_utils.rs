@@ -27,12 +27,6 @@ pub fn get_current_kor_naive_datetime_str() -> Result<String, anyhow::Error> {
     Ok(cur_time_str)
 }
 
-#[doc = "Function that returns the current UTC time as a string"]
-pub fn get_current_utc_naivedate_str(fmt: &str) -> Result<String, anyhow::Error> {
-    let curr_time = get_current_utc_naivedate();
-    get_str_from_naivedate(curr_time, fmt)
-}
-
 #[doc = "Function that converts the date data 'naivedate' format to the string format"]
 pub fn get_str_from_naivedatetime(
     naive_date: NaiveDateTime,
@@ -62,4 +56,83 @@ pub fn calc_time_window(duration_secs: i64) -> (NaiveDateTime, NaiveDateTime) {
     let curr_time_utc: NaiveDateTime = get_currnet_utc_naivedatetime();
     let time_minutes_ago: NaiveDateTime = curr_time_utc - chrono::Duration::seconds(duration_secs);
     (curr_time_utc, time_minutes_ago)
-}
\ No newline at end of file
+}
+
+#[doc = "한국 표준시(GMT + 9) FixedOffset 을 반환해주는 함수"]
+pub fn get_kst_offset() -> Result<FixedOffset, anyhow::Error> {
+    FixedOffset::east_opt(9 * 3600)
+        .ok_or_else(|| anyhow!("[get_kst_offset()] There was a problem initializing 'kst_offset'."))
+}
+
+#[doc = "'90s', '30m', '2h', '1d' 형식의 문자열을 chrono::Duration 으로 변환해주는 함수"]
+/// # Arguments
+/// * `duration_str` - 숫자 + 단위(s/m/h/d) 형식의 문자열
+///
+/// # Returns
+/// * Result<chrono::Duration, anyhow::Error>
+pub fn parse_duration_str(duration_str: &str) -> Result<chrono::Duration, anyhow::Error> {
+    let trimmed: &str = duration_str.trim();
+    let unit_pos: usize = trimmed
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| anyhow!("[parse_duration_str()] Missing unit in '{}'", duration_str))?;
+
+    let (num_str, unit) = trimmed.split_at(unit_pos);
+    let num: i64 = num_str
+        .parse::<i64>()
+        .map_err(|e| anyhow!("[parse_duration_str()] Invalid number '{}': {}", num_str, e))?;
+
+    let duration: Option<chrono::Duration> = match unit {
+        "s" => chrono::TimeDelta::try_seconds(num),
+        "m" => chrono::TimeDelta::try_minutes(num),
+        "h" => chrono::TimeDelta::try_hours(num),
+        "d" => chrono::TimeDelta::try_days(num),
+        _ => {
+            return Err(anyhow!(
+                "[parse_duration_str()] Unsupported unit '{}' (use s/m/h/d)",
+                unit
+            ))
+        }
+    };
+
+    duration.ok_or_else(|| {
+        anyhow!(
+            "[parse_duration_str()] Duration is too large: '{}'",
+            duration_str
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_duration_str_units() {
+        assert_eq!(
+            parse_duration_str("90s").unwrap(),
+            chrono::Duration::seconds(90)
+        );
+        assert_eq!(
+            parse_duration_str("30m").unwrap(),
+            chrono::Duration::minutes(30)
+        );
+        assert_eq!(
+            parse_duration_str(" 2h ").unwrap(),
+            chrono::Duration::hours(2)
+        );
+        assert_eq!(parse_duration_str("1d").unwrap(), chrono::Duration::days(1));
+    }
+
+    #[test]
+    fn parse_duration_str_rejects_invalid_input() {
+        assert!(parse_duration_str("30").is_err());
+        assert!(parse_duration_str("m").is_err());
+        assert!(parse_duration_str("3w").is_err());
+    }
+
+    #[test]
+    fn parse_duration_str_rejects_overflow() {
+        assert!(parse_duration_str("999999999999d").is_err());
+        assert!(parse_duration_str("9999999999999999h").is_err());
+    }
+}